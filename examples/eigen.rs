@@ -39,12 +39,7 @@ fn main() {
 
     for i in 0..4 {
         let eval_i = eval.get(i);
-        evec.column(i, |evec_i| {
-            println!("eigenvalue = {}", eval_i);
-            evec_i.expect("Failed to get get column").vector(|v| {
-                let v = v.expect("Failed to get vector from column");
-                println!("eigenvector = {:?}", v);
-            });
-        });
+        println!("eigenvalue = {}", eval_i);
+        println!("eigenvector = {:?}", *evec.const_column(i));
     }
 }
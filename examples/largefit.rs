@@ -29,12 +29,11 @@ mod example {
         (x * x * x).exp()
     }
 
-    fn build_row(t: f64, row: &mut VectorF64) {
-        let p = row.len();
+    fn build_row(t: f64, row: &mut [f64]) {
         let mut xj = 1.;
 
-        for j in 0..p {
-            row.set(j, xj);
+        for x in row {
+            *x = xj;
             xj *= t;
         }
     }
@@ -64,31 +63,22 @@ mod example {
 
             // build (X,y) block with 'nr' rows
             for i in 0..nr {
-                xv.matrix_mut(|mat| {
-                    mat.expect("Failed to get matrix").row(i, |row| {
-                        let mut row = row.expect("Failed to get row...");
-                        let fi = func(t);
-                        // noise
-                        let ei = r.gaussian(0.1 * fi);
-                        let yi = fi + ei;
-
-                        // construct this row of LS matrix
-                        row.vector_mut(|vector| {
-                            build_row(t, vector.expect("Failed to get vector"));
-                        });
-
-                        // set right hand side value with added noise
-                        yv.vector_mut(|vector| {
-                            vector.expect("Failed to get vector").set(i, yi);
-                        });
-
-                        if print_data && i % 100 == 0 {
-                            println!("{} {}", t, yi);
-                        }
-                    });
-
-                    t += DT;
-                });
+                let fi = func(t);
+                // noise
+                let ei = r.gaussian(0.1 * fi);
+                let yi = fi + ei;
+
+                // construct this row of LS matrix
+                build_row(t, xv.row(i).as_slice_mut().expect("Failed to get row"));
+
+                // set right hand side value with added noise
+                yv.set(i, yi);
+
+                if print_data && i % 100 == 0 {
+                    println!("{} {}", t, yi);
+                }
+
+                t += DT;
             }
 
             // accumulate (X,y) block into LS system
@@ -164,7 +154,7 @@ mod example {
 
         while t <= 1. {
             let f_exact = func(t);
-            build_row(t, &mut v);
+            build_row(t, v.as_slice_mut().expect("Failed to get vector"));
 
             let f_tsqr = blas::level1::ddot(&v, &c_tsqr).unwrap();
             let f_normal = blas::level1::ddot(&v, &c_normal).unwrap();
@@ -614,6 +614,16 @@ pub fn Knu_scaled_e(nu: f64, x: f64) -> Result<types::Result, Value> {
     result_handler!(ret, unsafe { result.assume_init() }.into())
 }
 
+/// This routine computes the scaled irregular modified Bessel function of fractional order \nu, \exp(+|x|) K_\nu(x) for x>0, \nu>0,
+/// using the [`ResultE10`][crate::ResultE10] type to return a result with extended range.
+#[doc(alias = "gsl_sf_bessel_Knu_scaled_e10_e")]
+pub fn Knu_scaled_e10_e(nu: f64, x: f64) -> Result<types::ResultE10, Value> {
+    let mut result = MaybeUninit::<sys::gsl_sf_result_e10>::uninit();
+    let ret = unsafe { sys::gsl_sf_bessel_Knu_scaled_e10_e(nu, x, result.as_mut_ptr()) };
+
+    result_handler!(ret, unsafe { result.assume_init() }.into())
+}
+
 /// This routine computes the irregular cylindrical Bessel function of zeroth order, Y_0(x), for x>0.
 #[doc(alias = "gsl_sf_bessel_Y0")]
 pub fn Y0(x: f64) -> f64 {
@@ -800,3 +810,24 @@ pub fn zero_Jnu_e(nu: f64, s: u32) -> Result<types::Result, Value> {
 
     result_handler!(ret, unsafe { result.assume_init() }.into())
 }
+
+/// This routine computes the locations of the first `n` positive zeros of the Bessel function
+/// J_0(x), in increasing order.
+pub fn zeros_J0(n: u32) -> Result<Vec<f64>, Value> {
+    (1..=n).map(|s| zero_J0_e(s).map(|r| r.val)).collect()
+}
+
+/// This routine computes the locations of the first `n` positive zeros of the Bessel function
+/// J_1(x), in increasing order.
+pub fn zeros_J1(n: u32) -> Result<Vec<f64>, Value> {
+    (1..=n).map(|s| zero_J1_e(s).map(|r| r.val)).collect()
+}
+
+/// This routine computes the locations of the first `n` positive zeros of the Bessel function
+/// J_\nu(x), in increasing order.
+/// The current implementation does not support negative values of nu.
+///
+/// These are the sample points used by [`DiscreteHankel`][crate::DiscreteHankel].
+pub fn zeros_Jnu(nu: f64, n: u32) -> Result<Vec<f64>, Value> {
+    (1..=n).map(|s| zero_Jnu_e(nu, s).map(|r| r.val)).collect()
+}
@@ -415,6 +415,8 @@ pub mod level1 {
 
     /// This function computes a modified Givens transformation.
     /// The modified Givens transformation is defined in the original Level-1 BLAS specification, given in the references.
+    ///
+    /// Returns `Value::BadLength` if `d1`, `d2` or `b1` is empty or `P` has less than 5 elements.
     #[doc(alias = "gsl_blas_srotmg")]
     pub fn srotmg(
         d1: &mut [f32],
@@ -423,6 +425,9 @@ pub mod level1 {
         b2: f32,
         P: &mut [f32],
     ) -> Result<(), Value> {
+        if d1.is_empty() || d2.is_empty() || b1.is_empty() || P.len() < 5 {
+            return Err(Value::BadLength);
+        }
         let ret = unsafe {
             sys::gsl_blas_srotmg(
                 d1.as_mut_ptr(),
@@ -437,6 +442,8 @@ pub mod level1 {
 
     /// This function computes a modified Givens transformation.
     /// The modified Givens transformation is defined in the original Level-1 BLAS specification, given in the references.
+    ///
+    /// Returns `Value::BadLength` if `d1`, `d2` or `b1` is empty or `P` has less than 5 elements.
     #[doc(alias = "gsl_blas_drotmg")]
     pub fn drotmg(
         d1: &mut [f64],
@@ -445,6 +452,9 @@ pub mod level1 {
         b2: f64,
         P: &mut [f64],
     ) -> Result<(), Value> {
+        if d1.is_empty() || d2.is_empty() || b1.is_empty() || P.len() < 5 {
+            return Err(Value::BadLength);
+        }
         let ret = unsafe {
             sys::gsl_blas_drotmg(
                 d1.as_mut_ptr(),
@@ -458,24 +468,34 @@ pub mod level1 {
     }
 
     /// This function applies a modified Givens transformation.
+    ///
+    /// Returns `Value::BadLength` if `P` has less than 5 elements.
     #[doc(alias = "gsl_blas_srotm")]
     pub fn srotm(
         x: &mut types::VectorF32,
         y: &mut types::VectorF32,
         P: &mut [f32],
     ) -> Result<(), Value> {
+        if P.len() < 5 {
+            return Err(Value::BadLength);
+        }
         let ret =
             unsafe { sys::gsl_blas_srotm(x.unwrap_unique(), y.unwrap_unique(), P.as_mut_ptr()) };
         result_handler!(ret, ())
     }
 
     /// This function applies a modified Givens transformation.
+    ///
+    /// Returns `Value::BadLength` if `P` has less than 5 elements.
     #[doc(alias = "gsl_blas_drotm")]
     pub fn drotm(
         x: &mut types::VectorF64,
         y: &mut types::VectorF64,
         P: &mut [f64],
     ) -> Result<(), Value> {
+        if P.len() < 5 {
+            return Err(Value::BadLength);
+        }
         let ret =
             unsafe { sys::gsl_blas_drotm(x.unwrap_unique(), y.unwrap_unique(), P.as_mut_ptr()) };
         result_handler!(ret, ())
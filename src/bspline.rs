@@ -0,0 +1,128 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+//! Least-squares fitting of smoothing curves with B-splines, built on top of
+//! [`BSpLineWorkspace`] and [`MultifitLinearWorkspace`].
+
+use crate::{BSpLineWorkspace, MatrixF64, MultifitLinearWorkspace, Value, VectorF64};
+
+/// A B-spline fitted to data by [`fit`].
+///
+/// The fitted curve is f(x) = ∑ c_i B_i(x) where the B_i are the B-spline basis functions.
+pub struct BSplineFit {
+    workspace: BSpLineWorkspace,
+    coefficients: VectorF64,
+    covariance: MatrixF64,
+    chisq: f64,
+    // Range of the knots, outside of which GSL calls the error handler.
+    range: (f64, f64),
+    // Buffer for the basis functions, of length ncoeffs.
+    basis: VectorF64,
+}
+
+impl BSplineFit {
+    /// Returns the coefficients c_i of the fit.
+    pub fn coefficients(&self) -> &VectorF64 {
+        &self.coefficients
+    }
+
+    /// Returns the covariance matrix of the coefficients.
+    pub fn covariance(&self) -> &MatrixF64 {
+        &self.covariance
+    }
+
+    /// Returns the sum of squared residuals of the fit.
+    pub fn chisq(&self) -> f64 {
+        self.chisq
+    }
+
+    /// Returns the underlying workspace, whose knots are those used for the fit.
+    pub fn workspace(&self) -> &BSpLineWorkspace {
+        &self.workspace
+    }
+
+    /// Evaluates the fitted curve at `x`.
+    ///
+    /// Returns [`Value::Domain`] if `x` lies outside of the range of the data.
+    pub fn eval(&mut self, x: f64) -> Result<f64, Value> {
+        self.eval_err(x).map(|(y, _)| y)
+    }
+
+    /// Evaluates the fitted curve at `x`.
+    ///
+    /// Returns `(y, y_err)` where `y_err` is the standard deviation of `y` computed from the
+    /// covariance of the coefficients, or [`Value::Domain`] if `x` lies outside of the range of
+    /// the data.
+    pub fn eval_err(&mut self, x: f64) -> Result<(f64, f64), Value> {
+        let (a, b) = self.range;
+        if !(a <= x && x <= b) {
+            return Err(Value::Domain);
+        }
+        self.workspace.eval(x, &mut self.basis)?;
+        crate::multilinear::linear_est(&self.basis, &self.coefficients, &self.covariance)
+    }
+}
+
+/// Fits the data points `(x, y)` in the least-squares sense with B-splines of order `order`
+/// (4 for cubic B-splines) and `nbreak` uniform breakpoints spanning the range of `x`, that is
+/// with `nbreak + order - 2` coefficients.
+///
+/// Returns [`Value::BadLength`] if `x` and `y` have different lengths and [`Value::Invalid`] if
+/// `nbreak < 2`, `order == 0`, there are not more points than coefficients (the covariance of the
+/// coefficients could not be estimated) or all the abscissae are equal.
+///
+/// ```
+/// use rgsl::bspline;
+///
+/// let x: Vec<f64> = (0..100).map(|i| i as f64 * 0.1).collect();
+/// let y: Vec<f64> = x.iter().map(|x| x.sin()).collect();
+/// let mut fit = bspline::fit(&x, &y, 10, 4).unwrap();
+/// assert!((fit.eval(5.).unwrap() - 5f64.sin()).abs() < 1e-3);
+/// ```
+pub fn fit(x: &[f64], y: &[f64], nbreak: usize, order: usize) -> Result<BSplineFit, Value> {
+    if x.len() != y.len() {
+        return Err(Value::BadLength);
+    }
+    if nbreak < 2 || order == 0 {
+        return Err(Value::Invalid);
+    }
+    let n = x.len();
+    let ncoeffs = nbreak + order - 2;
+    if n <= ncoeffs {
+        return Err(Value::Invalid);
+    }
+    let a = x.iter().copied().fold(f64::INFINITY, f64::min);
+    let b = x.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if a >= b {
+        return Err(Value::Invalid);
+    }
+
+    let mut workspace = BSpLineWorkspace::new(order, nbreak).ok_or(Value::NoMemory)?;
+    workspace.knots_uniform(a, b)?;
+
+    // Design matrix X_ij = B_j(x_i).
+    let mut basis = VectorF64::new(ncoeffs).ok_or(Value::NoMemory)?;
+    let mut design = MatrixF64::new(n, ncoeffs).ok_or(Value::NoMemory)?;
+    for (i, &xi) in x.iter().enumerate() {
+        workspace.eval(xi, &mut basis)?;
+        for j in 0..ncoeffs {
+            design.set(i, j, basis.get(j));
+        }
+    }
+
+    let y = VectorF64::from_slice(y).ok_or(Value::NoMemory)?;
+    let mut coefficients = VectorF64::new(ncoeffs).ok_or(Value::NoMemory)?;
+    let mut covariance = MatrixF64::new(ncoeffs, ncoeffs).ok_or(Value::NoMemory)?;
+    let mut mw = MultifitLinearWorkspace::new(n, ncoeffs).ok_or(Value::NoMemory)?;
+    let chisq = mw.linear(&design, &y, &mut coefficients, &mut covariance)?;
+
+    Ok(BSplineFit {
+        workspace,
+        coefficients,
+        covariance,
+        chisq,
+        range: (a, b),
+        basis,
+    })
+}
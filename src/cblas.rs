@@ -4,41 +4,80 @@
 
 pub mod level1 {
     use crate::vector::{as_mut_ptr, as_ptr, check_equal_len, len, stride, Vector, VectorMut};
+    use crate::Value;
     #[cfg(feature = "complex")]
     use num_complex::Complex;
 
     /// Return the sum of `alpha` and the dot product of `x` and `y`.
+    ///
+    /// Panics if `x` and `y` do not have the same length, see [`try_sdsdot`].
     #[doc(alias = "cblas_sdsdot")]
     pub fn sdsdot<T: Vector<f32> + ?Sized>(alpha: f32, x: &T, y: &T) -> f32 {
         check_equal_len(x, y).expect("The length of `x` and `y` must be equal");
         unsafe { sys::cblas_sdsdot(len(x), alpha, as_ptr(x), stride(x), as_ptr(y), stride(y)) }
     }
 
+    /// Same as [`sdsdot`], returning [`Value::BadLength`] instead of panicking if `x` and `y`
+    /// do not have the same length.
+    pub fn try_sdsdot<T: Vector<f32> + ?Sized>(alpha: f32, x: &T, y: &T) -> Result<f32, Value> {
+        check_equal_len(x, y)?;
+        Ok(sdsdot(alpha, x, y))
+    }
+
     /// Return the dot product of `x` and `y`.
+    ///
+    /// Panics if `x` and `y` do not have the same length, see [`try_dsdot`].
     #[doc(alias = "cblas_dsdot")]
     pub fn dsdot<T: Vector<f32> + ?Sized>(x: &T, y: &T) -> f64 {
         check_equal_len(x, y).expect("The length of `x` and `y` must be equal");
         unsafe { sys::cblas_dsdot(len(x), as_ptr(x), stride(x), as_ptr(y), stride(y)) }
     }
 
+    /// Same as [`dsdot`], returning [`Value::BadLength`] instead of panicking if `x` and `y`
+    /// do not have the same length.
+    pub fn try_dsdot<T: Vector<f32> + ?Sized>(x: &T, y: &T) -> Result<f64, Value> {
+        check_equal_len(x, y)?;
+        Ok(dsdot(x, y))
+    }
+
     /// Return the dot product of `x` and `y`.
+    ///
+    /// Panics if `x` and `y` do not have the same length, see [`try_sdot`].
     #[doc(alias = "cblas_sdot")]
     pub fn sdot<T: Vector<f32> + ?Sized>(x: &T, y: &T) -> f32 {
         check_equal_len(x, y).expect("The length of `x` and `y` must be equal");
         unsafe { sys::cblas_sdot(len(x), as_ptr(x), stride(x), as_ptr(y), stride(y)) }
     }
 
+    /// Same as [`sdot`], returning [`Value::BadLength`] instead of panicking if `x` and `y`
+    /// do not have the same length.
+    pub fn try_sdot<T: Vector<f32> + ?Sized>(x: &T, y: &T) -> Result<f32, Value> {
+        check_equal_len(x, y)?;
+        Ok(sdot(x, y))
+    }
+
     /// Return the dot product of `x` and `y`.
+    ///
+    /// Panics if `x` and `y` do not have the same length, see [`try_ddot`].
     #[doc(alias = "cblas_ddot")]
     pub fn ddot<T: Vector<f64> + ?Sized>(x: &T, y: &T) -> f64 {
         check_equal_len(x, y).expect("The length of `x` and `y` must be equal");
         unsafe { sys::cblas_ddot(len(x), as_ptr(x), stride(x), as_ptr(y), stride(y)) }
     }
 
+    /// Same as [`ddot`], returning [`Value::BadLength`] instead of panicking if `x` and `y`
+    /// do not have the same length.
+    pub fn try_ddot<T: Vector<f64> + ?Sized>(x: &T, y: &T) -> Result<f64, Value> {
+        check_equal_len(x, y)?;
+        Ok(ddot(x, y))
+    }
+
     #[cfg(feature = "complex")]
     /// Return the unconjugated dot product between `x` and `y`, that
     /// is ∑ xᵢ yᵢ.
     ///
+    /// Panics if `x` and `y` do not have the same length, see [`try_cdotu`].
+    ///
     /// # Example
     ///
     /// ```
@@ -67,10 +106,23 @@ pub mod level1 {
         dotu
     }
 
+    #[cfg(feature = "complex")]
+    /// Same as [`cdotu`], returning [`Value::BadLength`] instead of panicking if `x` and `y`
+    /// do not have the same length.
+    pub fn try_cdotu<T>(x: &T, y: &T) -> Result<Complex<f32>, Value>
+    where
+        T: Vector<Complex<f32>> + ?Sized,
+    {
+        check_equal_len(x, y)?;
+        Ok(cdotu(x, y))
+    }
+
     #[cfg(feature = "complex")]
     /// Return the (conjugated) dot product between `x` and `y`, that
     /// is ∑ x̅ᵢ yᵢ.
     ///
+    /// Panics if `x` and `y` do not have the same length, see [`try_cdotc`].
+    ///
     /// # Example
     ///
     /// ```
@@ -99,10 +151,23 @@ pub mod level1 {
         dotc
     }
 
+    #[cfg(feature = "complex")]
+    /// Same as [`cdotc`], returning [`Value::BadLength`] instead of panicking if `x` and `y`
+    /// do not have the same length.
+    pub fn try_cdotc<T>(x: &T, y: &T) -> Result<Complex<f32>, Value>
+    where
+        T: Vector<Complex<f32>> + ?Sized,
+    {
+        check_equal_len(x, y)?;
+        Ok(cdotc(x, y))
+    }
+
     #[cfg(feature = "complex")]
     /// Return the unconjugated dot product between `x` and `y`, that
     /// is ∑ xᵢ yᵢ.
     ///
+    /// Panics if `x` and `y` do not have the same length, see [`try_zdotu`].
+    ///
     /// # Example
     ///
     /// ```
@@ -131,10 +196,23 @@ pub mod level1 {
         dotu
     }
 
+    #[cfg(feature = "complex")]
+    /// Same as [`zdotu`], returning [`Value::BadLength`] instead of panicking if `x` and `y`
+    /// do not have the same length.
+    pub fn try_zdotu<T>(x: &T, y: &T) -> Result<Complex<f64>, Value>
+    where
+        T: Vector<Complex<f64>> + ?Sized,
+    {
+        check_equal_len(x, y)?;
+        Ok(zdotu(x, y))
+    }
+
     #[cfg(feature = "complex")]
     /// Return the (conjugated) dot product between `x` and `y`, that
     /// is ∑ x̅ᵢ yᵢ.
     ///
+    /// Panics if `x` and `y` do not have the same length, see [`try_zdotc`].
+    ///
     /// # Example
     ///
     /// ```
@@ -163,6 +241,17 @@ pub mod level1 {
         dotc
     }
 
+    #[cfg(feature = "complex")]
+    /// Same as [`zdotc`], returning [`Value::BadLength`] instead of panicking if `x` and `y`
+    /// do not have the same length.
+    pub fn try_zdotc<T>(x: &T, y: &T) -> Result<Complex<f64>, Value>
+    where
+        T: Vector<Complex<f64>> + ?Sized,
+    {
+        check_equal_len(x, y)?;
+        Ok(zdotc(x, y))
+    }
+
     /// Return the Euclidean norm of `x`.
     #[doc(alias = "cblas_snrm2")]
     pub fn snrm2<T: Vector<f32> + ?Sized>(x: &T) -> f32 {
@@ -264,6 +353,8 @@ pub mod level1 {
     }
 
     /// Swap vectors `x` and `y`.
+    ///
+    /// Panics if `x` and `y` do not have the same length, see [`try_sswap`].
     #[doc(alias = "cblas_sswap")]
     pub fn sswap<T1, T2>(x: &mut T1, y: &mut T2)
     where
@@ -274,7 +365,21 @@ pub mod level1 {
         unsafe { sys::cblas_sswap(len(x), as_mut_ptr(x), stride(x), as_mut_ptr(y), stride(y)) }
     }
 
+    /// Same as [`sswap`], returning [`Value::BadLength`] instead of panicking if `x` and `y`
+    /// do not have the same length.
+    pub fn try_sswap<T1, T2>(x: &mut T1, y: &mut T2) -> Result<(), Value>
+    where
+        T1: VectorMut<f32> + ?Sized,
+        T2: VectorMut<f32> + ?Sized,
+    {
+        check_equal_len(x, y)?;
+        sswap(x, y);
+        Ok(())
+    }
+
     /// Copy the content of `x` into `y`.
+    ///
+    /// Panics if `x` and `y` do not have the same length, see [`try_scopy`].
     #[doc(alias = "cblas_scopy")]
     pub fn scopy<T1, T2>(x: &T1, y: &mut T2)
     where
@@ -285,7 +390,21 @@ pub mod level1 {
         unsafe { sys::cblas_scopy(len(x), as_ptr(x), stride(x), as_mut_ptr(y), stride(y)) }
     }
 
+    /// Same as [`scopy`], returning [`Value::BadLength`] instead of panicking if `x` and `y`
+    /// do not have the same length.
+    pub fn try_scopy<T1, T2>(x: &T1, y: &mut T2) -> Result<(), Value>
+    where
+        T1: Vector<f32> + ?Sized,
+        T2: VectorMut<f32> + ?Sized,
+    {
+        check_equal_len(x, y)?;
+        scopy(x, y);
+        Ok(())
+    }
+
     /// `y` := `alpha` * `x` + `y`.
+    ///
+    /// Panics if `x` and `y` do not have the same length, see [`try_saxpy`].
     #[doc(alias = "cblas_saxpy")]
     pub fn saxpy<T1, T2>(alpha: f32, x: &T1, y: &mut T2)
     where
@@ -305,7 +424,21 @@ pub mod level1 {
         }
     }
 
+    /// Same as [`saxpy`], returning [`Value::BadLength`] instead of panicking if `x` and `y`
+    /// do not have the same length.
+    pub fn try_saxpy<T1, T2>(alpha: f32, x: &T1, y: &mut T2) -> Result<(), Value>
+    where
+        T1: Vector<f32> + ?Sized,
+        T2: VectorMut<f32> + ?Sized,
+    {
+        check_equal_len(x, y)?;
+        saxpy(alpha, x, y);
+        Ok(())
+    }
+
     /// Swap vectors `x` and `y`.
+    ///
+    /// Panics if `x` and `y` do not have the same length, see [`try_dswap`].
     #[doc(alias = "cblas_dswap")]
     pub fn dswap<T1, T2>(x: &mut T1, y: &mut T2)
     where
@@ -316,7 +449,21 @@ pub mod level1 {
         unsafe { sys::cblas_dswap(len(x), as_mut_ptr(x), stride(x), as_mut_ptr(y), stride(y)) }
     }
 
+    /// Same as [`dswap`], returning [`Value::BadLength`] instead of panicking if `x` and `y`
+    /// do not have the same length.
+    pub fn try_dswap<T1, T2>(x: &mut T1, y: &mut T2) -> Result<(), Value>
+    where
+        T1: VectorMut<f64> + ?Sized,
+        T2: VectorMut<f64> + ?Sized,
+    {
+        check_equal_len(x, y)?;
+        dswap(x, y);
+        Ok(())
+    }
+
     /// Copy the content of `x` into `y`.
+    ///
+    /// Panics if `x` and `y` do not have the same length, see [`try_dcopy`].
     #[doc(alias = "cblas_dcopy")]
     pub fn dcopy<T1, T2>(x: &T1, y: &mut T2)
     where
@@ -327,7 +474,21 @@ pub mod level1 {
         unsafe { sys::cblas_dcopy(len(x), as_ptr(x), stride(x), as_mut_ptr(y), stride(y)) }
     }
 
+    /// Same as [`dcopy`], returning [`Value::BadLength`] instead of panicking if `x` and `y`
+    /// do not have the same length.
+    pub fn try_dcopy<T1, T2>(x: &T1, y: &mut T2) -> Result<(), Value>
+    where
+        T1: Vector<f64> + ?Sized,
+        T2: VectorMut<f64> + ?Sized,
+    {
+        check_equal_len(x, y)?;
+        dcopy(x, y);
+        Ok(())
+    }
+
     /// `y` := `alpha` * `x` + `y`.
+    ///
+    /// Panics if `x` and `y` do not have the same length, see [`try_daxpy`].
     #[doc(alias = "cblas_daxpy")]
     pub fn daxpy<T1, T2>(alpha: f64, x: &T1, y: &mut T2)
     where
@@ -347,8 +508,22 @@ pub mod level1 {
         }
     }
 
+    /// Same as [`daxpy`], returning [`Value::BadLength`] instead of panicking if `x` and `y`
+    /// do not have the same length.
+    pub fn try_daxpy<T1, T2>(alpha: f64, x: &T1, y: &mut T2) -> Result<(), Value>
+    where
+        T1: Vector<f64> + ?Sized,
+        T2: VectorMut<f64> + ?Sized,
+    {
+        check_equal_len(x, y)?;
+        daxpy(alpha, x, y);
+        Ok(())
+    }
+
     #[cfg(feature = "complex")]
     /// Swap vectors `x` and `y`.
+    ///
+    /// Panics if `x` and `y` do not have the same length, see [`try_cswap`].
     #[doc(alias = "cblas_cswap")]
     pub fn cswap<T1, T2>(x: &mut T1, y: &mut T2)
     where
@@ -367,8 +542,23 @@ pub mod level1 {
         }
     }
 
+    #[cfg(feature = "complex")]
+    /// Same as [`cswap`], returning [`Value::BadLength`] instead of panicking if `x` and `y`
+    /// do not have the same length.
+    pub fn try_cswap<T1, T2>(x: &mut T1, y: &mut T2) -> Result<(), Value>
+    where
+        T1: VectorMut<Complex<f32>> + ?Sized,
+        T2: VectorMut<Complex<f32>> + ?Sized,
+    {
+        check_equal_len(x, y)?;
+        cswap(x, y);
+        Ok(())
+    }
+
     #[cfg(feature = "complex")]
     /// Copy the content of `x` into `y`.
+    ///
+    /// Panics if `x` and `y` do not have the same length, see [`try_ccopy`].
     #[doc(alias = "cblas_ccopy")]
     pub fn ccopy<T1, T2>(x: &T1, y: &mut T2)
     where
@@ -387,8 +577,23 @@ pub mod level1 {
         }
     }
 
+    #[cfg(feature = "complex")]
+    /// Same as [`ccopy`], returning [`Value::BadLength`] instead of panicking if `x` and `y`
+    /// do not have the same length.
+    pub fn try_ccopy<T1, T2>(x: &T1, y: &mut T2) -> Result<(), Value>
+    where
+        T1: Vector<Complex<f32>> + ?Sized,
+        T2: VectorMut<Complex<f32>> + ?Sized,
+    {
+        check_equal_len(x, y)?;
+        ccopy(x, y);
+        Ok(())
+    }
+
     #[cfg(feature = "complex")]
     /// `y` := `alpha` * `x` + `y`.
+    ///
+    /// Panics if `x` and `y` do not have the same length, see [`try_caxpy`].
     #[doc(alias = "cblas_caxpy")]
     pub fn caxpy<T1, T2>(alpha: &Complex<f32>, x: &T1, y: &mut T2)
     where
@@ -408,8 +613,23 @@ pub mod level1 {
         }
     }
 
+    #[cfg(feature = "complex")]
+    /// Same as [`caxpy`], returning [`Value::BadLength`] instead of panicking if `x` and `y`
+    /// do not have the same length.
+    pub fn try_caxpy<T1, T2>(alpha: &Complex<f32>, x: &T1, y: &mut T2) -> Result<(), Value>
+    where
+        T1: Vector<Complex<f32>> + ?Sized,
+        T2: VectorMut<Complex<f32>> + ?Sized,
+    {
+        check_equal_len(x, y)?;
+        caxpy(alpha, x, y);
+        Ok(())
+    }
+
     #[cfg(feature = "complex")]
     /// Swap vectors `x` and `y`.
+    ///
+    /// Panics if `x` and `y` do not have the same length, see [`try_zswap`].
     #[doc(alias = "cblas_zswap")]
     pub fn zswap<T1, T2>(x: &mut T1, y: &mut T2)
     where
@@ -428,8 +648,23 @@ pub mod level1 {
         }
     }
 
+    #[cfg(feature = "complex")]
+    /// Same as [`zswap`], returning [`Value::BadLength`] instead of panicking if `x` and `y`
+    /// do not have the same length.
+    pub fn try_zswap<T1, T2>(x: &mut T1, y: &mut T2) -> Result<(), Value>
+    where
+        T1: VectorMut<Complex<f64>> + ?Sized,
+        T2: VectorMut<Complex<f64>> + ?Sized,
+    {
+        check_equal_len(x, y)?;
+        zswap(x, y);
+        Ok(())
+    }
+
     #[cfg(feature = "complex")]
     /// Copy the content of `x` into `y`.
+    ///
+    /// Panics if `x` and `y` do not have the same length, see [`try_zcopy`].
     #[doc(alias = "cblas_zcopy")]
     pub fn zcopy<T1, T2>(x: &T1, y: &mut T2)
     where
@@ -448,8 +683,23 @@ pub mod level1 {
         }
     }
 
+    #[cfg(feature = "complex")]
+    /// Same as [`zcopy`], returning [`Value::BadLength`] instead of panicking if `x` and `y`
+    /// do not have the same length.
+    pub fn try_zcopy<T1, T2>(x: &T1, y: &mut T2) -> Result<(), Value>
+    where
+        T1: Vector<Complex<f64>> + ?Sized,
+        T2: VectorMut<Complex<f64>> + ?Sized,
+    {
+        check_equal_len(x, y)?;
+        zcopy(x, y);
+        Ok(())
+    }
+
     #[cfg(feature = "complex")]
     /// `y` := `alpha` * `x` + `y`.
+    ///
+    /// Panics if `x` and `y` do not have the same length, see [`try_zaxpy`].
     #[doc(alias = "cblas_zaxpy")]
     pub fn zaxpy<T1, T2>(alpha: &Complex<f64>, x: &T1, y: &mut T2)
     where
@@ -469,6 +719,19 @@ pub mod level1 {
         }
     }
 
+    #[cfg(feature = "complex")]
+    /// Same as [`zaxpy`], returning [`Value::BadLength`] instead of panicking if `x` and `y`
+    /// do not have the same length.
+    pub fn try_zaxpy<T1, T2>(alpha: &Complex<f64>, x: &T1, y: &mut T2) -> Result<(), Value>
+    where
+        T1: Vector<Complex<f64>> + ?Sized,
+        T2: VectorMut<Complex<f64>> + ?Sized,
+    {
+        check_equal_len(x, y)?;
+        zaxpy(alpha, x, y);
+        Ok(())
+    }
+
     /// Given the Cartesian coordinates (`a`, `b`), returns
     /// (c, s, r, z) such that
     ///
@@ -578,6 +841,8 @@ pub mod level1 {
     /// ⎩`y`ᵢ⎭   ⎩-`s` `c`⎭ ⎩`y`ᵢ⎭
     ///
     /// for all indices i.
+    ///
+    /// Panics if `x` and `y` do not have the same length, see [`try_srot`].
     #[doc(alias = "cblas_srot")]
     pub fn srot<T>(x: &mut T, y: &mut T, c: f32, s: f32)
     where
@@ -597,12 +862,25 @@ pub mod level1 {
         }
     }
 
+    /// Same as [`srot`], returning [`Value::BadLength`] instead of panicking if `x` and `y`
+    /// do not have the same length.
+    pub fn try_srot<T>(x: &mut T, y: &mut T, c: f32, s: f32) -> Result<(), Value>
+    where
+        T: VectorMut<f32> + ?Sized,
+    {
+        check_equal_len(x, y)?;
+        srot(x, y, c, s);
+        Ok(())
+    }
+
     /// Apply the matrix rotation `h` to `x`, `y`.
     ///
     /// ⎧`x`ᵢ⎫ = `h` ⎧`x`ᵢ⎫
     /// ⎩`y`ᵢ⎭       ⎩`y`ᵢ⎭
     ///
     /// for all indices i.
+    ///
+    /// Panics if `x` and `y` do not have the same length, see [`try_srotm`].
     #[doc(alias = "cblas_srotm")]
     pub fn srotm<T>(x: &mut T, y: &mut T, h: H<f32>)
     where
@@ -627,6 +905,17 @@ pub mod level1 {
         }
     }
 
+    /// Same as [`srotm`], returning [`Value::BadLength`] instead of panicking if `x` and `y`
+    /// do not have the same length.
+    pub fn try_srotm<T>(x: &mut T, y: &mut T, h: H<f32>) -> Result<(), Value>
+    where
+        T: VectorMut<f32> + ?Sized,
+    {
+        check_equal_len(x, y)?;
+        srotm(x, y, h);
+        Ok(())
+    }
+
     /// Given the Cartesian coordinates (`a`, `b`), returns
     /// (c, s, r, z) such that
     ///
@@ -704,6 +993,8 @@ pub mod level1 {
     /// ⎩`y`ᵢ⎭   ⎩-`s` `c`⎭ ⎩`y`ᵢ⎭
     ///
     /// for all indices i.
+    ///
+    /// Panics if `x` and `y` do not have the same length, see [`try_drot`].
     #[doc(alias = "cblas_drot")]
     pub fn drot<T>(x: &mut T, y: &mut T, c: f64, s: f64)
     where
@@ -723,12 +1014,25 @@ pub mod level1 {
         }
     }
 
+    /// Same as [`drot`], returning [`Value::BadLength`] instead of panicking if `x` and `y`
+    /// do not have the same length.
+    pub fn try_drot<T>(x: &mut T, y: &mut T, c: f64, s: f64) -> Result<(), Value>
+    where
+        T: VectorMut<f64> + ?Sized,
+    {
+        check_equal_len(x, y)?;
+        drot(x, y, c, s);
+        Ok(())
+    }
+
     /// Apply the matrix rotation `h` to `x`, `y`.
     ///
     /// ⎧`x`ᵢ⎫ = `h` ⎧`x`ᵢ⎫
     /// ⎩`y`ᵢ⎭       ⎩`y`ᵢ⎭
     ///
     /// for all indices i.
+    ///
+    /// Panics if `x` and `y` do not have the same length, see [`try_drotm`].
     #[doc(alias = "cblas_drotm")]
     pub fn drotm<T>(x: &mut T, y: &mut T, h: H<f64>)
     where
@@ -753,6 +1057,17 @@ pub mod level1 {
         }
     }
 
+    /// Same as [`drotm`], returning [`Value::BadLength`] instead of panicking if `x` and `y`
+    /// do not have the same length.
+    pub fn try_drotm<T>(x: &mut T, y: &mut T, h: H<f64>) -> Result<(), Value>
+    where
+        T: VectorMut<f64> + ?Sized,
+    {
+        check_equal_len(x, y)?;
+        drotm(x, y, h);
+        Ok(())
+    }
+
     /// Multiply each element of `x` by `alpha`.
     #[doc(alias = "cblas_sscal")]
     pub fn sscal<T>(alpha: f32, x: &mut T)
@@ -2,6 +2,11 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
+//! Elliptic integrals are available both in the Legendre form (see [`legendre`]) and in the
+//! Carlson symmetric form (see [`carlson`]). The two are related by, for instance,
+//!
+//! F(\phi,k) = \sin(\phi) RF(\cos^2(\phi), 1 - k^2 \sin^2(\phi), 1)
+//!
 //! Further information about the elliptic integrals can be found in Abramowitz & Stegun, Chapter 17.
 
 /// The Legendre forms of elliptic integrals F(\phi,k), E(\phi,k) and \Pi(\phi,k,n) are defined by,
@@ -277,6 +277,44 @@ impl From<sys::gsl_fft_direction> for FftDirection {
     }
 }
 
+/// Which part of the result of [`fft::convolve`](crate::fft::convolve) and
+/// [`fft::correlate`](crate::fft::correlate) to return.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum ConvolutionMode {
+    /// The full result, of length `a.len() + b.len() - 1`.
+    Full,
+    /// The central part of the result, of length `max(a.len(), b.len())`.
+    Same,
+}
+
+/// Window functions applied to a signal before computing its
+/// [`fft::periodogram`](crate::fft::periodogram), to reduce the spectral leakage.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum FftWindow {
+    /// No window: w_j = 1.
+    Rectangular,
+    /// w_j = 0.5 - 0.5 cos(2 \pi j / n)
+    Hann,
+    /// w_j = 0.54 - 0.46 cos(2 \pi j / n)
+    Hamming,
+    /// w_j = 0.42 - 0.5 cos(2 \pi j / n) + 0.08 cos(4 \pi j / n)
+    Blackman,
+}
+
+impl FftWindow {
+    /// Returns the j-th coefficient of the window of length `n`. The windows are periodic, as is
+    /// customary for spectral analysis.
+    pub fn coefficient(self, j: usize, n: usize) -> f64 {
+        let t = 2. * std::f64::consts::PI * j as f64 / n as f64;
+        match self {
+            Self::Rectangular => 1.,
+            Self::Hann => 0.5 - 0.5 * t.cos(),
+            Self::Hamming => 0.54 - 0.46 * t.cos(),
+            Self::Blackman => 0.42 - 0.5 * t.cos() + 0.08 * (2. * t).cos(),
+        }
+    }
+}
+
 /// The low-level integration rules in QUADPACK are identified by small integers (1-6). We'll use
 /// symbolic constants to refer to them.
 #[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
@@ -393,6 +431,36 @@ impl From<std::os::raw::c_int> for VegasMode {
     }
 }
 
+/// Stage at which [`VegasMonteCarlo::integrate`](crate::VegasMonteCarlo::integrate) starts,
+/// see [`VegasParams::stage`](crate::VegasParams::stage).
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum VegasStage {
+    /// Begins with a new uniform grid and an empty weighted average (stage 0).
+    NewGrid,
+    /// Keeps the grid of the previous run but discards the weighted average (stage 1), so that
+    /// the grid can be tuned with a small number of points before a large run.
+    KeepGrid,
+    /// Keeps the grid and the weighted average of the previous run (stage 2), possibly
+    /// changing the number of histogram bins depending on the number of calls available.
+    KeepAverage,
+    /// Enters the main loop without changing anything (stage 3), which is equivalent to
+    /// performing additional iterations of the previous run.
+    Continue,
+}
+
+#[doc(hidden)]
+#[allow(clippy::from_over_into)]
+impl Into<std::os::raw::c_int> for VegasStage {
+    fn into(self) -> std::os::raw::c_int {
+        match self {
+            Self::NewGrid => 0,
+            Self::KeepGrid => 1,
+            Self::KeepAverage => 2,
+            Self::Continue => 3,
+        }
+    }
+}
+
 /// Possible return values for an hadjust() evolution method for ordinary differential equations
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
@@ -457,6 +525,26 @@ impl From<sys::gsl_wavelet_direction> for WaveletDirection {
     }
 }
 
+/// Thresholding rules applied to the detail coefficients by
+/// [`wavelet_transforms::denoise`](crate::wavelet_transforms::denoise).
+///
+/// The universal threshold is σ sqrt(2 ln n), where n is the length of the signal and the noise
+/// level σ is estimated as the median absolute value of the finest detail coefficients divided
+/// by 0.6745.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum WaveletThreshold {
+    /// Hard thresholding with the given threshold: coefficients smaller than it in absolute
+    /// value are set to zero, the others are kept.
+    Hard(f64),
+    /// Soft thresholding with the given threshold: coefficients are shrunk towards zero by the
+    /// threshold, those smaller than it in absolute value being set to zero.
+    Soft(f64),
+    /// Hard thresholding with the universal threshold.
+    UniversalHard,
+    /// Soft thresholding with the universal threshold.
+    UniversalSoft,
+}
+
 #[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
 pub enum SfLegendreNorm {
     Schmidt,
@@ -669,6 +757,46 @@ impl From<sys::gsl_filter_end_t> for FilterEnd {
     }
 }
 
+/// How the end points of a signal are handled by the moving window
+/// statistics, see [`MovstatWorkspace`](crate::MovstatWorkspace).
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum MovstatEnd {
+    /// The input signal is padded with zeros at each end.
+    PadZero,
+    /// The input signal is padded with its first and last values.
+    PadValue,
+    /// The windows near the end points are truncated.
+    Truncate,
+}
+
+#[doc(hidden)]
+#[allow(clippy::from_over_into)]
+#[cfg(feature = "v2_5")]
+impl Into<sys::gsl_movstat_end_t> for MovstatEnd {
+    fn into(self) -> sys::gsl_movstat_end_t {
+        match self {
+            Self::PadZero => sys::gsl_movstat_end_t_GSL_MOVSTAT_END_PADZERO,
+            Self::PadValue => sys::gsl_movstat_end_t_GSL_MOVSTAT_END_PADVALUE,
+            Self::Truncate => sys::gsl_movstat_end_t_GSL_MOVSTAT_END_TRUNCATE,
+        }
+    }
+}
+
+#[doc(hidden)]
+#[cfg(feature = "v2_5")]
+impl From<sys::gsl_movstat_end_t> for MovstatEnd {
+    fn from(v: sys::gsl_movstat_end_t) -> MovstatEnd {
+        match v {
+            sys::gsl_movstat_end_t_GSL_MOVSTAT_END_PADZERO => Self::PadZero,
+            sys::gsl_movstat_end_t_GSL_MOVSTAT_END_PADVALUE => Self::PadValue,
+            sys::gsl_movstat_end_t_GSL_MOVSTAT_END_TRUNCATE => Self::Truncate,
+            _ => panic!("Unknown MovstatEnd value"),
+        }
+    }
+}
+
 #[cfg(feature = "v2_5")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 #[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
@@ -706,3 +834,83 @@ impl From<sys::gsl_filter_scale_t> for FilterScale {
         }
     }
 }
+
+/// Finite difference scheme used to approximate the Jacobian matrix (and the second directional
+/// derivative `fvv` when geodesic acceleration is enabled) when it is not supplied by the user.
+/// See [`MultiFitNlinearParameters`](crate::MultiFitNlinearParameters).
+#[cfg(feature = "v2_2")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum MultiFitNlinearFdType {
+    /// Use forward finite differences, `J_ij = (f_i(x + h e_j) - f_i(x)) / h`.
+    ForwardDiff,
+    /// Use centered finite differences, `J_ij = (f_i(x + h/2 e_j) - f_i(x - h/2 e_j)) / h`.
+    /// This is more accurate but requires twice as many function evaluations.
+    CentralDiff,
+}
+
+#[doc(hidden)]
+#[allow(clippy::from_over_into)]
+#[cfg(feature = "v2_2")]
+impl Into<sys::gsl_multifit_nlinear_fdtype> for MultiFitNlinearFdType {
+    fn into(self) -> sys::gsl_multifit_nlinear_fdtype {
+        match self {
+            Self::ForwardDiff => sys::gsl_multifit_nlinear_fdtype_GSL_MULTIFIT_NLINEAR_FWDIFF,
+            Self::CentralDiff => sys::gsl_multifit_nlinear_fdtype_GSL_MULTIFIT_NLINEAR_CTRDIFF,
+        }
+    }
+}
+
+#[doc(hidden)]
+#[cfg(feature = "v2_2")]
+impl From<sys::gsl_multifit_nlinear_fdtype> for MultiFitNlinearFdType {
+    fn from(v: sys::gsl_multifit_nlinear_fdtype) -> MultiFitNlinearFdType {
+        match v {
+            sys::gsl_multifit_nlinear_fdtype_GSL_MULTIFIT_NLINEAR_FWDIFF => Self::ForwardDiff,
+            sys::gsl_multifit_nlinear_fdtype_GSL_MULTIFIT_NLINEAR_CTRDIFF => Self::CentralDiff,
+            _ => panic!("Unknown MultiFitNlinearFdType value"),
+        }
+    }
+}
+
+/// The branch of the Lambert W function to evaluate with
+/// [`lambert_w`](crate::lambert_w::lambert_w).
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum Branch {
+    /// The principal branch W_0(x), real-valued for x >= -1/e, where W >= -1.
+    Principal,
+    /// The secondary real branch W_{-1}(x), real-valued for -1/e <= x < 0, where W <= -1.
+    Minus1,
+    /// Any other branch W_k(x), k ∉ {0, -1}. These branches are complex-valued for every real x
+    /// and are not supported: [`lambert_w`](crate::lambert_w::lambert_w) returns
+    /// [`Value::Unimplemented`].
+    Other(i32),
+}
+
+impl Branch {
+    /// Returns the branch corresponding to the index `k`, as used by `scipy.special.lambertw`.
+    pub fn from_index(k: i32) -> Branch {
+        match k {
+            0 => Branch::Principal,
+            -1 => Branch::Minus1,
+            k => Branch::Other(k),
+        }
+    }
+}
+
+/// Algorithms available to sample the Gaussian distribution, see
+/// [`Rng::gaussian_with`](crate::Rng::gaussian_with).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GaussianMethod {
+    /// The Box-Muller algorithm, used by [`Rng::gaussian`](crate::Rng::gaussian). It requires
+    /// two calls to the random number generator.
+    #[default]
+    BoxMuller,
+    /// The Marsaglia-Tsang ziggurat method, used by
+    /// [`Rng::gaussian_ziggurat`](crate::Rng::gaussian_ziggurat). It is the fastest algorithm
+    /// in most cases.
+    Ziggurat,
+    /// The Kinderman-Monahan-Leva ratio method, used by
+    /// [`Rng::gaussian_ratio_method`](crate::Rng::gaussian_ratio_method).
+    RatioMethod,
+}
@@ -5,9 +5,12 @@
 //! The error function is described in Abramowitz & Stegun, Chapter 7.
 
 use crate::{types, Value};
+use std::cell::RefCell;
 use std::ffi::CStr;
+use std::fmt;
 use std::mem::MaybeUninit;
 use std::os::raw::{c_char, c_int};
+use std::sync::{Mutex, PoisonError};
 
 /// This routine computes the error function erf(x), where erf(x) = (2/\sqrt(\pi)) \int_0^x dt \exp(-t^2).
 #[doc(alias = "gsl_sf_erf")]
@@ -156,7 +159,17 @@ pub fn str_error(error: crate::Value) -> &'static str {
     }
 }
 
-static mut CALLBACK: Option<fn(&str, &str, u32, crate::Value)> = None;
+static CALLBACK: Mutex<Option<fn(&str, &str, u32, crate::Value)>> = Mutex::new(None);
+
+// Replaces the handler called by `inner_error_handler`, returning the previous one.
+fn replace_callback(
+    f: Option<fn(&str, &str, u32, crate::Value)>,
+) -> Option<fn(&str, &str, u32, crate::Value)> {
+    std::mem::replace(
+        &mut *CALLBACK.lock().unwrap_or_else(PoisonError::into_inner),
+        f,
+    )
+}
 
 /// `f` is the type of GSL error handler functions. An error handler will be passed four arguments
 /// which specify the reason for the error (a string), the name of the source file in which it
@@ -200,19 +213,18 @@ static mut CALLBACK: Option<fn(&str, &str, u32, crate::Value)> = None;
 pub fn set_error_handler(
     f: Option<fn(&str, &str, u32, crate::Value)>,
 ) -> Option<fn(&str, &str, u32, crate::Value)> {
+    let out = replace_callback(f);
     unsafe {
-        let out = CALLBACK.take();
         match f {
-            Some(f) => {
-                CALLBACK = Some(f);
+            Some(_) => {
                 sys::gsl_set_error_handler(Some(inner_error_handler));
             }
             None => {
                 sys::gsl_set_error_handler(None);
             }
         }
-        out
     }
+    out
 }
 
 /// This function turns off the error handler by defining an error handler which does nothing. This
@@ -223,8 +235,173 @@ pub fn set_error_handler(
 pub fn set_error_handler_off() -> Option<fn(&str, &str, u32, crate::Value)> {
     unsafe {
         sys::gsl_set_error_handler_off();
-        CALLBACK.take()
     }
+    replace_callback(None)
+}
+
+/// Restores the error handler that was active when it was created once it is dropped, including
+/// when unwinding from a panic.
+///
+/// Guards restore the handlers in the order they are dropped, so nested guards must be dropped
+/// in the reverse order of their creation, which is what happens when they are bound to local
+/// variables. As for [`set_error_handler`], the handler is global to the program: guards must
+/// not be used from several threads at the same time, since a thread would restore the handler
+/// while another one still relies on its own, and the functions using guards, such as
+/// [`with_handler_off`] and [`with_error_context`], must not run concurrently either.
+///
+/// ```
+/// use rgsl::error::HandlerGuard;
+///
+/// {
+///     let _guard = HandlerGuard::off();
+///     // Errors are only reported through the return values here.
+/// }
+/// // The previous handler is back.
+/// ```
+#[must_use = "the previous handler is restored as soon as the guard is dropped"]
+pub struct HandlerGuard {
+    handler: sys::gsl_error_handler_t,
+    callback: Option<fn(&str, &str, u32, crate::Value)>,
+}
+
+impl HandlerGuard {
+    /// Installs the error handler `f`, see [`set_error_handler`], until the guard is dropped.
+    /// `None` installs the default handler, which aborts the program.
+    pub fn new(f: Option<fn(&str, &str, u32, crate::Value)>) -> HandlerGuard {
+        let callback = replace_callback(f);
+        let handler = unsafe {
+            match f {
+                Some(_) => sys::gsl_set_error_handler(Some(inner_error_handler)),
+                None => sys::gsl_set_error_handler(None),
+            }
+        };
+        HandlerGuard { handler, callback }
+    }
+
+    /// Turns off the error handler, see [`set_error_handler_off`], until the guard is dropped.
+    pub fn off() -> HandlerGuard {
+        let callback = replace_callback(None);
+        let handler = unsafe { sys::gsl_set_error_handler_off() };
+        HandlerGuard { handler, callback }
+    }
+}
+
+impl Drop for HandlerGuard {
+    fn drop(&mut self) {
+        replace_callback(self.callback.take());
+        unsafe {
+            sys::gsl_set_error_handler(self.handler);
+        }
+    }
+}
+
+/// Runs `f` with the error handler turned off and restores the previous handler afterwards, even
+/// if `f` panics. The errors must then be handled through the return values of the library
+/// routines.
+///
+/// As the handler is global, this should not be used while other threads call the library, see
+/// [`HandlerGuard`].
+///
+/// ```
+/// use rgsl::{bessel, error, Value};
+///
+/// let res = error::with_handler_off(|| bessel::K0_e(1e3));
+/// assert!(matches!(res, Err(Value::UnderFlow)));
+/// ```
+pub fn with_handler_off<T, F: FnOnce() -> T>(f: F) -> T {
+    let _guard = HandlerGuard::off();
+    f()
+}
+
+/// An error value together with the context given by GSL when it raised it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErrorWithContext {
+    /// The error.
+    pub value: Value,
+    /// The reason given by GSL or, if the error was not raised through the error handler, the
+    /// generic description returned by [`str_error`].
+    pub reason: String,
+    /// The source file and line where GSL raised the error, if it did.
+    pub location: Option<(String, u32)>,
+}
+
+impl ErrorWithContext {
+    fn without_context(value: Value) -> ErrorWithContext {
+        ErrorWithContext {
+            value,
+            reason: str_error(value).to_owned(),
+            location: None,
+        }
+    }
+}
+
+impl fmt::Display for ErrorWithContext {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.location {
+            Some((ref file, line)) => {
+                write!(f, "{}:{}: {} ({:?})", file, line, self.reason, self.value)
+            }
+            None => write!(f, "{} ({:?})", self.reason, self.value),
+        }
+    }
+}
+
+impl std::error::Error for ErrorWithContext {}
+
+impl From<ErrorWithContext> for Value {
+    fn from(e: ErrorWithContext) -> Value {
+        e.value
+    }
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<ErrorWithContext>> = const { RefCell::new(None) };
+}
+
+/// An error handler, to be installed with [`set_error_handler`] or [`HandlerGuard::new`], which
+/// records the errors instead of aborting. The last error raised by the current thread can then
+/// be retrieved with [`take_last_error`].
+pub fn capture_error(reason: &str, file: &str, line: u32, value: Value) {
+    LAST_ERROR.with(|e| {
+        *e.borrow_mut() = Some(ErrorWithContext {
+            value,
+            reason: reason.to_owned(),
+            location: Some((file.to_owned(), line)),
+        })
+    });
+}
+
+/// Returns the last error recorded by [`capture_error`] in the current thread, and forgets it.
+pub fn take_last_error() -> Option<ErrorWithContext> {
+    LAST_ERROR.with(|e| e.borrow_mut().take())
+}
+
+/// Runs `f` with [`capture_error`] as error handler and attaches the reason, file and line given
+/// by GSL to the error returned by `f`. The previous handler is restored afterwards.
+///
+/// If the error was not raised through the error handler, for instance because it was detected
+/// by the Rust wrappers, its context only holds the generic description of the error.
+///
+/// As the handler is global, this should not be used while other threads call the library.
+///
+/// ```
+/// use rgsl::{bessel, error, Value};
+///
+/// let err = error::with_error_context(|| bessel::K0_e(1e3)).unwrap_err();
+/// assert_eq!(err.value, Value::UnderFlow);
+/// assert!(err.location.is_some());
+/// println!("{}", err);
+/// ```
+pub fn with_error_context<T, F: FnOnce() -> Result<T, Value>>(f: F) -> Result<T, ErrorWithContext> {
+    take_last_error();
+    let guard = HandlerGuard::new(Some(capture_error));
+    let res = f();
+    drop(guard);
+    let last = take_last_error();
+    res.map_err(|value| match last {
+        Some(e) if e.value == value => e,
+        _ => ErrorWithContext::without_context(value),
+    })
 }
 
 extern "C" fn inner_error_handler(
@@ -233,8 +410,10 @@ extern "C" fn inner_error_handler(
     line: c_int,
     gsl_errno: c_int,
 ) {
+    // The lock is released before calling the handler, which may change it.
+    let callback = *CALLBACK.lock().unwrap_or_else(PoisonError::into_inner);
     unsafe {
-        if let Some(ref call) = CALLBACK {
+        if let Some(call) = callback {
             let s = CStr::from_ptr(reason);
             let f = CStr::from_ptr(file);
             call(
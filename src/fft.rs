@@ -159,6 +159,9 @@ The mixed-radix algorithm does not have this restriction, and it stores the real
 is desirable for better locality of memory accesses).
 !*/
 
+use crate::vector::Vector;
+use crate::{ConvolutionMode, FftPlan, FftWindow, Value};
+
 /// These functions compute forward, backward and inverse FFTs of length n with stride stride, on the packed complex array data using an in-place radix-2
 /// decimation-in-time algorithm. The length of the transform is restricted to powers of two. For the transform version of the function
 /// the sign argument can be either forward (-1) or backward (+1).
@@ -234,6 +237,115 @@ pub mod radix2 {
     }
 }
 
+/// Same as [`radix2`] for packed arrays of single precision complex numbers.
+///
+/// Returns [`Value::BadLength`] if `data` is shorter than `2 * (stride * (n - 1) + 1)`.
+pub mod radix2_float {
+    use crate::Value;
+
+    // GSL does not know the length of `data`: check that the `n` complex numbers (with stride
+    // `stride`) fit in it.
+    fn check_len(data: &[f32], stride: usize, n: usize) -> Result<(), Value> {
+        if n == 0 {
+            // Rejected by GSL without accessing `data`.
+            return Ok(());
+        }
+        match (n - 1)
+            .checked_mul(stride)
+            .and_then(|m| m.checked_add(1))
+            .and_then(|m| m.checked_mul(2))
+        {
+            Some(len) if len <= data.len() => Ok(()),
+            _ => Err(Value::BadLength),
+        }
+    }
+
+    #[doc(alias = "gsl_fft_complex_float_radix2_forward")]
+    pub fn forward(data: &mut [f32], stride: usize, n: usize) -> Result<(), Value> {
+        check_len(data, stride, n)?;
+        let ret =
+            unsafe { sys::gsl_fft_complex_float_radix2_forward(data.as_mut_ptr(), stride, n) };
+        result_handler!(ret, ())
+    }
+
+    #[doc(alias = "gsl_fft_complex_float_radix2_transform")]
+    pub fn transform(
+        data: &mut [f32],
+        stride: usize,
+        n: usize,
+        sign: crate::FftDirection,
+    ) -> Result<(), Value> {
+        check_len(data, stride, n)?;
+        let ret = unsafe {
+            sys::gsl_fft_complex_float_radix2_transform(data.as_mut_ptr(), stride, n, sign.into())
+        };
+        result_handler!(ret, ())
+    }
+
+    #[doc(alias = "gsl_fft_complex_float_radix2_backward")]
+    pub fn backward(data: &mut [f32], stride: usize, n: usize) -> Result<(), Value> {
+        check_len(data, stride, n)?;
+        let ret =
+            unsafe { sys::gsl_fft_complex_float_radix2_backward(data.as_mut_ptr(), stride, n) };
+        result_handler!(ret, ())
+    }
+
+    #[doc(alias = "gsl_fft_complex_float_radix2_inverse")]
+    pub fn inverse(data: &mut [f32], stride: usize, n: usize) -> Result<(), Value> {
+        check_len(data, stride, n)?;
+        let ret =
+            unsafe { sys::gsl_fft_complex_float_radix2_inverse(data.as_mut_ptr(), stride, n) };
+        result_handler!(ret, ())
+    }
+
+    /// This is decimation-in-frequency version of the radix-2 FFT function.
+    #[doc(alias = "gsl_fft_complex_float_radix2_dif_forward")]
+    pub fn dif_forward(data: &mut [f32], stride: usize, n: usize) -> Result<(), Value> {
+        check_len(data, stride, n)?;
+        let ret =
+            unsafe { sys::gsl_fft_complex_float_radix2_dif_forward(data.as_mut_ptr(), stride, n) };
+        result_handler!(ret, ())
+    }
+
+    /// This is decimation-in-frequency version of the radix-2 FFT function.
+    #[doc(alias = "gsl_fft_complex_float_radix2_dif_transform")]
+    pub fn dif_transform(
+        data: &mut [f32],
+        stride: usize,
+        n: usize,
+        sign: crate::FftDirection,
+    ) -> Result<(), Value> {
+        check_len(data, stride, n)?;
+        let ret = unsafe {
+            sys::gsl_fft_complex_float_radix2_dif_transform(
+                data.as_mut_ptr(),
+                stride,
+                n,
+                sign.into(),
+            )
+        };
+        result_handler!(ret, ())
+    }
+
+    /// This is decimation-in-frequency version of the radix-2 FFT function.
+    #[doc(alias = "gsl_fft_complex_float_radix2_dif_backward")]
+    pub fn dif_backward(data: &mut [f32], stride: usize, n: usize) -> Result<(), Value> {
+        check_len(data, stride, n)?;
+        let ret =
+            unsafe { sys::gsl_fft_complex_float_radix2_dif_backward(data.as_mut_ptr(), stride, n) };
+        result_handler!(ret, ())
+    }
+
+    /// This is decimation-in-frequency version of the radix-2 FFT function.
+    #[doc(alias = "gsl_fft_complex_float_radix2_dif_inverse")]
+    pub fn dif_inverse(data: &mut [f32], stride: usize, n: usize) -> Result<(), Value> {
+        check_len(data, stride, n)?;
+        let ret =
+            unsafe { sys::gsl_fft_complex_float_radix2_dif_inverse(data.as_mut_ptr(), stride, n) };
+        result_handler!(ret, ())
+    }
+}
+
 /// This section describes radix-2 FFT algorithms for real data. They use the Cooley-Tukey algorithm to compute in-place FFTs for lengths which
 /// are a power of 2.
 pub mod real_radix2 {
@@ -339,3 +451,420 @@ pub mod real_radix2 {
         result_handler!(ret, ())
     }
 }
+
+macro_rules! fft_real_modules {
+    (
+        @hc $ty:ident, $hc_wavetable:ident, $workspace:ident, $hc:ident,
+        $(#[$attr:meta])* $name:ident
+    ) => {
+        paste::paste! {
+            $(#[$attr])*
+            #[doc(alias = $hc _ $name)]
+            pub fn $name<V: VectorMut<$ty> + ?Sized>(
+                data: &mut V,
+                wavetable: &$hc_wavetable,
+                work: &mut $workspace,
+            ) -> Result<(), Value> {
+                let (stride, n) = (V::stride(data), V::len(data));
+                let ret = unsafe {
+                    sys::[<$hc _ $name>](
+                        V::as_mut_slice(data).as_mut_ptr(),
+                        stride,
+                        n,
+                        wavetable.unwrap_shared(),
+                        work.unwrap_unique(),
+                    )
+                };
+                result_handler!(ret, ())
+            }
+        }
+    };
+    (@hc_radix2 $ty:ident, $hc:ident, $(#[$attr:meta])* $name:ident) => {
+        paste::paste! {
+            $(#[$attr])*
+            #[doc(alias = $hc _radix2_ $name)]
+            pub fn [<radix2_ $name>]<V: VectorMut<$ty> + ?Sized>(
+                data: &mut V,
+            ) -> Result<(), Value> {
+                let (stride, n) = (V::stride(data), V::len(data));
+                let ret = unsafe {
+                    sys::[<$hc _radix2_ $name>](V::as_mut_slice(data).as_mut_ptr(), stride, n)
+                };
+                result_handler!(ret, ())
+            }
+        }
+    };
+    (
+        $real_mod:ident, $hc_mod:ident, $ty:ident, $real_wavetable:ident, $hc_wavetable:ident,
+        $workspace:ident, $real:ident, $hc:ident
+    ) => {
+        paste::paste! {
+            #[doc = "Mixed-radix and radix-2 FFTs of real `" $ty "` data, producing half-complex"]
+            /// sequences. The data can be any [`VectorMut`](crate::vector::VectorMut), e.g. a
+            /// slice or a vector (view), whose length and stride are used for the transform.
+            ///
+            /// The half-complex output of the mixed-radix routines stores the real and imaginary
+            /// parts of each term in neighboring locations: for k < n/2 the real part of the k-th
+            /// term is stored in location 2k-1 and its imaginary part in location 2k, the k=0
+            /// (and, if n is even, k=n/2) terms being purely real and stored in locations 0 (and
+            /// n-1). See [`real_radix2`](crate::fft::real_radix2) for the layout used by the
+            /// radix-2 routines.
+            pub mod $real_mod {
+                use crate::ffi::FFI;
+                use crate::vector::VectorMut;
+                use crate::{$real_wavetable, $workspace, Value};
+
+                /// This function computes the FFT of `data`, a real array, using a mixed radix
+                /// decimation-in-frequency algorithm. There is no restriction on the length of
+                /// the data. The output is a half-complex sequence, which is stored in-place.
+                ///
+                /// The wavetable and the workspace must have been allocated for the length of
+                /// `data`.
+                #[doc(alias = $real _transform)]
+                pub fn transform<V: VectorMut<$ty> + ?Sized>(
+                    data: &mut V,
+                    wavetable: &$real_wavetable,
+                    work: &mut $workspace,
+                ) -> Result<(), Value> {
+                    let (stride, n) = (V::stride(data), V::len(data));
+                    let ret = unsafe {
+                        sys::[<$real _transform>](
+                            V::as_mut_slice(data).as_mut_ptr(),
+                            stride,
+                            n,
+                            wavetable.unwrap_shared(),
+                            work.unwrap_unique(),
+                        )
+                    };
+                    result_handler!(ret, ())
+                }
+
+                /// This function computes an in-place radix-2 FFT of `data`, a real array whose
+                /// length must be a power of 2. The output is a half-complex sequence stored in
+                /// the radix-2 layout.
+                #[doc(alias = $real _radix2_transform)]
+                pub fn radix2_transform<V: VectorMut<$ty> + ?Sized>(
+                    data: &mut V,
+                ) -> Result<(), Value> {
+                    let (stride, n) = (V::stride(data), V::len(data));
+                    let ret = unsafe {
+                        sys::[<$real _radix2_transform>](
+                            V::as_mut_slice(data).as_mut_ptr(),
+                            stride,
+                            n,
+                        )
+                    };
+                    result_handler!(ret, ())
+                }
+            }
+
+            #[doc = "Inverse and backward FFTs of half-complex `" $ty "` sequences, as produced"]
+            #[doc = "by [`" $real_mod "`](crate::fft::" $real_mod "), back to real data."]
+            /// The backward transform is the unscaled version of the inverse transform.
+            pub mod $hc_mod {
+                use crate::ffi::FFI;
+                use crate::vector::VectorMut;
+                #[cfg(feature = "complex")]
+                use crate::vector::Vector;
+                use crate::{$hc_wavetable, $workspace, Value};
+                #[cfg(feature = "complex")]
+                use num_complex::Complex;
+                #[cfg(feature = "complex")]
+                use std::borrow::Cow;
+
+                fft_real_modules!(
+                    @hc $ty, $hc_wavetable, $workspace, $hc,
+                    /// This function computes the FFT of `data`, a half-complex sequence, in
+                    /// place. Its definition is the same as the forward transform of real data.
+                    transform
+                );
+                fft_real_modules!(
+                    @hc $ty, $hc_wavetable, $workspace, $hc,
+                    /// This function computes the backward (unscaled inverse) FFT of `data`, a
+                    /// half-complex sequence, in place. The result is a real array.
+                    backward
+                );
+                fft_real_modules!(
+                    @hc $ty, $hc_wavetable, $workspace, $hc,
+                    /// This function computes the inverse FFT of `data`, a half-complex
+                    /// sequence, in place. The result is a real array.
+                    inverse
+                );
+                fft_real_modules!(
+                    @hc_radix2 $ty, $hc,
+                    /// Same as [`backward`] for a half-complex sequence in the radix-2 layout,
+                    /// whose length must be a power of 2.
+                    backward
+                );
+                fft_real_modules!(
+                    @hc_radix2 $ty, $hc,
+                    /// Same as [`inverse`] for a half-complex sequence in the radix-2 layout,
+                    /// whose length must be a power of 2.
+                    inverse
+                );
+
+                // GSL uses the same stride for the input and the output, so strided input is
+                // copied to a contiguous array first.
+                #[cfg(feature = "complex")]
+                fn contiguous<V: Vector<$ty> + ?Sized>(data: &V) -> Cow<'_, [$ty]> {
+                    let stride = V::stride(data);
+                    if stride == 1 {
+                        Cow::Borrowed(&V::as_slice(data)[..V::len(data)])
+                    } else {
+                        Cow::Owned(V::as_slice(data).iter().step_by(stride).copied().collect())
+                    }
+                }
+
+                /// Converts `halfcomplex`, an array of half-complex coefficients as returned by
+                #[doc = "[`" $real_mod "::transform`](super::" $real_mod "::transform),"]
+                /// into an ordinary complex array. The redundant elements are reconstructed using
+                /// the symmetry z_k = z_{n-k}^*.
+                #[cfg(feature = "complex")]
+                #[doc(alias = $hc _unpack)]
+                pub fn unpack<V: Vector<$ty> + ?Sized>(halfcomplex: &V) -> Vec<Complex<$ty>> {
+                    let n = V::len(halfcomplex);
+                    let mut z = vec![Complex::new(0., 0.); n];
+                    if n > 0 {
+                        let hc = contiguous(halfcomplex);
+                        // `Complex` is `repr(C)`, so `z` is a packed complex array.
+                        unsafe {
+                            sys::[<$hc _unpack>](hc.as_ptr(), z.as_mut_ptr() as *mut $ty, 1, n)
+                        };
+                    }
+                    z
+                }
+
+                /// Inverse of [`unpack`]: stores the complex sequence `z`, which must satisfy the
+                /// symmetry z_k = z_{n-k}^* of the Fourier transform of real data, as a
+                /// half-complex array that can be passed to [`inverse`] or [`backward`]. Only the
+                /// first half of `z` is read.
+                #[cfg(feature = "complex")]
+                pub fn pack(z: &[Complex<$ty>]) -> Vec<$ty> {
+                    let n = z.len();
+                    let mut hc = vec![0.; n];
+                    if n == 0 {
+                        return hc;
+                    }
+                    hc[0] = z[0].re;
+                    let mut k = 1;
+                    while k < n - k {
+                        hc[2 * k - 1] = z[k].re;
+                        hc[2 * k] = z[k].im;
+                        k += 1;
+                    }
+                    if k == n - k {
+                        hc[n - 1] = z[k].re;
+                    }
+                    hc
+                }
+
+                /// Same as [`unpack`] for half-complex coefficients in the radix-2 layout, as
+                /// returned by
+                #[doc = "[`radix2_transform`](super::" $real_mod "::radix2_transform)."]
+                #[cfg(feature = "complex")]
+                #[doc(alias = $hc _radix2_unpack)]
+                pub fn radix2_unpack<V: Vector<$ty> + ?Sized>(
+                    halfcomplex: &V,
+                ) -> Vec<Complex<$ty>> {
+                    let n = V::len(halfcomplex);
+                    let mut z = vec![Complex::new(0., 0.); n];
+                    if n > 0 {
+                        let hc = contiguous(halfcomplex);
+                        unsafe {
+                            sys::[<$hc _radix2_unpack>](
+                                hc.as_ptr(),
+                                z.as_mut_ptr() as *mut $ty,
+                                1,
+                                n,
+                            )
+                        };
+                    }
+                    z
+                }
+
+                /// Same as [`pack`] for the radix-2 layout, e.g. to call [`radix2_inverse`].
+                #[cfg(feature = "complex")]
+                pub fn radix2_pack(z: &[Complex<$ty>]) -> Vec<$ty> {
+                    let n = z.len();
+                    let mut hc = vec![0.; n];
+                    if n == 0 {
+                        return hc;
+                    }
+                    hc[0] = z[0].re;
+                    let mut k = 1;
+                    while k < n - k {
+                        hc[k] = z[k].re;
+                        hc[n - k] = z[k].im;
+                        k += 1;
+                    }
+                    if k == n - k {
+                        hc[k] = z[k].re;
+                    }
+                    hc
+                }
+            }
+        }
+    };
+}
+
+fft_real_modules!(
+    real,
+    halfcomplex,
+    f64,
+    FftRealF64WaveTable,
+    FftHalfComplexF64WaveTable,
+    FftRealF64Workspace,
+    gsl_fft_real,
+    gsl_fft_halfcomplex
+);
+
+fft_real_modules!(
+    real_float,
+    halfcomplex_float,
+    f32,
+    FftRealF32WaveTable,
+    FftHalfComplexF32WaveTable,
+    FftRealF32Workspace,
+    gsl_fft_real_float,
+    gsl_fft_halfcomplex_float
+);
+
+/// Returns the discrete convolution of `a` and `b`, (a * b)_k = \sum_j a_j b_{k-j}, computed
+/// with real FFTs of the zero-padded inputs.
+///
+/// With [`ConvolutionMode::Full`] the result has length `a.len() + b.len() - 1`; with
+/// [`ConvolutionMode::Same`] only its central part, of length `max(a.len(), b.len())`, is
+/// returned. Returns [`Value::Invalid`] if one of the inputs is empty.
+///
+/// ```
+/// use rgsl::{fft, ConvolutionMode};
+///
+/// let c = fft::convolve(&[1., 2., 3.], &[0., 1., 0.5], ConvolutionMode::Full).unwrap();
+/// // c ≈ [0., 1., 2.5, 4., 1.5]
+/// ```
+pub fn convolve<T1, T2>(a: &T1, b: &T2, mode: ConvolutionMode) -> Result<Vec<f64>, Value>
+where
+    T1: Vector<f64> + ?Sized,
+    T2: Vector<f64> + ?Sized,
+{
+    let a: Vec<f64> = elements(a).collect();
+    let b: Vec<f64> = elements(b).collect();
+    fft_convolve(&a, &b, mode)
+}
+
+/// Returns the discrete cross-correlation of `a` and `b`, c_k = \sum_j a_{j+k} b_j, computed with
+/// real FFTs of the zero-padded inputs.
+///
+/// With [`ConvolutionMode::Full`] the result has length `a.len() + b.len() - 1`, its i-th element
+/// corresponding to the lag k = i - (b.len() - 1); with [`ConvolutionMode::Same`] only its
+/// central part, of length `max(a.len(), b.len())`, is returned. Returns [`Value::Invalid`] if
+/// one of the inputs is empty.
+pub fn correlate<T1, T2>(a: &T1, b: &T2, mode: ConvolutionMode) -> Result<Vec<f64>, Value>
+where
+    T1: Vector<f64> + ?Sized,
+    T2: Vector<f64> + ?Sized,
+{
+    let a: Vec<f64> = elements(a).collect();
+    let mut b: Vec<f64> = elements(b).collect();
+    b.reverse();
+    fft_convolve(&a, &b, mode)
+}
+
+fn elements<T: Vector<f64> + ?Sized>(x: &T) -> impl Iterator<Item = f64> + '_ {
+    T::as_slice(x)
+        .iter()
+        .step_by(T::stride(x))
+        .take(T::len(x))
+        .copied()
+}
+
+fn fft_convolve(a: &[f64], b: &[f64], mode: ConvolutionMode) -> Result<Vec<f64>, Value> {
+    if a.is_empty() || b.is_empty() {
+        return Err(Value::Invalid);
+    }
+    let len = a.len() + b.len() - 1;
+    // Powers of 2 are the fastest lengths for the mixed-radix algorithm.
+    let n = len.next_power_of_two();
+    let mut plan = FftPlan::new(n).ok_or(Value::NoMemory)?;
+    let mut fa = vec![0.; n];
+    let mut fb = vec![0.; n];
+    fa[..a.len()].copy_from_slice(a);
+    fb[..b.len()].copy_from_slice(b);
+    plan.forward(&mut fa)?;
+    plan.forward(&mut fb)?;
+    // Multiply the half-complex sequences: the terms k = 0 and (n even) k = n/2 are real, the
+    // others are stored as (re, im) pairs in locations (2k-1, 2k).
+    fa[0] *= fb[0];
+    let mut k = 1;
+    while k < n - k {
+        let (re, im) = (fa[2 * k - 1], fa[2 * k]);
+        let (re_b, im_b) = (fb[2 * k - 1], fb[2 * k]);
+        fa[2 * k - 1] = re * re_b - im * im_b;
+        fa[2 * k] = re * im_b + im * re_b;
+        k += 1;
+    }
+    if k == n - k {
+        fa[n - 1] *= fb[n - 1];
+    }
+    plan.inverse(&mut fa)?;
+    fa.truncate(len);
+    match mode {
+        ConvolutionMode::Full => Ok(fa),
+        ConvolutionMode::Same => {
+            let start = (a.len().min(b.len()) - 1) / 2;
+            Ok(fa[start..start + a.len().max(b.len())].to_vec())
+        }
+    }
+}
+
+/// Estimates the power spectral density of `signal` with a periodogram, after multiplying it by
+/// the given `window`.
+///
+/// Returns the frequencies f_k = k/n, k = 0, ..., n/2, in units of the sampling frequency, and
+/// the corresponding one-sided power spectral density P_k = |X_k|^2 / \sum_j w_j^2 (doubled for
+/// 0 < k < n/2), X being the FFT of the windowed signal. With the rectangular window,
+/// \sum_k P_k / n is the mean square of the signal. Multiply the frequencies by the sampling
+/// frequency `fs` and divide the densities by `fs` to get physical units. The mean of the signal
+/// is not removed.
+///
+/// Returns [`Value::Invalid`] if `signal` is empty.
+///
+/// ```
+/// use rgsl::{fft, FftWindow};
+///
+/// let signal: Vec<f64> = (0..64)
+///     .map(|i| (0.25 * std::f64::consts::PI * i as f64).sin())
+///     .collect();
+/// let (freq, power) = fft::periodogram(&signal, FftWindow::Hann).unwrap();
+/// // The peak is at the frequency 1/8.
+/// ```
+pub fn periodogram<T>(signal: &T, window: FftWindow) -> Result<(Vec<f64>, Vec<f64>), Value>
+where
+    T: Vector<f64> + ?Sized,
+{
+    let n = T::len(signal);
+    if n == 0 {
+        return Err(Value::Invalid);
+    }
+    let mut plan = FftPlan::new(n).ok_or(Value::NoMemory)?;
+    let mut data: Vec<f64> = elements(signal)
+        .enumerate()
+        .map(|(j, x)| x * window.coefficient(j, n))
+        .collect();
+    let scale: f64 = (0..n).map(|j| window.coefficient(j, n).powi(2)).sum();
+    plan.forward(&mut data)?;
+    // Half-complex layout of the mixed-radix transform, see `fft_convolve`. The terms other than
+    // k = 0 and k = n/2 are doubled to account for the negative frequencies.
+    let mut power = Vec::with_capacity(n / 2 + 1);
+    power.push(data[0] * data[0] / scale);
+    let mut k = 1;
+    while k < n - k {
+        power.push(2. * (data[2 * k - 1].powi(2) + data[2 * k].powi(2)) / scale);
+        k += 1;
+    }
+    if k == n - k {
+        power.push(data[n - 1] * data[n - 1] / scale);
+    }
+    let freq = (0..power.len()).map(|k| k as f64 / n as f64).collect();
+    Ok((freq, power))
+}
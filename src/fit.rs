@@ -203,3 +203,110 @@ pub fn mul_est(x: f64, c1: f64, cov11: f64) -> Result<(f64, f64), Value> {
     let ret = unsafe { sys::gsl_fit_mul_est(x, c1, cov11, &mut y, &mut y_err) };
     result_handler!(ret, (y, y_err))
 }
+
+/// Result of a fit to the straight line model Y = c_0 + c_1 X, see [`LinearFit::fit`] and
+/// [`LinearFit::wfit`].
+///
+/// # Example
+///
+/// ```
+/// use rgsl::fit::LinearFit;
+/// let fit = LinearFit::fit(&[0., 1., 2.], &[1., 3., 5.])?;
+/// assert_eq!((fit.c0, fit.c1), (1., 2.));
+/// let (y, _y_err) = fit.predict(3.);
+/// assert_eq!(y, 7.);
+/// # Ok::<(), rgsl::Value>(())
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LinearFit {
+    pub c0: f64,
+    pub c1: f64,
+    pub cov00: f64,
+    pub cov01: f64,
+    pub cov11: f64,
+    /// Sum of squares of the residuals, weighted for [`LinearFit::wfit`] (\chi^2).
+    pub sumsq: f64,
+}
+
+impl LinearFit {
+    /// Fits the dataset (`x`, `y`), see [`linear`].
+    #[doc(alias = "gsl_fit_linear")]
+    pub fn fit<T: Vector<f64> + ?Sized>(x: &T, y: &T) -> Result<LinearFit, Value> {
+        let (c0, c1, cov00, cov01, cov11, sumsq) = linear(x, y)?;
+        Ok(LinearFit {
+            c0,
+            c1,
+            cov00,
+            cov01,
+            cov11,
+            sumsq,
+        })
+    }
+
+    /// Fits the weighted dataset (`x`, `y`) with weights `w`, see [`wlinear`].
+    #[doc(alias = "gsl_fit_wlinear")]
+    pub fn wfit<T: Vector<f64> + ?Sized>(x: &T, w: &T, y: &T) -> Result<LinearFit, Value> {
+        let (c0, c1, cov00, cov01, cov11, sumsq) = wlinear(x, w, y)?;
+        Ok(LinearFit {
+            c0,
+            c1,
+            cov00,
+            cov01,
+            cov11,
+            sumsq,
+        })
+    }
+
+    /// Returns the fitted function y and its standard deviation y_err at the point `x`, see
+    /// [`linear_est`].
+    ///
+    /// Returns `(y, y_err)`.
+    #[doc(alias = "gsl_fit_linear_est")]
+    pub fn predict(&self, x: f64) -> (f64, f64) {
+        let mut y = 0.;
+        let mut y_err = 0.;
+        unsafe {
+            sys::gsl_fit_linear_est(
+                x, self.c0, self.c1, self.cov00, self.cov01, self.cov11, &mut y, &mut y_err,
+            )
+        };
+        (y, y_err)
+    }
+}
+
+/// Result of a fit to the model Y = c_1 X, see [`MulFit::fit`] and [`MulFit::wfit`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MulFit {
+    pub c1: f64,
+    pub cov11: f64,
+    /// Sum of squares of the residuals, weighted for [`MulFit::wfit`] (\chi^2).
+    pub sumsq: f64,
+}
+
+impl MulFit {
+    /// Fits the dataset (`x`, `y`), see [`mul`].
+    #[doc(alias = "gsl_fit_mul")]
+    pub fn fit<T: Vector<f64> + ?Sized>(x: &T, y: &T) -> Result<MulFit, Value> {
+        let (c1, cov11, sumsq) = mul(x, y)?;
+        Ok(MulFit { c1, cov11, sumsq })
+    }
+
+    /// Fits the weighted dataset (`x`, `y`) with weights `w`, see [`wmul`].
+    #[doc(alias = "gsl_fit_wmul")]
+    pub fn wfit<T: Vector<f64> + ?Sized>(x: &T, w: &T, y: &T) -> Result<MulFit, Value> {
+        let (c1, cov11, sumsq) = wmul(x, w, y)?;
+        Ok(MulFit { c1, cov11, sumsq })
+    }
+
+    /// Returns the fitted function y and its standard deviation y_err at the point `x`, see
+    /// [`mul_est`].
+    ///
+    /// Returns `(y, y_err)`.
+    #[doc(alias = "gsl_fit_mul_est")]
+    pub fn predict(&self, x: f64) -> (f64, f64) {
+        let mut y = 0.;
+        let mut y_err = 0.;
+        unsafe { sys::gsl_fit_mul_est(x, self.c1, self.cov11, &mut y, &mut y_err) };
+        (y, y_err)
+    }
+}
@@ -240,6 +240,44 @@ pub mod factorials {
 
         result_handler!(ret, unsafe { result.assume_init() }.into())
     }
+
+    /// This routine computes the factorial n! exactly, using checked integer arithmetic.
+    /// The largest n such that n! fits in a `u128` is 34; for larger n,
+    /// [`OverFlow`](crate::Value::OverFlow) is returned.
+    pub fn fact_u128(n: u32) -> Result<u128, Value> {
+        (2..=n as u128).try_fold(1u128, |acc, k| acc.checked_mul(k).ok_or(Value::OverFlow))
+    }
+
+    /// This routine computes the combinatorial factor n choose m = n!/(m!(n-m)!) exactly, using
+    /// checked integer arithmetic. Intermediate products are reduced so that
+    /// [`OverFlow`](crate::Value::OverFlow) is only returned when the result itself does not
+    /// fit in a `u128`. As for [`choose_e`], m > n is a [`Domain`](crate::Value::Domain) error.
+    pub fn choose_u128(n: u32, m: u32) -> Result<u128, Value> {
+        if m > n {
+            return Err(Value::Domain);
+        }
+        let m = m.min(n - m) as u128;
+        let n = n as u128;
+        let mut result = 1u128;
+        for i in 0..m {
+            // result * (n - i) is always divisible by (i + 1), since the product is
+            // (i + 1) * C(n, i + 1). Divide by the common factors first to keep the
+            // intermediate values as small as the result.
+            let g = gcd(result, i + 1);
+            let d = (i + 1) / g;
+            result = (result / g)
+                .checked_mul((n - i) / d)
+                .ok_or(Value::OverFlow)?;
+        }
+        Ok(result)
+    }
+
+    fn gcd(mut a: u128, mut b: u128) -> u128 {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    }
 }
 
 pub mod pochhammer_symbol {
@@ -68,11 +68,16 @@ pub fn gegenpoly_n_e(n: i32, lambda: f64, x: f64) -> Result<types::Result, Value
 }
 
 /// This function computes an array of Gegenbauer polynomials C^{(\lambda)}_n(x) for n = 0, 1, 2, \dots, nmax, subject to \lambda > -1/2, nmax >= 0.
+///
+/// `nmax` is `result_array.len() - 1`, so that `result_array[n]` holds C^{(\lambda)}_n(x).
 #[doc(alias = "gsl_sf_gegenpoly_array")]
 pub fn gegenpoly_array(lambda: f64, x: f64, result_array: &mut [f64]) -> Result<(), Value> {
+    if result_array.is_empty() {
+        return Ok(());
+    }
     let ret = unsafe {
         sys::gsl_sf_gegenpoly_array(
-            result_array.len() as _,
+            result_array.len() as i32 - 1,
             lambda,
             x,
             result_array.as_mut_ptr(),
@@ -125,6 +125,80 @@ pub fn hyperg_2F1_e(a: f64, b: f64, c: f64, x: f64) -> Result<types::Result, Val
     result_handler!(ret, unsafe { result.assume_init() }.into())
 }
 
+/// This routine computes the Gauss hypergeometric function 2F1(a,b,c,x) for all real x for which
+/// it is real-valued, extending [`hyperg_2F1_e`] beyond -1 <= x < 1:
+///
+/// * if a or b is a non-positive integer, 2F1 is a polynomial and is summed directly for any x;
+/// * for x < -1, the Pfaff transformation
+///   2F1(a,b,c,x) = (1-x)^{-a} 2F1(a,c-b,c,x/(x-1)) brings the argument back into [0,1);
+/// * for x = 1, Gauss' theorem 2F1(a,b,c,1) = \Gamma(c)\Gamma(c-a-b)/(\Gamma(c-a)\Gamma(c-b))
+///   is used when c - a - b > 0, and [`Singularity`](crate::Value::Singularity) is returned
+///   otherwise since the function diverges there.
+///
+/// For x > 1 the argument lies on the branch cut of 2F1, where the function is complex-valued,
+/// and [`Domain`](crate::Value::Domain) is returned.
+pub fn hyperg_2F1_ext(a: f64, b: f64, c: f64, x: f64) -> Result<types::Result, Value> {
+    let is_non_positive_int = |v: f64| v <= 0. && v == v.trunc();
+
+    if is_non_positive_int(a) || is_non_positive_int(b) {
+        let n = if is_non_positive_int(a) && (!is_non_positive_int(b) || a > b) {
+            -a
+        } else {
+            -b
+        } as u32;
+        return hyperg_2F1_polynomial(a, b, c, x, n);
+    }
+    if (-1. ..1.).contains(&x) {
+        return hyperg_2F1_e(a, b, c, x);
+    }
+    if x < -1. {
+        let r = hyperg_2F1_e(a, c - b, c, x / (x - 1.))?;
+        let factor = (1. - x).powf(-a);
+        return Ok(types::Result {
+            val: factor * r.val,
+            err: factor * r.err + 2. * crate::DBL_EPSILON * (factor * r.val).abs(),
+        });
+    }
+    if x == 1. {
+        if c - a - b <= 0. || is_non_positive_int(c) {
+            return Err(Value::Singularity);
+        }
+        let num = crate::gamma_beta::gamma::gamma_e(c)?.val
+            * crate::gamma_beta::gamma::gamma_e(c - a - b)?.val;
+        let den = crate::gamma_beta::gamma::gammainv_e(c - a)?.val
+            * crate::gamma_beta::gamma::gammainv_e(c - b)?.val;
+        let val = num * den;
+        return Ok(types::Result {
+            val,
+            err: 4. * crate::DBL_EPSILON * val.abs(),
+        });
+    }
+    Err(Value::Domain)
+}
+
+/// Sums the terminating series of 2F1(a,b,c,x) up to degree `n`.
+fn hyperg_2F1_polynomial(a: f64, b: f64, c: f64, x: f64, n: u32) -> Result<types::Result, Value> {
+    let mut term = 1.;
+    let mut val = 1.;
+    let mut abs_sum = 1.;
+    for k in 0..n {
+        let k = k as f64;
+        if c + k == 0. {
+            return Err(Value::Singularity);
+        }
+        term *= (a + k) * (b + k) / ((c + k) * (k + 1.)) * x;
+        val += term;
+        abs_sum += term.abs();
+    }
+    if !val.is_finite() {
+        return Err(Value::OverFlow);
+    }
+    Ok(types::Result {
+        val,
+        err: 2. * (n as f64 + 1.) * crate::DBL_EPSILON * abs_sum,
+    })
+}
+
 /// This routine computes the Gauss hypergeometric function 2F1(a_R + i a_I, a_R - i a_I, c, x) with complex parameters for |x| < 1.
 #[doc(alias = "gsl_sf_hyperg_2F1_conj")]
 pub fn hyperg_2F1_conj(aR: f64, aI: f64, c: f64, x: f64) -> f64 {
@@ -205,3 +205,62 @@ pub fn eval_integ_e(
     };
     result_handler!(ret, result)
 }
+
+// Builds a spline of type `t` through `(x, y)`.
+fn spline_through(x: &[f64], y: &[f64], t: crate::InterpType) -> Result<crate::Spline, Value> {
+    if x.len() != y.len() {
+        return Err(Value::BadLength);
+    }
+    if x.len() < t.min_size() as usize {
+        return Err(Value::Invalid);
+    }
+    let mut spline = crate::Spline::new(t, x.len()).ok_or(Value::NoMemory)?;
+    spline.init(x, y)?;
+    Ok(spline)
+}
+
+/// Interpolates the data points `(x, y)` with the interpolation type `t` and returns the values
+/// of the interpolating function at each point of `new_x`. The abscissae `x` must be strictly
+/// increasing.
+///
+/// Returns [`Value::BadLength`] if `x` and `y` have different lengths, [`Value::Invalid`] if
+/// there are fewer points than [`InterpType::min_size`](crate::InterpType::min_size) and
+/// [`Value::Domain`] if a point of `new_x` lies outside of the range of `x`.
+///
+/// ```
+/// use rgsl::{interpolation, InterpType};
+///
+/// let x = [0., 1., 2., 3.];
+/// let y = [0., 2., 4., 6.];
+/// let v = interpolation::resample(&x, &y, &[0.5, 2.5], InterpType::linear()).unwrap();
+/// assert_eq!(v, [1., 5.]);
+/// ```
+pub fn resample(
+    x: &[f64],
+    y: &[f64],
+    new_x: &[f64],
+    t: crate::InterpType,
+) -> Result<Vec<f64>, Value> {
+    let spline = spline_through(x, y, t)?;
+    let mut acc = crate::InterpAccel::new();
+    let mut out = vec![0.; new_x.len()];
+    spline.eval_slice(new_x, &mut out, &mut acc)?;
+    Ok(out)
+}
+
+/// Same as [`resample`] but also returns the derivative of the interpolating function at each
+/// point of `new_x`, as `(values, derivatives)`.
+pub fn resample_with_deriv(
+    x: &[f64],
+    y: &[f64],
+    new_x: &[f64],
+    t: crate::InterpType,
+) -> Result<(Vec<f64>, Vec<f64>), Value> {
+    let spline = spline_through(x, y, t)?;
+    let mut acc = crate::InterpAccel::new();
+    let mut values = vec![0.; new_x.len()];
+    let mut derivs = vec![0.; new_x.len()];
+    spline.eval_slice(new_x, &mut values, &mut acc)?;
+    spline.eval_deriv_slice(new_x, &mut derivs, &mut acc)?;
+    Ok((values, derivs))
+}
@@ -69,3 +69,28 @@ pub fn laguerre_n_e(n: i32, a: f64, x: f64) -> Result<types::Result, Value> {
 
     result_handler!(ret, unsafe { result.assume_init() }.into())
 }
+
+/// This function computes an array of generalized Laguerre polynomials L^a_n(x) for
+/// n = 0, 1, 2, \dots, nmax, subject to a > -1, where `nmax` is `result_array.len() - 1`.
+///
+/// The values are obtained with the three-term recurrence
+/// (n+1) L^a_{n+1}(x) = (2n+1+a-x) L^a_n(x) - (n+a) L^a_{n-1}(x), which is much cheaper than
+/// calling [`laguerre_n`] for each order.
+pub fn laguerre_n_array(a: f64, x: f64, result_array: &mut [f64]) -> Result<(), Value> {
+    if a <= -1. {
+        return Err(Value::Domain);
+    }
+    let len = result_array.len();
+    if len > 0 {
+        result_array[0] = 1.;
+    }
+    if len > 1 {
+        result_array[1] = 1. + a - x;
+    }
+    for n in 1..len.saturating_sub(1) {
+        let k = n as f64;
+        result_array[n + 1] =
+            ((2. * k + 1. + a - x) * result_array[n] - (k + a) * result_array[n - 1]) / (k + 1.);
+    }
+    Ok(())
+}
@@ -7,7 +7,7 @@ Lambert’s W functions, W(x), are defined to be solutions of the equation W(x)
 We define W_0(x) to be the principal branch, where W > -1 for x < 0, and W_{-1}(x) to be the other real branch, where W < -1 for x < 0.
 !*/
 
-use crate::{types, Value};
+use crate::{types, Branch, Value};
 use std::mem::MaybeUninit;
 
 /// This computes the principal branch of the Lambert W function, W_0(x).
@@ -39,3 +39,18 @@ pub fn lambert_Wm1_e(x: f64) -> Result<types::Result, Value> {
 
     result_handler!(ret, unsafe { result.assume_init() }.into())
 }
+
+/// This computes the Lambert W function W_k(x) on the given `branch`.
+///
+/// The real branches are only defined for x >= -1/e; outside this domain [`Value::Domain`] is
+/// returned. On [`Branch::Minus1`], for x >= 0 (where W_{-1} is complex), GSL falls back to the
+/// principal branch; to mirror `scipy.special.lambertw`, this function returns
+/// [`Value::Domain`] instead. Complex-valued branches return [`Value::Unimplemented`].
+pub fn lambert_w(x: f64, branch: Branch) -> Result<types::Result, Value> {
+    match branch {
+        Branch::Principal => lambert_W0_e(x),
+        Branch::Minus1 if x >= 0. => Err(Value::Domain),
+        Branch::Minus1 => lambert_Wm1_e(x),
+        Branch::Other(_) => Err(Value::Unimplemented),
+    }
+}
@@ -397,3 +397,100 @@ pub mod radial {
         result_handler!(ret, ())
     }
 }
+
+/// Spherical harmonics Y_l^m(\theta, \phi) for all 0 <= l <= lmax, -l <= m <= l, built on top of
+/// [`legendre_array`](associated_polynomials::legendre_array) with the
+/// [`SphericalHarmonic`](crate::SfLegendreNorm::SphericalHarmonic) normalization.
+///
+/// The results are stored in a flat array where Y_l^m is at index
+/// [`sph_harm_index(l, m)`](spherical_harmonics::sph_harm_index) = l^2 + l + m, so an array for
+/// `lmax` has (lmax + 1)^2 elements.
+pub mod spherical_harmonics {
+    use crate::{enums, Value};
+    #[cfg(feature = "complex")]
+    use num_complex::Complex;
+
+    /// Returns the index of Y_l^m in the arrays returned by the functions of this module.
+    pub fn sph_harm_index(l: usize, m: i32) -> usize {
+        debug_assert!(m.unsigned_abs() as usize <= l);
+        (l * l + l).wrapping_add_signed(m as isize)
+    }
+
+    /// Computes the orthonormalized associated Legendre functions
+    /// \sqrt{(2l+1)/(4\pi)} \sqrt{(l-m)!/(l+m)!} P_l^m(\cos\theta) for m >= 0, including the
+    /// Condon-Shortley phase (-1)^m if `csphase` is true.
+    fn normalized_plm(lmax: usize, theta: f64, csphase: bool) -> Result<Vec<f64>, Value> {
+        let mut plm = vec![0.; super::associated_polynomials::legendre_array_n(lmax)];
+        let ret = unsafe {
+            sys::gsl_sf_legendre_array_e(
+                enums::SfLegendreNorm::SphericalHarmonic.into(),
+                lmax,
+                theta.cos(),
+                if csphase { -1. } else { 1. },
+                plm.as_mut_ptr(),
+            )
+        };
+        result_handler!(ret, plm)
+    }
+
+    #[cfg(feature = "complex")]
+    /// Computes the complex spherical harmonics Y_l^m(\theta, \phi) for 0 <= l <= lmax and
+    /// -l <= m <= l, where \theta is the polar angle and \phi the azimuthal angle.
+    ///
+    /// If `csphase` is true, the Condon-Shortley phase (-1)^m is included for m > 0 (the usual
+    /// convention in quantum mechanics). The negative orders are the same in both conventions,
+    /// Y_l^{-m} = (-1)^m \overline{Y_l^m} where Y_l^m includes the Condon-Shortley phase.
+    #[doc(alias = "gsl_sf_legendre_array_e")]
+    pub fn sph_harm(
+        lmax: usize,
+        theta: f64,
+        phi: f64,
+        csphase: bool,
+    ) -> Result<Vec<Complex<f64>>, Value> {
+        let plm = normalized_plm(lmax, theta, csphase)?;
+        let mut ylm = vec![Complex::new(0., 0.); (lmax + 1) * (lmax + 1)];
+        for l in 0..=lmax {
+            for m in 0..=l {
+                let p = plm[super::associated_polynomials::legendre_array_index(l, m)];
+                let y = Complex::from_polar(p, m as f64 * phi);
+                ylm[sph_harm_index(l, m as i32)] = y;
+                if m > 0 {
+                    // Y_l^{-m} does not depend on the convention: it is (-1)^m conj(Y_l^m) with
+                    // the Condon-Shortley phase and conj(Y_l^m) without it.
+                    let sign = if csphase && m % 2 == 1 { -1. } else { 1. };
+                    ylm[sph_harm_index(l, -(m as i32))] = y.conj() * sign;
+                }
+            }
+        }
+        Ok(ylm)
+    }
+
+    /// Computes the real spherical harmonics Y_{lm}(\theta, \phi) for 0 <= l <= lmax and
+    /// -l <= m <= l, where \theta is the polar angle and \phi the azimuthal angle:
+    ///
+    /// ```text
+    /// Y_{lm} = \sqrt{2} N_l^m P_l^{m}(\cos\theta) \cos(m\phi)       m > 0
+    /// Y_{l0} = N_l^0 P_l(\cos\theta)
+    /// Y_{lm} = \sqrt{2} N_l^{|m|} P_l^{|m|}(\cos\theta) \sin(|m|\phi) m < 0
+    /// ```
+    ///
+    /// where N_l^m P_l^m are the orthonormalized associated Legendre functions, without the
+    /// Condon-Shortley phase. These functions form a real orthonormal basis on the sphere.
+    #[doc(alias = "gsl_sf_legendre_array_e")]
+    pub fn real_sph_harm(lmax: usize, theta: f64, phi: f64) -> Result<Vec<f64>, Value> {
+        let plm = normalized_plm(lmax, theta, false)?;
+        let mut ylm = vec![0.; (lmax + 1) * (lmax + 1)];
+        for l in 0..=lmax {
+            ylm[sph_harm_index(l, 0)] =
+                plm[super::associated_polynomials::legendre_array_index(l, 0)];
+            for m in 1..=l {
+                let p = std::f64::consts::SQRT_2
+                    * plm[super::associated_polynomials::legendre_array_index(l, m)];
+                let (sin, cos) = (m as f64 * phi).sin_cos();
+                ylm[sph_harm_index(l, m as i32)] = p * cos;
+                ylm[sph_harm_index(l, -(m as i32))] = p * sin;
+            }
+        }
+        Ok(ylm)
+    }
+}
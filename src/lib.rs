@@ -39,6 +39,7 @@ pub mod types;
 pub mod airy;
 pub mod bessel;
 pub mod blas;
+pub mod bspline;
 pub mod cblas;
 pub mod clausen;
 pub mod coulomb;
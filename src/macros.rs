@@ -109,3 +109,180 @@ macro_rules! result_handler {
         }
     }};
 }
+
+/// Implements the element-wise arithmetic operators on a vector or matrix type `$rust_name` by
+/// delegating to the corresponding in-place methods. Since operators cannot return errors, they
+/// panic if the operands do not have the same dimensions (as returned by `$dims`).
+#[doc(hidden)]
+macro_rules! elementwise_ops {
+    ($rust_name:ident, $dims:ident,
+     $($op:ident $op_fn:ident, $op_assign:ident $op_assign_fn:ident => $method:ident;)*) => {
+        $(
+            impl std::ops::$op_assign<&$rust_name> for $rust_name {
+                fn $op_assign_fn(&mut self, other: &$rust_name) {
+                    assert_eq!(
+                        self.$dims(),
+                        other.$dims(),
+                        concat!("rgsl::", stringify!($rust_name), "::", stringify!($op_fn),
+                                ": dimension mismatch")
+                    );
+                    $rust_name::$method(self, other).expect(concat!(
+                        "rgsl::", stringify!($rust_name), "::", stringify!($method), " failed"
+                    ));
+                }
+            }
+
+            impl std::ops::$op<&$rust_name> for $rust_name {
+                type Output = $rust_name;
+
+                fn $op_fn(mut self, other: &$rust_name) -> $rust_name {
+                    std::ops::$op_assign::$op_assign_fn(&mut self, other);
+                    self
+                }
+            }
+
+            impl std::ops::$op<&$rust_name> for &$rust_name {
+                type Output = $rust_name;
+
+                fn $op_fn(self, other: &$rust_name) -> $rust_name {
+                    let copy = $rust_name::clone(self)
+                        .expect(concat!("rgsl::", stringify!($rust_name), "::clone failed"));
+                    std::ops::$op::$op_fn(copy, other)
+                }
+            }
+        )*
+    };
+}
+
+/// Implements the operators with a scalar (multiplication by `scale` and addition by
+/// `add_constant`) on a vector or matrix type `$rust_name` with elements of type `$rust_ty`.
+#[doc(hidden)]
+macro_rules! scalar_ops {
+    ($rust_name:ident, $rust_ty:ty) => {
+        scalar_ops!(@op $rust_name, $rust_ty, Mul mul, MulAssign mul_assign => scale);
+        scalar_ops!(@op $rust_name, $rust_ty, Add add, AddAssign add_assign => add_constant);
+    };
+    (@op $rust_name:ident, $rust_ty:ty,
+     $op:ident $op_fn:ident, $op_assign:ident $op_assign_fn:ident => $method:ident) => {
+        impl std::ops::$op_assign<$rust_ty> for $rust_name {
+            fn $op_assign_fn(&mut self, x: $rust_ty) {
+                $rust_name::$method(self, x).expect(concat!(
+                    "rgsl::", stringify!($rust_name), "::", stringify!($method), " failed"
+                ));
+            }
+        }
+
+        impl std::ops::$op<$rust_ty> for $rust_name {
+            type Output = $rust_name;
+
+            fn $op_fn(mut self, x: $rust_ty) -> $rust_name {
+                std::ops::$op_assign::$op_assign_fn(&mut self, x);
+                self
+            }
+        }
+
+        impl std::ops::$op<$rust_ty> for &$rust_name {
+            type Output = $rust_name;
+
+            fn $op_fn(self, x: $rust_ty) -> $rust_name {
+                let copy = $rust_name::clone(self)
+                    .expect(concat!("rgsl::", stringify!($rust_name), "::clone failed"));
+                std::ops::$op::$op_fn(copy, x)
+            }
+        }
+
+        impl std::ops::$op<$rust_name> for $rust_ty {
+            type Output = $rust_name;
+
+            fn $op_fn(self, v: $rust_name) -> $rust_name {
+                std::ops::$op::$op_fn(v, self)
+            }
+        }
+
+        impl std::ops::$op<&$rust_name> for $rust_ty {
+            type Output = $rust_name;
+
+            fn $op_fn(self, v: &$rust_name) -> $rust_name {
+                std::ops::$op::$op_fn(v, self)
+            }
+        }
+    };
+    // Negation, for signed element types only.
+    (@neg $rust_name:ident, $rust_ty:ty) => {
+        impl std::ops::Neg for $rust_name {
+            type Output = $rust_name;
+
+            fn neg(self) -> $rust_name {
+                std::ops::Mul::mul(self, -(1 as $rust_ty))
+            }
+        }
+
+        impl std::ops::Neg for &$rust_name {
+            type Output = $rust_name;
+
+            fn neg(self) -> $rust_name {
+                std::ops::Mul::mul(self, -(1 as $rust_ty))
+            }
+        }
+    };
+}
+
+// Applies a `Permutation` to the elements of a vector, or to the columns of a matrix.
+macro_rules! permute_ops {
+    ($rust_name:ident, $suffix:ident) => {
+        paste::paste! {
+            impl $rust_name {
+                /// Applies the permutation `p` to the elements of the vector, considered as a
+                /// row-vector acted on by a permutation matrix from the right, v' = v P. The
+                /// permutation and the vector must have the same length.
+                #[doc(alias = gsl_permute_ $suffix)]
+                pub fn permute(&mut self, p: &crate::Permutation) -> Result<(), crate::Value> {
+                    let ret = unsafe {
+                        sys::[<gsl_permute_ $suffix>](p.unwrap_shared(), self.unwrap_unique())
+                    };
+                    result_handler!(ret, ())
+                }
+
+                /// Applies the inverse of the permutation `p` to the elements of the vector,
+                /// v' = v P^T.
+                #[doc(alias = gsl_permute_ $suffix _inverse)]
+                pub fn permute_inverse(
+                    &mut self,
+                    p: &crate::Permutation,
+                ) -> Result<(), crate::Value> {
+                    let ret = unsafe {
+                        sys::[<gsl_permute_ $suffix _inverse>](
+                            p.unwrap_shared(),
+                            self.unwrap_unique(),
+                        )
+                    };
+                    result_handler!(ret, ())
+                }
+            }
+        }
+    };
+    (@matrix $rust_name:ident, $suffix:ident) => {
+        paste::paste! {
+            impl $rust_name {
+                /// Applies the permutation `p` to the columns of the matrix, A' = A P. The j-th
+                /// column of the permutation matrix P is given by the p_j-th column of the
+                /// identity matrix. The permutation length must be equal to the number of columns.
+                ///
+                /// To reorder the rows, permute the columns of the
+                /// [`transpose`](Self::transpose) instead.
+                #[cfg(feature = "v2_2")]
+                #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+                #[doc(alias = gsl_permute_ $suffix)]
+                pub fn permute_columns(
+                    &mut self,
+                    p: &crate::Permutation,
+                ) -> Result<(), crate::Value> {
+                    let ret = unsafe {
+                        sys::[<gsl_permute_ $suffix>](p.unwrap_shared(), self.unwrap_unique())
+                    };
+                    result_handler!(ret, ())
+                }
+            }
+        }
+    };
+}
@@ -2,7 +2,7 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
-use crate::Value;
+use crate::{Minimizer, MinimizerType, Value};
 
 /// This function tests for the convergence of the interval [x_lower, x_upper] with absolute error epsabs and relative error epsrel. The
 /// test returns crate::Value::Success if the following condition is achieved,
@@ -27,3 +27,57 @@ use crate::Value;
 pub fn test_interval(x_lower: f64, x_upper: f64, epsabs: f64, epsrel: f64) -> Value {
     Value::from(unsafe { sys::gsl_min_test_interval(x_lower, x_upper, epsabs, epsrel) })
 }
+
+/// Finds the minimum of `f` in the interval [`a`, `b`] starting from the estimate `x_guess`,
+/// which must satisfy a < x_guess < b and f(a) > f(x_guess) < f(b), with the algorithm `t`.
+///
+/// The minimizer is iterated until the interval containing the minimum is smaller than `tol`, as
+/// checked by [`test_interval`] with an absolute error `tol` and no relative error, or at most
+/// `max_iter` times.
+///
+/// Returns the location of the minimum and the width of the final interval, which bounds the
+/// error on it. Returns [`Value::Invalid`] if `x_guess` does not satisfy the conditions above,
+/// [`Value::MaxIteration`] if the interval did not shrink enough and the error of the failed
+/// iteration otherwise.
+///
+/// ```
+/// use rgsl::{minimizer, MinimizerType, Value};
+///
+/// let (x, err) =
+///     minimizer::minimize(|x| x.cos() + 1., 0., 6., 2., MinimizerType::brent(), 1e-6, 100)
+///         .unwrap();
+/// assert!((x - std::f64::consts::PI).abs() <= err);
+///
+/// // The cosine is decreasing on [0, 3]: there is no minimum inside of it.
+/// let ret = minimizer::minimize(|x| x.cos(), 0., 3., 2., MinimizerType::brent(), 1e-6, 100);
+/// assert_eq!(ret.err(), Some(Value::Invalid));
+/// ```
+pub fn minimize<F: Fn(f64) -> f64>(
+    f: F,
+    a: f64,
+    b: f64,
+    x_guess: f64,
+    t: MinimizerType,
+    tol: f64,
+    max_iter: usize,
+) -> Result<(f64, f64), Value> {
+    // GSL calls the error handler rather than returning an error for these.
+    if !(a < x_guess && x_guess < b) {
+        return Err(Value::Invalid);
+    }
+    let (f_lower, f_guess, f_upper) = (f(a), f(x_guess), f(b));
+    if !(f_guess < f_lower && f_guess < f_upper) {
+        return Err(Value::Invalid);
+    }
+
+    let mut s = Minimizer::new(t).ok_or(Value::NoMemory)?;
+    s.set_with_values(f, x_guess, f_guess, a, f_lower, b, f_upper)?;
+    for _ in 0..max_iter {
+        s.iterate()?;
+        let (lower, upper) = (s.x_lower(), s.x_upper());
+        if test_interval(lower, upper, tol, 0.) == Value::Success {
+            return Ok((s.x_minimum(), upper - lower));
+        }
+    }
+    Err(Value::MaxIteration)
+}
@@ -101,3 +101,99 @@ pub fn linear_residuals(
     };
     result_handler!(ret, ())
 }
+
+/// Regression diagnostics of a linear least squares fit, see [`linear_diagnostics`].
+#[derive(Debug)]
+pub struct LinearDiagnostics {
+    /// Residuals `r_i = y_i - X_i c`.
+    pub residuals: VectorF64,
+    /// Leverage values `h_i`, the diagonal of the hat matrix `H = X (X^T X)^{-1} X^T`.
+    pub leverage: VectorF64,
+    /// Internally studentized residuals `r_i / (σ sqrt(1 - h_i))` where
+    /// `σ^2 = rss / (n - p)`.
+    pub studentized_residuals: VectorF64,
+    /// Total sum of squares of the observations about their mean, `∑ (y_i - ȳ)^2`.
+    pub tss: f64,
+    /// Residual sum of squares, `∑ r_i^2`.
+    pub rss: f64,
+    /// Coefficient of determination, `R^2 = 1 - rss / tss`, or NaN if `tss = 0`.
+    pub r_squared: f64,
+    /// Adjusted coefficient of determination, `1 - (1 - R^2) (n - 1) / (n - p)`.
+    pub adj_r_squared: f64,
+}
+
+/// Computes the residuals, leverage values, studentized residuals, total sum of squares and
+/// `R^2` of the unweighted least squares fit `c` (e.g. obtained with
+/// [`MultifitLinearWorkspace::linear`](crate::MultifitLinearWorkspace::linear)) of the
+/// observations `y` with the `n`-by-`p` design matrix `x`.
+///
+/// The leverage values are computed from the QR decomposition of `x`, so the accuracy is not
+/// affected by the squared condition number of `X^T X`.
+///
+/// `R^2` and its adjusted version assume that the model contains an intercept term. They are
+/// NaN when all the observations are equal (`tss = 0`).
+///
+/// Returns `Value::BadLength` if the dimensions of `x`, `y` and `c` do not match,
+/// `Value::Invalid` if there are not more observations than parameters (`n <= p`) and
+/// `Value::Domain` if `x` is rank deficient.
+pub fn linear_diagnostics(
+    x: &MatrixF64,
+    y: &VectorF64,
+    c: &VectorF64,
+) -> Result<LinearDiagnostics, Value> {
+    let n = x.size1();
+    let p = x.size2();
+    if y.len() != n || c.len() != p {
+        return Err(Value::BadLength);
+    }
+    if n <= p {
+        return Err(Value::Invalid);
+    }
+
+    let mut residuals = VectorF64::new(n).ok_or(Value::NoMemory)?;
+    linear_residuals(x, y, c, &mut residuals)?;
+    let rss = (0..n).map(|i| residuals.get(i).powi(2)).sum::<f64>();
+    let tss = crate::stats::tss(y);
+
+    // X = Q R, the upper triangle of `qr` holds R.
+    let mut qr = x.clone().ok_or(Value::NoMemory)?;
+    let mut tau = VectorF64::new(p).ok_or(Value::NoMemory)?;
+    crate::linear_algebra::QR_decomp(&mut qr, &mut tau)?;
+    let rmax = (0..p).map(|j| qr.get(j, j).abs()).fold(0., f64::max);
+    if (0..p).any(|j| qr.get(j, j).abs() <= rmax * n as f64 * f64::EPSILON) {
+        return Err(Value::Domain);
+    }
+
+    // h_i = x_i^T (X^T X)^{-1} x_i = ||R^{-T} x_i||^2.
+    let mut leverage = VectorF64::new(n).ok_or(Value::NoMemory)?;
+    let mut z = vec![0.; p];
+    for i in 0..n {
+        for j in 0..p {
+            let s = (0..j).map(|k| qr.get(k, j) * z[k]).sum::<f64>();
+            z[j] = (x.get(i, j) - s) / qr.get(j, j);
+        }
+        leverage.set(i, z.iter().map(|z| z * z).sum());
+    }
+
+    let sigma = (rss / (n as f64 - p as f64)).sqrt();
+    let mut studentized_residuals = VectorF64::new(n).ok_or(Value::NoMemory)?;
+    for i in 0..n {
+        studentized_residuals.set(
+            i,
+            residuals.get(i) / (sigma * (1. - leverage.get(i)).sqrt()),
+        );
+    }
+
+    let r_squared = if tss > 0. { 1. - rss / tss } else { f64::NAN };
+    let adj_r_squared = 1. - (1. - r_squared) * (n as f64 - 1.) / (n as f64 - p as f64);
+
+    Ok(LinearDiagnostics {
+        residuals,
+        leverage,
+        studentized_residuals,
+        tss,
+        rss,
+        r_squared,
+        adj_r_squared,
+    })
+}
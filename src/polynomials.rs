@@ -104,8 +104,13 @@ pub mod divided_difference_representation {
     /// This function computes a divided-difference representation of the interpolating polynomial for the points (x, y) stored in the arrays
     /// xa and ya of length size. On output the divided-differences of (xa,ya) are stored in the array dd, also of length size. Using the
     /// notation above, `dd[k] = [x_0,x_1,...,x_k]`.
+    ///
+    /// The size is the length of `dd`. Returns `Value::BadLength` if `xa` or `ya` is shorter.
     #[doc(alias = "gsl_poly_dd_init")]
     pub fn poly_dd_init(dd: &mut [f64], xa: &[f64], ya: &[f64]) -> Result<(), Value> {
+        if xa.len() < dd.len() || ya.len() < dd.len() {
+            return Err(Value::BadLength);
+        }
         let ret = unsafe {
             sys::gsl_poly_dd_init(dd.as_mut_ptr(), xa.as_ptr(), ya.as_ptr(), dd.len() as _)
         };
@@ -121,6 +126,8 @@ pub mod divided_difference_representation {
     /// This function converts the divided-difference representation of a polynomial to a Taylor expansion. The divided-difference representation
     /// is supplied in the arrays dd and xa of length size. On output the Taylor coefficients of the polynomial expanded about the point xp are
     /// stored in the array c also of length size. A workspace of length size must be provided in the array w.
+    ///
+    /// The size is the length of `dd`. Returns `Value::BadLength` if `c`, `xa` or `w` is shorter.
     #[doc(alias = "gsl_poly_dd_taylor")]
     pub fn poly_dd_taylor(
         c: &mut [f64],
@@ -129,6 +136,10 @@ pub mod divided_difference_representation {
         xa: &[f64],
         w: &mut [f64],
     ) -> Result<(), Value> {
+        let n = dd.len();
+        if c.len() < n || xa.len() < n || w.len() < n {
+            return Err(Value::BadLength);
+        }
         let ret = unsafe {
             sys::gsl_poly_dd_taylor(
                 c.as_mut_ptr(),
@@ -148,6 +159,9 @@ pub mod divided_difference_representation {
     /// new dataset z = \{x_0,x_0,x_1,x_1,...\}, which is stored in the array za of length 2*size on output. On output the divided-differences
     /// of the Hermite representation are stored in the array dd, also of length 2*size. Using the notation above, `dd[k] = [z_0,z_1,...,z_k]`.
     /// The resulting Hermite polynomial can be evaluated by calling gsl_poly_dd_eval and using za for the input argument xa.
+    ///
+    /// The size is the length of `xa`. Returns `Value::BadLength` if `ya` or `dya` is shorter, or
+    /// if `dd` or `za` has less than 2*size elements.
     #[doc(alias = "gsl_poly_dd_hermite_init")]
     pub fn poly_dd_hermite_init(
         dd: &mut [f64],
@@ -156,6 +170,11 @@ pub mod divided_difference_representation {
         ya: &[f64],
         dya: &[f64],
     ) -> Result<(), Value> {
+        // GSL fills 2 * size elements of `dd` and `za` from `size` points.
+        let n = xa.len();
+        if dd.len() < 2 * n || za.len() < 2 * n || ya.len() < n || dya.len() < n {
+            return Err(Value::BadLength);
+        }
         let ret = unsafe {
             sys::gsl_poly_dd_hermite_init(
                 dd.as_mut_ptr(),
@@ -163,7 +182,7 @@ pub mod divided_difference_representation {
                 xa.as_ptr(),
                 ya.as_ptr(),
                 dya.as_ptr(),
-                dd.len() as _,
+                n as _,
             )
         };
         result_handler!(ret, ())
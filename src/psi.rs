@@ -12,6 +12,8 @@ where \psi(x) = \Gamma'(x)/\Gamma(x) is known as the digamma function.
 
 pub mod diagamma {
     use crate::{types, Value};
+    #[cfg(feature = "complex")]
+    use num_complex::Complex;
     use std::mem::MaybeUninit;
 
     /// This routine computes the digamma function \psi(n) for positive integer n. The digamma function is also called the Psi function.
@@ -46,18 +48,41 @@ pub mod diagamma {
 
     /// This routine computes the real part of the digamma function on the line 1+i y, \Re[\psi(1 + i y)].
     #[doc(alias = "gsl_sf_psi_1piy")]
-    pub fn psi_1piy(x: f64) -> f64 {
-        unsafe { sys::gsl_sf_psi_1piy(x) }
+    pub fn psi_1piy(y: f64) -> f64 {
+        unsafe { sys::gsl_sf_psi_1piy(y) }
     }
 
     /// This routine computes the real part of the digamma function on the line 1+i y, \Re[\psi(1 + i y)].
     #[doc(alias = "gsl_sf_psi_1piy_e")]
-    pub fn psi_1piy_e(x: f64) -> Result<types::Result, Value> {
+    pub fn psi_1piy_e(y: f64) -> Result<types::Result, Value> {
         let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
-        let ret = unsafe { sys::gsl_sf_psi_1piy_e(x, result.as_mut_ptr()) };
+        let ret = unsafe { sys::gsl_sf_psi_1piy_e(y, result.as_mut_ptr()) };
 
         result_handler!(ret, unsafe { result.assume_init() }.into())
     }
+
+    #[cfg(feature = "complex")]
+    /// This routine computes the complex digamma function on the line 1+i y, \psi(1 + i y).
+    ///
+    /// The real part is given by [`psi_1piy_e`] while the imaginary part is obtained from the
+    /// identity \Im[\psi(1 + i y)] = -1/(2y) + (\pi/2) \coth(\pi y).
+    pub fn psi_1piy_complex(y: f64) -> Result<Complex<f64>, Value> {
+        let re = psi_1piy_e(y)?.val;
+        let x = std::f64::consts::PI * y;
+        let im = if x.abs() < 1. {
+            // coth(x) - 1/x = x/(3 + x^2/(5 + x^2/(7 + ...))), which avoids the cancellation
+            // of the two terms above for small y.
+            let x2 = x * x;
+            let mut d = 41.;
+            for k in (1..20).rev() {
+                d = (2 * k + 1) as f64 + x2 / d;
+            }
+            0.5 * std::f64::consts::PI * x / d
+        } else {
+            -0.5 / y + 0.5 * std::f64::consts::PI / x.tanh()
+        };
+        Ok(Complex::new(re, im))
+    }
 }
 
 pub mod trigamma {
@@ -113,4 +138,19 @@ pub mod polygamma {
 
         result_handler!(ret, unsafe { result.assume_init() }.into())
     }
+
+    /// This routine computes the polygamma functions \psi^{(n)}(x) for n from 0 to nmax
+    /// inclusive, x > 0, storing the results in the array result_array.
+    ///
+    /// Returns [`Value::BadLength`] if `result_array` has less than `nmax + 1` elements.
+    pub fn psi_n_array(nmax: u32, x: f64, result_array: &mut [f64]) -> Result<(), Value> {
+        let nmax = i32::try_from(nmax).map_err(|_| Value::Domain)?;
+        if result_array.len() <= nmax as usize {
+            return Err(Value::BadLength);
+        }
+        for (n, r) in (0..=nmax).zip(result_array.iter_mut()) {
+            *r = psi_n_e(n, x)?.val;
+        }
+        Ok(())
+    }
 }
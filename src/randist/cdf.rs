@@ -0,0 +1,45 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+All the cumulative distribution functions P(x), Q(x) and their inverses P^{-1}, Q^{-1} of the
+`gsl_cdf_*` family, gathered in one place. They are also available from the module of their
+distribution, along with the corresponding probability density functions.
+
+```
+use rgsl::randist::cdf;
+
+// Two-sided p-value of a z-score of 1.96.
+let p = 2. * cdf::ugaussian_Q(1.96);
+assert!((p - 0.05).abs() < 1e-3);
+```
+!*/
+
+pub use super::beta::{beta_P, beta_Pinv, beta_Q, beta_Qinv};
+pub use super::binomial::{binomial_P, binomial_Q};
+pub use super::cauchy::{cauchy_P, cauchy_Pinv, cauchy_Q, cauchy_Qinv};
+pub use super::chi_squared::{chisq_P, chisq_Pinv, chisq_Q, chisq_Qinv};
+pub use super::exponential::{exponential_P, exponential_Pinv, exponential_Q, exponential_Qinv};
+pub use super::exponential_power::{exppow_P, exppow_Q};
+pub use super::f_distribution::{fdist_P, fdist_Pinv, fdist_Q, fdist_Qinv};
+pub use super::flat::{flat_P, flat_Pinv, flat_Q, flat_Qinv};
+pub use super::gamma::{gamma_P, gamma_Pinv, gamma_Q, gamma_Qinv};
+pub use super::gaussian::{
+    gaussian_P, gaussian_Pinv, gaussian_Q, gaussian_Qinv, ugaussian_P, ugaussian_Pinv, ugaussian_Q,
+    ugaussian_Qinv,
+};
+pub use super::geometric::{geometric_P, geometric_Q};
+pub use super::gumbel::type_1::{gumbel1_P, gumbel1_Pinv, gumbel1_Q, gumbel1_Qinv};
+pub use super::gumbel::type_2::{gumbel2_P, gumbel2_Pinv, gumbel2_Q, gumbel2_Qinv};
+pub use super::hypergeometric::{hypergeometric_P, hypergeometric_Q};
+pub use super::laplace::{laplace_P, laplace_Pinv, laplace_Q, laplace_Qinv};
+pub use super::logistic::{logistic_P, logistic_Pinv, logistic_Q, logistic_Qinv};
+pub use super::lognormal::{lognormal_P, lognormal_Pinv, lognormal_Q, lognormal_Qinv};
+pub use super::negative_binomial::{negative_binomial_P, negative_binomial_Q};
+pub use super::pareto::{pareto_P, pareto_Pinv, pareto_Q, pareto_Qinv};
+pub use super::pascal::{pascal_P, pascal_Q};
+pub use super::poisson::{poisson_P, poisson_Q};
+pub use super::rayleigh::{rayleigh_P, rayleigh_Pinv, rayleigh_Q, rayleigh_Qinv};
+pub use super::t_distribution::{tdist_P, tdist_Pinv, tdist_Q, tdist_Qinv};
+pub use super::weibull::{weibull_P, weibull_Pinv, weibull_Q, weibull_Qinv};
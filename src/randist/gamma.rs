@@ -35,3 +35,10 @@ pub fn gamma_Pinv(P: f64, a: f64, b: f64) -> f64 {
 pub fn gamma_Qinv(Q: f64, a: f64, b: f64) -> f64 {
     unsafe { sys::gsl_cdf_gamma_Qinv(Q, a, b) }
 }
+
+/// This function computes the probability density p(x) at x for an Erlang distribution with
+/// scale parameter a and order n.
+#[doc(alias = "gsl_ran_erlang_pdf")]
+pub fn erlang_pdf(x: f64, a: f64, n: f64) -> f64 {
+    unsafe { sys::gsl_ran_erlang_pdf(x, a, n) }
+}
@@ -58,6 +58,7 @@ pub mod beta;
 pub mod binomial;
 pub mod bivariate_gaussian;
 pub mod cauchy;
+pub mod cdf;
 pub mod chi_squared;
 pub mod dirichlet;
 pub mod exponential;
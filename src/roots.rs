@@ -2,7 +2,7 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
-use crate::Value;
+use crate::{RootFSolver, RootFSolverType, RootFdfSolver, RootFdfSolverType, Value};
 
 #[doc(alias = "gsl_root_test_interval")]
 pub fn test_interval(x_lower: f64, x_upper: f64, epsabs: f64, epsrel: f64) -> Value {
@@ -18,3 +18,162 @@ pub fn test_residual(f: f64, epsabs: f64) -> Value {
 pub fn test_delta(x1: f64, x0: f64, epsabs: f64, epsrel: f64) -> Value {
     Value::from(unsafe { sys::gsl_root_test_delta(x1, x0, epsabs, epsrel) })
 }
+
+/// Tolerances of the root-finding drivers [`find`], [`find_with`] and [`newton`], built from the
+/// default values by chaining the setters.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RootParams {
+    epsabs: f64,
+    epsrel: f64,
+    max_iter: usize,
+    max_expansions: usize,
+}
+
+impl Default for RootParams {
+    fn default() -> RootParams {
+        RootParams {
+            epsabs: 1e-12,
+            epsrel: 1e-10,
+            max_iter: 100,
+            max_expansions: 50,
+        }
+    }
+}
+
+impl RootParams {
+    /// Sets the absolute and relative tolerances on the root, see [`test_interval`] and
+    /// [`test_delta`]. The default values are 1e-12 and 1e-10.
+    pub fn tolerance(mut self, epsabs: f64, epsrel: f64) -> Self {
+        self.epsabs = epsabs;
+        self.epsrel = epsrel;
+        self
+    }
+
+    /// Sets the maximum number of iterations of the solver. The default value is 100.
+    pub fn max_iter(mut self, max_iter: usize) -> Self {
+        self.max_iter = max_iter;
+        self
+    }
+
+    /// Sets the maximum number of times the initial interval of [`find_with`] may be enlarged to
+    /// bracket a root. The default value is 50.
+    pub fn max_expansions(mut self, max_expansions: usize) -> Self {
+        self.max_expansions = max_expansions;
+        self
+    }
+}
+
+/// Finds a root of `f` around the interval [`lo`, `hi`] with the Brent solver and the default
+/// [`RootParams`], see [`find_with`].
+///
+/// ```
+/// use rgsl::roots;
+///
+/// let (x, err) = roots::find(|x| x * x - 5., 0., 1.).unwrap();
+/// assert!((x - 5f64.sqrt()).abs() <= err);
+/// ```
+pub fn find<F: Fn(f64) -> f64>(f: F, lo: f64, hi: f64) -> Result<(f64, f64), Value> {
+    find_with(f, lo, hi, RootFSolverType::brent(), &RootParams::default())
+}
+
+/// Finds a root of `f` around the interval [`lo`, `hi`] with a solver of type `t`.
+///
+/// If `f(lo)` and `f(hi)` have the same sign, the interval is enlarged geometrically on the side
+/// where |f| is the smallest until it brackets a root, at most `params.max_expansions` times. The
+/// solver is then iterated until the bracketing interval satisfies [`test_interval`].
+///
+/// Returns the root and the width of the final interval, which bounds the error on it. Returns
+/// [`Value::Invalid`] if `lo >= hi` or no bracketing interval was found, [`Value::BadFunction`] if
+/// `f` is not finite at the ends of the interval, [`Value::MaxIteration`] if the solver did not
+/// converge and the error of the failed iteration otherwise.
+pub fn find_with<F: Fn(f64) -> f64>(
+    f: F,
+    lo: f64,
+    hi: f64,
+    t: RootFSolverType,
+    params: &RootParams,
+) -> Result<(f64, f64), Value> {
+    if lo >= hi || lo.is_nan() || hi.is_nan() {
+        return Err(Value::Invalid);
+    }
+    let (mut lo, mut hi) = (lo, hi);
+    let (mut f_lo, mut f_hi) = (f(lo), f(hi));
+    let mut expansions = 0;
+    loop {
+        if !f_lo.is_finite() || !f_hi.is_finite() {
+            return Err(Value::BadFunction);
+        }
+        if f_lo == 0. {
+            return Ok((lo, 0.));
+        }
+        if f_hi == 0. {
+            return Ok((hi, 0.));
+        }
+        if f_lo.signum() != f_hi.signum() {
+            break;
+        }
+        if expansions == params.max_expansions {
+            return Err(Value::Invalid);
+        }
+        expansions += 1;
+        let width = 1.6 * (hi - lo);
+        if f_lo.abs() < f_hi.abs() {
+            lo -= width;
+            f_lo = f(lo);
+        } else {
+            hi += width;
+            f_hi = f(hi);
+        }
+    }
+
+    let mut s = RootFSolver::new(t).ok_or(Value::NoMemory)?;
+    s.set(f, lo, hi)?;
+    for _ in 0..params.max_iter {
+        s.iterate()?;
+        let (lower, upper) = (s.x_lower(), s.x_upper());
+        if test_interval(lower, upper, params.epsabs, params.epsrel) == Value::Success {
+            return Ok((s.root(), upper - lower));
+        }
+    }
+    Err(Value::MaxIteration)
+}
+
+/// Finds a root of `f`, whose derivative is `df`, with Newton's method starting from `x0`.
+///
+/// The iterations stop when the last step satisfies [`test_delta`] with the tolerances of
+/// `params`.
+///
+/// Returns the root and the size of the last step as an estimate of the error, which is
+/// accurate since Newton's method converges quadratically close to a root. Returns
+/// [`Value::MaxIteration`] if the method did not converge and the error of the failed iteration
+/// otherwise.
+///
+/// ```
+/// use rgsl::roots::{self, RootParams};
+///
+/// let (x, _) = roots::newton(|x| x * x - 5., |x| 2. * x, 5., &RootParams::default()).unwrap();
+/// assert!((x - 5f64.sqrt()).abs() < 1e-10);
+/// ```
+pub fn newton<F: Fn(f64) -> f64, DF: Fn(f64) -> f64>(
+    f: F,
+    df: DF,
+    x0: f64,
+    params: &RootParams,
+) -> Result<(f64, f64), Value> {
+    let mut s = RootFdfSolver::new(RootFdfSolverType::newton()).ok_or(Value::NoMemory)?;
+    let fdf = |x: f64, y: &mut f64, dy: &mut f64| {
+        *y = f(x);
+        *dy = df(x);
+    };
+    s.set(&f, &df, fdf, x0)?;
+    let mut x = x0;
+    for _ in 0..params.max_iter {
+        s.iterate()?;
+        let x_new = s.root();
+        if test_delta(x_new, x, params.epsabs, params.epsrel) == Value::Success {
+            return Ok((x_new, (x_new - x).abs()));
+        }
+        x = x_new;
+    }
+    Err(Value::MaxIteration)
+}
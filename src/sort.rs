@@ -108,6 +108,18 @@ pub mod vectors {
         }
     }
 
+    /// Same as [`sort2`] but returns `Value::BadLength` instead of panicking if the vectors do
+    /// not have the same length.
+    pub fn try_sort2<T1, T2>(data1: &mut T1, data2: &mut T2) -> Result<(), Value>
+    where
+        T1: VectorMut<f64> + ?Sized,
+        T2: VectorMut<f64> + ?Sized,
+    {
+        check_equal_len(data1, data2)?;
+        sort2(data1, data2);
+        Ok(())
+    }
+
     /// This function sorts the elements of the vector v into ascending numerical order.
     #[doc(alias = "gsl_sort_vector")]
     #[deprecated(since = "8.0.0", note = "Please use `sort` instead")]
@@ -154,6 +166,170 @@ pub mod vectors {
         let ret = unsafe { sys::gsl_sort_vector_index(p.unwrap_unique(), v.unwrap_shared()) };
         result_handler!(ret, ())
     }
+
+    /// Returns the permutation which sorts `data` into ascending order
+    /// (see [`sort_index`]).  It can be used to reorder companion
+    /// arrays with [`Permutation::permute`].  Returns `None` if the
+    /// permutation cannot be allocated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rgsl::sort::vectors::sort_permutation;
+    /// let p = sort_permutation(&[4., 1., 3., 2.]).unwrap();
+    /// assert_eq!(p.as_slice(), [1, 3, 2, 0]);
+    /// ```
+    #[doc(alias = "gsl_sort_index")]
+    pub fn sort_permutation<T>(data: &T) -> Option<Permutation>
+    where
+        T: Vector<f64> + ?Sized,
+    {
+        let mut p = Permutation::new(T::len(data))?;
+        sort_index(p.as_mut_slice(), data);
+        Some(p)
+    }
+
+    /// Returns the rank of each element of `data`: the element
+    /// `data[i]` would be at position `rank[i]` if the array was sorted
+    /// into ascending order.  This is the inverse of the permutation
+    /// computed by [`sort_index`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rgsl::sort::vectors::rank;
+    /// assert_eq!(rank(&[4., 1., 3., 2.]), [3, 0, 2, 1]);
+    /// ```
+    pub fn rank<T>(data: &T) -> Vec<usize>
+    where
+        T: Vector<f64> + ?Sized,
+    {
+        let mut p = vec![0; T::len(data)];
+        sort_index(&mut p, data);
+        let mut rank = vec![0; p.len()];
+        for (i, &j) in p.iter().enumerate() {
+            rank[j] = i;
+        }
+        rank
+    }
+}
+
+/// The following functions sort arrays of arbitrary elements using a
+/// comparison function, in the same way as the standard C `qsort`
+/// function but with the heapsort algorithm.  The comparison is a
+/// Rust closure, so any type can be sorted.
+pub mod heapsort {
+    use std::any::Any;
+    use std::cell::Cell;
+    use std::cmp::Ordering;
+    use std::os::raw::{c_int, c_void};
+    use std::panic::{self, AssertUnwindSafe};
+    use std::ptr::NonNull;
+
+    type Compare<'a> = dyn FnMut(*const c_void, *const c_void) -> c_int + 'a;
+
+    thread_local! {
+        // GSL comparison functions do not take a user parameter, the
+        // closure of the running sort is found here.
+        static COMPARE: Cell<Option<NonNull<Compare<'static>>>> = const { Cell::new(None) };
+    }
+
+    unsafe extern "C" fn compare(a: *const c_void, b: *const c_void) -> c_int {
+        let mut f = COMPARE
+            .with(|c| c.get())
+            .expect("rgsl::sort::heapsort: no comparison function");
+        (f.as_mut())(a, b)
+    }
+
+    /// Runs `sort` with `cmp` as the comparison function of `compare`.
+    /// If `cmp` panics, the sort is completed with all elements
+    /// considered equal and the panic is then resumed.
+    fn with_compare<T, F, R>(mut cmp: F, sort: impl FnOnce() -> R) -> R
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut panicked: Option<Box<dyn Any + Send>> = None;
+        let mut f = |a: *const c_void, b: *const c_void| -> c_int {
+            if panicked.is_some() {
+                return 0;
+            }
+            let (a, b) = unsafe { (&*(a as *const T), &*(b as *const T)) };
+            match panic::catch_unwind(AssertUnwindSafe(|| cmp(a, b))) {
+                Ok(o) => o as c_int,
+                Err(e) => {
+                    panicked = Some(e);
+                    0
+                }
+            }
+        };
+        let f: &mut Compare<'_> = &mut f;
+        // The lifetime is erased but the pointer is removed from
+        // `COMPARE` before `f` goes out of scope.
+        let f: NonNull<Compare<'static>> = unsafe { std::mem::transmute(f) };
+        let prev = COMPARE.with(|c| c.replace(Some(f)));
+        let ret = sort();
+        COMPARE.with(|c| c.set(prev));
+        if let Some(e) = panicked {
+            panic::resume_unwind(e);
+        }
+        ret
+    }
+
+    /// This function sorts the elements of `data` into ascending
+    /// order according to the comparison function `compare`.  The
+    /// sort is not stable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rgsl::sort::heapsort::heapsort;
+    /// let mut data = ["pear", "apple", "fig"];
+    /// heapsort(&mut data, |a, b| a.len().cmp(&b.len()));
+    /// assert_eq!(data, ["fig", "pear", "apple"]);
+    /// ```
+    #[doc(alias = "gsl_heapsort")]
+    pub fn heapsort<T, F>(data: &mut [T], compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        with_compare(compare, || unsafe {
+            sys::gsl_heapsort(
+                data.as_mut_ptr() as *mut c_void,
+                data.len(),
+                std::mem::size_of::<T>(),
+                Some(self::compare),
+            )
+        })
+    }
+
+    /// This function indirectly sorts the elements of `data` into
+    /// ascending order according to the comparison function
+    /// `compare`, storing the resulting permutation in `p`.  The
+    /// elements of `p` give the index of the array element which
+    /// would have been stored in that position if the array had been
+    /// sorted in place.  The array `data` is not changed.
+    ///
+    /// Returns `Value::BadLength` if `p` and `data` don't have the
+    /// same length.
+    #[doc(alias = "gsl_heapsort_index")]
+    pub fn heapsort_index<T, F>(p: &mut [usize], data: &[T], compare: F) -> Result<(), crate::Value>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        if p.len() != data.len() {
+            return Err(crate::Value::BadLength);
+        }
+        let ret = with_compare(compare, || unsafe {
+            sys::gsl_heapsort_index(
+                p.as_mut_ptr(),
+                data.as_ptr() as *const c_void,
+                data.len(),
+                std::mem::size_of::<T>(),
+                Some(self::compare),
+            )
+        });
+        result_handler!(ret, ())
+    }
 }
 
 /// The functions described in this section select the k smallest or largest elements of a data set of size N. The routines use an O(kN) direct insertion
@@ -277,6 +453,9 @@ pub mod select {
     where
         T: Vector<f64> + ?Sized,
     {
+        if p.len() > T::len(src) {
+            panic!("rgsl::sort::select::sort_largest_index: `p.len() > src.len()`");
+        }
         let ret = unsafe {
             sys::gsl_sort_largest_index(
                 p.as_mut_ptr(),
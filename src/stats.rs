@@ -9,6 +9,362 @@ use crate::vector::VectorMut;
 
 // FIXME: Many functions are missing.
 
+/// # Mean, Standard Deviation and Variance
+///
+/// Returns the arithmetic mean of `data`.  The arithmetic mean, or
+/// sample mean, is denoted by ̂μ and defined as,
+/// ̂μ = (1/N) ∑ xᵢ
+/// where xᵢ are the elements of the dataset `data`.
+///
+/// # Example
+///
+/// ```
+/// use rgsl::stats::mean;
+/// assert_eq!(mean(&[1., 2., 3.]), 2.);
+/// ```
+#[doc(alias = "gsl_stats_mean")]
+pub fn mean<T>(data: &T) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    unsafe { sys::gsl_stats_mean(vector::as_ptr(data), T::stride(data), T::len(data)) }
+}
+
+/// Returns the estimated, or sample, variance of `data`.  The
+/// estimated variance is denoted by ̂σ² and is defined by,
+/// ̂σ² = (1/(N-1)) ∑ (xᵢ - ̂μ)²
+/// where xᵢ are the elements of the dataset `data`.  Note that the
+/// normalization factor of 1/(N-1) results from the derivation of ̂σ²
+/// as an unbiased estimator of the population variance σ².
+#[doc(alias = "gsl_stats_variance")]
+pub fn variance<T>(data: &T) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    unsafe { sys::gsl_stats_variance(vector::as_ptr(data), T::stride(data), T::len(data)) }
+}
+
+/// Returns the sample variance of `data` relative to the given value
+/// of `mean`, ̂σ² = (1/(N-1)) ∑ (xᵢ - mean)².
+#[doc(alias = "gsl_stats_variance_m")]
+pub fn variance_m<T>(data: &T, mean: f64) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    unsafe { sys::gsl_stats_variance_m(vector::as_ptr(data), T::stride(data), T::len(data), mean) }
+}
+
+/// Returns the estimated standard deviation of `data`, the square
+/// root of the estimated variance (see [`variance`]).
+#[doc(alias = "gsl_stats_sd")]
+pub fn sd<T>(data: &T) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    unsafe { sys::gsl_stats_sd(vector::as_ptr(data), T::stride(data), T::len(data)) }
+}
+
+/// Returns the estimated standard deviation of `data` relative to
+/// the given value of `mean`, the square root of [`variance_m`].
+#[doc(alias = "gsl_stats_sd_m")]
+pub fn sd_m<T>(data: &T, mean: f64) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    unsafe { sys::gsl_stats_sd_m(vector::as_ptr(data), T::stride(data), T::len(data), mean) }
+}
+
+/// Returns the total sum of squares (TSS) of `data` about the mean,
+/// TSS = ∑ (xᵢ - ̂μ)².
+#[doc(alias = "gsl_stats_tss")]
+pub fn tss<T>(data: &T) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    unsafe { sys::gsl_stats_tss(vector::as_ptr(data), T::stride(data), T::len(data)) }
+}
+
+/// Returns the total sum of squares (TSS) of `data` about the given
+/// value of `mean`, TSS = ∑ (xᵢ - mean)².
+#[doc(alias = "gsl_stats_tss_m")]
+pub fn tss_m<T>(data: &T, mean: f64) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    unsafe { sys::gsl_stats_tss_m(vector::as_ptr(data), T::stride(data), T::len(data), mean) }
+}
+
+/// Computes an unbiased estimate of the variance of `data` when the
+/// population mean `mean` of the underlying distribution is known a
+/// priori.  In this case the estimator for the variance uses the
+/// factor 1/N and the sample mean ̂μ is replaced by the known
+/// population mean μ,
+/// ̂σ² = (1/N) ∑ (xᵢ - μ)².
+#[doc(alias = "gsl_stats_variance_with_fixed_mean")]
+pub fn variance_with_fixed_mean<T>(data: &T, mean: f64) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    unsafe {
+        sys::gsl_stats_variance_with_fixed_mean(
+            vector::as_ptr(data),
+            T::stride(data),
+            T::len(data),
+            mean,
+        )
+    }
+}
+
+/// Calculates the standard deviation of `data` for a fixed
+/// population mean `mean`, the square root of
+/// [`variance_with_fixed_mean`].
+#[doc(alias = "gsl_stats_sd_with_fixed_mean")]
+pub fn sd_with_fixed_mean<T>(data: &T, mean: f64) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    unsafe {
+        sys::gsl_stats_sd_with_fixed_mean(vector::as_ptr(data), T::stride(data), T::len(data), mean)
+    }
+}
+
+/// # Absolute deviation
+///
+/// Computes the absolute deviation from the mean of `data`.  The
+/// absolute deviation from the mean is defined as,
+/// absdev = (1/N) ∑ |xᵢ - ̂μ|
+/// where xᵢ are the elements of the dataset `data`.  The absolute
+/// deviation from the mean provides a more robust measure of the width
+/// of a distribution than the variance.
+#[doc(alias = "gsl_stats_absdev")]
+pub fn absdev<T>(data: &T) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    unsafe { sys::gsl_stats_absdev(vector::as_ptr(data), T::stride(data), T::len(data)) }
+}
+
+/// Computes the absolute deviation of `data` relative to the given
+/// value of `mean`, absdev = (1/N) ∑ |xᵢ - mean|.
+#[doc(alias = "gsl_stats_absdev_m")]
+pub fn absdev_m<T>(data: &T, mean: f64) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    unsafe { sys::gsl_stats_absdev_m(vector::as_ptr(data), T::stride(data), T::len(data), mean) }
+}
+
+/// # Higher moments (skewness and kurtosis)
+///
+/// Computes the skewness of `data`.  The skewness is defined as,
+/// skew = (1/N) ∑ ((xᵢ - ̂μ)/̂σ)³
+/// where xᵢ are the elements of the dataset `data`.  The skewness
+/// measures the asymmetry of the tails of a distribution.
+#[doc(alias = "gsl_stats_skew")]
+pub fn skew<T>(data: &T) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    unsafe { sys::gsl_stats_skew(vector::as_ptr(data), T::stride(data), T::len(data)) }
+}
+
+/// Computes the skewness of `data` using the given values of the mean
+/// `mean` and standard deviation `sd`,
+/// skew = (1/N) ∑ ((xᵢ - mean)/sd)³.
+#[doc(alias = "gsl_stats_skew_m_sd")]
+pub fn skew_m_sd<T>(data: &T, mean: f64, sd: f64) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    unsafe {
+        sys::gsl_stats_skew_m_sd(
+            vector::as_ptr(data),
+            T::stride(data),
+            T::len(data),
+            mean,
+            sd,
+        )
+    }
+}
+
+/// Computes the kurtosis of `data`.  The kurtosis is defined as,
+/// kurtosis = ((1/N) ∑ ((xᵢ - ̂μ)/̂σ)⁴) - 3.
+/// The kurtosis measures how sharply peaked a distribution is,
+/// relative to its width.  The kurtosis is normalized to zero for a
+/// Gaussian distribution.
+#[doc(alias = "gsl_stats_kurtosis")]
+pub fn kurtosis<T>(data: &T) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    unsafe { sys::gsl_stats_kurtosis(vector::as_ptr(data), T::stride(data), T::len(data)) }
+}
+
+/// Computes the kurtosis of `data` using the given values of the
+/// mean `mean` and standard deviation `sd`,
+/// kurtosis = ((1/N) ∑ ((xᵢ - mean)/sd)⁴) - 3.
+#[doc(alias = "gsl_stats_kurtosis_m_sd")]
+pub fn kurtosis_m_sd<T>(data: &T, mean: f64, sd: f64) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    unsafe {
+        sys::gsl_stats_kurtosis_m_sd(
+            vector::as_ptr(data),
+            T::stride(data),
+            T::len(data),
+            mean,
+            sd,
+        )
+    }
+}
+
+/// # Autocorrelation
+///
+/// Computes the lag-1 autocorrelation of `data`,
+/// a₁ = (∑_{i = 2}^n (xᵢ - ̂μ) (xᵢ₋₁ - ̂μ)) / (∑_{i = 1}^n (xᵢ - ̂μ) (xᵢ - ̂μ)).
+#[doc(alias = "gsl_stats_lag1_autocorrelation")]
+pub fn lag1_autocorrelation<T>(data: &T) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    unsafe {
+        sys::gsl_stats_lag1_autocorrelation(vector::as_ptr(data), T::stride(data), T::len(data))
+    }
+}
+
+/// Computes the lag-1 autocorrelation of `data` using the given
+/// value of the mean `mean`.
+#[doc(alias = "gsl_stats_lag1_autocorrelation_m")]
+pub fn lag1_autocorrelation_m<T>(data: &T, mean: f64) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    unsafe {
+        sys::gsl_stats_lag1_autocorrelation_m(
+            vector::as_ptr(data),
+            T::stride(data),
+            T::len(data),
+            mean,
+        )
+    }
+}
+
+/// # Covariance
+///
+/// Computes the covariance of the datasets `data1` and `data2`,
+/// covar = (1/(n - 1)) ∑_{i = 1}^{n} (xᵢ - ̂x) (yᵢ - ̂y)
+/// where xᵢ and yᵢ are the elements of `data1` and `data2`.
+///
+/// Panics if `data1` and `data2` don't have the same length.
+#[doc(alias = "gsl_stats_covariance")]
+pub fn covariance<T>(data1: &T, data2: &T) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    if T::len(data1) != T::len(data2) {
+        panic!("rgsl::stats::covariance: the size of data1 and data2 must be the same");
+    }
+    unsafe {
+        sys::gsl_stats_covariance(
+            vector::as_ptr(data1),
+            T::stride(data1),
+            vector::as_ptr(data2),
+            T::stride(data2),
+            T::len(data1),
+        )
+    }
+}
+
+/// Computes the covariance of the datasets `data1` and `data2` using
+/// the given values of the means, `mean1` and `mean2`.  This is useful
+/// if you have already computed the means of `data1` and `data2` and
+/// want to avoid recomputing them.
+///
+/// Panics if `data1` and `data2` don't have the same length.
+#[doc(alias = "gsl_stats_covariance_m")]
+pub fn covariance_m<T>(data1: &T, data2: &T, mean1: f64, mean2: f64) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    if T::len(data1) != T::len(data2) {
+        panic!("rgsl::stats::covariance_m: the size of data1 and data2 must be the same");
+    }
+    unsafe {
+        sys::gsl_stats_covariance_m(
+            vector::as_ptr(data1),
+            T::stride(data1),
+            vector::as_ptr(data2),
+            T::stride(data2),
+            T::len(data1),
+            mean1,
+            mean2,
+        )
+    }
+}
+
+/// # Correlation
+///
+/// Efficiently computes the Pearson correlation coefficient between the
+/// datasets `data1` and `data2`,
+/// r = cov(x, y) / (̂σₓ ̂σᵧ).
+///
+/// # Example
+///
+/// ```
+/// use rgsl::stats::correlation;
+/// let r = correlation(&[1., 2., 3.], &[2., 4., 6.]);
+/// assert!((r - 1.).abs() < 1e-12);
+/// ```
+///
+/// Panics if `data1` and `data2` don't have the same length.
+#[doc(alias = "gsl_stats_correlation")]
+pub fn correlation<T>(data1: &T, data2: &T) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    if T::len(data1) != T::len(data2) {
+        panic!("rgsl::stats::correlation: the size of data1 and data2 must be the same");
+    }
+    unsafe {
+        sys::gsl_stats_correlation(
+            vector::as_ptr(data1),
+            T::stride(data1),
+            vector::as_ptr(data2),
+            T::stride(data2),
+            T::len(data1),
+        )
+    }
+}
+
+/// Computes the Spearman rank correlation coefficient between the
+/// datasets `data1` and `data2`.  The Spearman rank correlation
+/// coefficient is the Pearson correlation of the ranks of the data, it
+/// measures how well their relationship can be described by a monotonic
+/// function.  The workspace needed by GSL is allocated internally.
+///
+/// Panics if `data1` and `data2` don't have the same length.
+#[doc(alias = "gsl_stats_spearman")]
+pub fn spearman<T>(data1: &T, data2: &T) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    if T::len(data1) != T::len(data2) {
+        panic!("rgsl::stats::spearman: the size of data1 and data2 must be the same");
+    }
+    let mut work = vec![0.; 2 * T::len(data1)];
+    unsafe {
+        sys::gsl_stats_spearman(
+            vector::as_ptr(data1),
+            T::stride(data1),
+            vector::as_ptr(data2),
+            T::stride(data2),
+            T::len(data1),
+            work.as_mut_ptr(),
+        )
+    }
+}
+
 /// # Weighted Samples
 ///
 /// The functions described in this section allow the computation of
@@ -397,6 +753,8 @@ where
     }
 }
 
+/// Returns the maximum value in `data`.  If the data contains NaNs
+/// then this function returns NaN.
 #[doc(alias = "gsl_stats_max")]
 pub fn max<T>(data: &T) -> f64
 where
@@ -405,6 +763,8 @@ where
     unsafe { sys::gsl_stats_max(vector::as_ptr(data), T::stride(data), T::len(data)) }
 }
 
+/// Returns the minimum value in `data`.  If the data contains NaNs
+/// then this function returns NaN.
 #[doc(alias = "gsl_stats_min")]
 pub fn min<T>(data: &T) -> f64
 where
@@ -434,6 +794,8 @@ where
     (min, max)
 }
 
+/// Returns the index of the maximum value in `data`.  When there
+/// are several equal maximum elements then the first one is chosen.
 #[doc(alias = "gsl_stats_max_index")]
 pub fn max_index<T>(data: &T) -> usize
 where
@@ -442,6 +804,8 @@ where
     unsafe { sys::gsl_stats_max_index(vector::as_ptr(data), T::stride(data), T::len(data)) }
 }
 
+/// Returns the index of the minimum value in `data`.  When there
+/// are several equal minimum elements then the first one is chosen.
 #[doc(alias = "gsl_stats_min_index")]
 pub fn min_index<T>(data: &T) -> usize
 where
@@ -471,6 +835,22 @@ where
     (min, max)
 }
 
+/// Finds the `k`-th smallest element of `data` (counting from 0)
+/// with the quickselect algorithm, which runs in O(n) on average.
+/// The elements of `data` are rearranged.  This is faster than
+/// sorting the whole dataset when a single order statistic is needed;
+/// to get the `k` smallest or largest elements, see
+/// [`crate::sort::select`].
+///
+/// # Example
+///
+/// ```
+/// use rgsl::stats::select;
+/// let mut data = [5., 1., 4., 2., 3.];
+/// assert_eq!(select(&mut data[..], 1), 2.);
+/// ```
+///
+/// Panics if `k` is not smaller than the length of `data`.
 #[cfg(feature = "v2_5")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 #[doc(alias = "gsl_stats_select")]
@@ -478,9 +858,103 @@ pub fn select<T>(data: &mut T, k: usize) -> f64
 where
     T: VectorMut<f64> + ?Sized,
 {
+    if k >= T::len(data) {
+        panic!("rgsl::stats::select: `k` must be smaller than the length of `data`");
+    }
     unsafe { sys::gsl_stats_select(vector::as_mut_ptr(data), T::stride(data), T::len(data), k) }
 }
 
+/// # Median and Percentiles
+///
+/// Returns the median value of `sorted_data`.  The elements of the
+/// array must be in ascending numerical order.  There are no checks
+/// to see whether the data are sorted, so the function
+/// [`crate::sort::vectors::sort`] should always be used first.
+///
+/// When the dataset has an odd number of elements the median is the
+/// value of element (n-1)/2.  When the dataset has an even number of
+/// elements the median is the mean of the two nearest middle values,
+/// elements (n-1)/2 and n/2.  Since the algorithm for computing the
+/// median involves interpolation this function always returns a
+/// floating-point number, even for integer data types.
+#[doc(alias = "gsl_stats_median_from_sorted_data")]
+pub fn median_from_sorted_data<T>(sorted_data: &T) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    unsafe {
+        sys::gsl_stats_median_from_sorted_data(
+            vector::as_ptr(sorted_data),
+            T::stride(sorted_data),
+            T::len(sorted_data),
+        )
+    }
+}
+
+/// Returns a quantile value of `sorted_data`.  The elements of the
+/// array must be in ascending numerical order.  The quantile is
+/// determined by `f`, a fraction between 0 and 1.  For example, to
+/// compute the value of the 75th percentile `f` should have the value
+/// 0.75.
+///
+/// There are no checks to see whether the data are sorted, so the
+/// function [`crate::sort::vectors::sort`] should always be used first.
+///
+/// The quantile is found by interpolation, using the formula
+/// quantile = (1 - δ) x_i + δ x_{i+1}
+/// where i is floor((n - 1)f) and δ is (n-1)f - i.
+///
+/// Panics if `f` is not in [0, 1].
+///
+/// # Example
+///
+/// ```
+/// use rgsl::stats::quantile_from_sorted_data;
+/// assert_eq!(quantile_from_sorted_data(&[1., 2., 3., 4., 5.], 0.75), 4.);
+/// ```
+#[doc(alias = "gsl_stats_quantile_from_sorted_data")]
+pub fn quantile_from_sorted_data<T>(sorted_data: &T, f: f64) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    // GSL reads the elements around index (n - 1)f without checking it.
+    if !(0. ..=1.).contains(&f) {
+        panic!("rgsl::stats::quantile_from_sorted_data: f must be between 0 and 1");
+    }
+    unsafe {
+        sys::gsl_stats_quantile_from_sorted_data(
+            vector::as_ptr(sorted_data),
+            T::stride(sorted_data),
+            T::len(sorted_data),
+            f,
+        )
+    }
+}
+
+/// Returns the quantiles of `sorted_data` for each of the fractions
+/// in `fs`, see [`quantile_from_sorted_data`].
+///
+/// Panics if one of the fractions is not in [0, 1].
+///
+/// # Example
+///
+/// ```
+/// use rgsl::stats::quantiles_from_sorted_data;
+/// let q = quantiles_from_sorted_data(&[1., 2., 3., 4., 5.], &[0., 0.5, 1.]);
+/// assert_eq!(q, [1., 3., 5.]);
+/// ```
+pub fn quantiles_from_sorted_data<T>(sorted_data: &T, fs: &[f64]) -> Vec<f64>
+where
+    T: Vector<f64> + ?Sized,
+{
+    fs.iter()
+        .map(|&f| quantile_from_sorted_data(sorted_data, f))
+        .collect()
+}
+
+/// Returns the median value of `data`.  The data does not need to be
+/// sorted: the median is found with the quickselect algorithm, which
+/// rearranges the elements of `data`.
 #[cfg(feature = "v2_5")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 #[doc(alias = "gsl_stats_median")]
@@ -490,3 +964,175 @@ where
 {
     unsafe { sys::gsl_stats_median(vector::as_mut_ptr(data), T::stride(data), T::len(data)) }
 }
+
+/// # Robust Location Estimates
+///
+/// Returns the trimmed mean of `sorted_data`, the mean of the data
+/// after discarding the fraction `trim` (between 0 and 0.5) of the
+/// smallest and largest values.  The elements of the array must be in
+/// ascending numerical order.  There are no checks to see whether the
+/// data are sorted, so the function [`crate::sort::vectors::sort`]
+/// should always be used first.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_trmean_from_sorted_data")]
+pub fn trmean_from_sorted_data<T>(trim: f64, sorted_data: &T) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    unsafe {
+        sys::gsl_stats_trmean_from_sorted_data(
+            trim,
+            vector::as_ptr(sorted_data),
+            T::stride(sorted_data),
+            T::len(sorted_data),
+        )
+    }
+}
+
+/// Returns the Gastwirth location estimator of `sorted_data`, a
+/// weighted sum of the 1/3, 1/2 and 2/3 quantiles,
+/// μ_G = 0.3 Q_{1/3} + 0.4 Q_{1/2} + 0.3 Q_{2/3}.
+/// The elements of the array must be in ascending numerical order.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_gastwirth_from_sorted_data")]
+pub fn gastwirth_from_sorted_data<T>(sorted_data: &T) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    unsafe {
+        sys::gsl_stats_gastwirth_from_sorted_data(
+            vector::as_ptr(sorted_data),
+            T::stride(sorted_data),
+            T::len(sorted_data),
+        )
+    }
+}
+
+/// # Robust Scale Estimates
+///
+/// Returns the median absolute deviation (MAD) of `data`, scaled by
+/// 1.4826 so that it is an unbiased estimate of the standard
+/// deviation for Gaussian data,
+/// MAD = 1.4826 median { |xᵢ - median(x)| }.
+/// The workspace needed by GSL is allocated internally.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_mad")]
+pub fn mad<T>(data: &T) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    let mut work = vec![0.; T::len(data)];
+    unsafe {
+        sys::gsl_stats_mad(
+            vector::as_ptr(data),
+            T::stride(data),
+            T::len(data),
+            work.as_mut_ptr(),
+        )
+    }
+}
+
+/// Returns the median absolute deviation of `data` without the
+/// scale factor of [`mad`],
+/// MAD₀ = median { |xᵢ - median(x)| }.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_mad0")]
+pub fn mad0<T>(data: &T) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    let mut work = vec![0.; T::len(data)];
+    unsafe {
+        sys::gsl_stats_mad0(
+            vector::as_ptr(data),
+            T::stride(data),
+            T::len(data),
+            work.as_mut_ptr(),
+        )
+    }
+}
+
+/// Returns the Sₙ statistic of Croux and Rousseeuw for
+/// `sorted_data`, scaled to be an unbiased estimate of the standard
+/// deviation for Gaussian data,
+/// Sₙ = 1.1926 c_n median_i { median_j |xᵢ - xⱼ| }.
+/// The elements of the array must be in ascending numerical order.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_Sn_from_sorted_data")]
+pub fn Sn_from_sorted_data<T>(sorted_data: &T) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    let mut work = vec![0.; T::len(sorted_data)];
+    unsafe {
+        sys::gsl_stats_Sn_from_sorted_data(
+            vector::as_ptr(sorted_data),
+            T::stride(sorted_data),
+            T::len(sorted_data),
+            work.as_mut_ptr(),
+        )
+    }
+}
+
+/// Returns the Qₙ statistic of Croux and Rousseeuw for
+/// `sorted_data`, scaled to be an unbiased estimate of the standard
+/// deviation for Gaussian data.  Qₙ is the first quartile of the
+/// pairwise distances |xᵢ - xⱼ|, i < j.  The elements of the array
+/// must be in ascending numerical order.
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+#[doc(alias = "gsl_stats_Qn_from_sorted_data")]
+pub fn Qn_from_sorted_data<T>(sorted_data: &T) -> f64
+where
+    T: Vector<f64> + ?Sized,
+{
+    let n = T::len(sorted_data);
+    let mut work = vec![0.; 3 * n];
+    let mut work_int = vec![0; 5 * n];
+    unsafe {
+        sys::gsl_stats_Qn_from_sorted_data(
+            vector::as_ptr(sorted_data),
+            T::stride(sorted_data),
+            n,
+            work.as_mut_ptr(),
+            work_int.as_mut_ptr(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_quantile_from_sorted_data() {
+        let data = [1., 2., 3., 4., 5.];
+        assert_eq!(quantile_from_sorted_data(&data, 0.), 1.);
+        assert_eq!(quantile_from_sorted_data(&data, 1.), 5.);
+        assert_eq!(quantile_from_sorted_data(&data, 0.125), 1.5);
+        assert_eq!(quantiles_from_sorted_data(&data, &[0., 1.]), [1., 5.]);
+    }
+
+    #[test]
+    #[should_panic(expected = "f must be between 0 and 1")]
+    fn test_quantile_above_one() {
+        quantile_from_sorted_data(&[1., 2., 3.], 1.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "f must be between 0 and 1")]
+    fn test_quantile_below_zero() {
+        quantile_from_sorted_data(&[1., 2., 3.], -0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "f must be between 0 and 1")]
+    fn test_quantiles_nan() {
+        quantiles_from_sorted_data(&[1., 2., 3.], &[0.5, f64::NAN]);
+    }
+}
@@ -30,7 +30,11 @@ complete set on that interval. Therefore we can expand a smoothing function as
 f(x) = \sum_i c_i B_(i,k)(x)
 
 given enough (x_j, f(x_j)) data pairs. The coefficients c_i can be readily obtained from a
-least-squares fit.
+least-squares fit, which [`bspline::fit`](crate::bspline::fit) performs.
+
+This module binds the B-spline interface of GSL up to version 2.7. The periodic bases and the
+interpolation-specific initialization (`gsl_bspline_init_periodic`, `gsl_bspline_init_interp`,
+…) were only introduced by the rewrite of the interface in GSL 2.8 and are not available yet.
 
 ### References and Further Reading
 
@@ -48,7 +52,7 @@ http://www.netlib.org/pppack, which is also part of SLATEC.
 !*/
 
 use crate::ffi::FFI;
-use crate::types::VectorF64;
+use crate::types::{MatrixF64, VectorF64};
 use crate::Value;
 
 ffi_wrapper!(
@@ -92,6 +96,26 @@ impl BSpLineWorkspace {
         result_handler!(ret, ())
     }
 
+    /// This function computes the appropriate knots vector from the given Greville abscissae
+    /// (which must be of length [`ncoeffs`](Self::ncoeffs)) and stores it internally, so that
+    /// interpolating at the Greville abscissae can be set up directly.
+    ///
+    /// The Greville abscissae determine the knots only up to a least-squares approximation:
+    /// returns the largest absolute error between the given abscissae and the ones of the
+    /// resulting knots.
+    #[doc(alias = "gsl_bspline_knots_greville")]
+    pub fn knots_greville(&mut self, abscissae: &VectorF64) -> Result<f64, Value> {
+        let mut abserr = 0.;
+        let ret = unsafe {
+            sys::gsl_bspline_knots_greville(
+                abscissae.unwrap_shared(),
+                self.unwrap_unique(),
+                &mut abserr,
+            )
+        };
+        result_handler!(ret, abserr)
+    }
+
     /// This function evaluates all B-spline basis functions at the position x and stores them in
     /// the vector B, so that the i-th element is B_i(x).
     ///
@@ -132,12 +156,86 @@ impl BSpLineWorkspace {
         result_handler!(ret, (istart, iend))
     }
 
+    /// This function evaluates all B-spline basis function derivatives of orders 0 through nderiv
+    /// (inclusive) at the position x and stores them in the matrix dB. The (i,j)-th element of dB
+    /// is d^jB_i(x)/dx^j.
+    ///
+    /// The matrix dB must be of size n = nbreak + k - 2 by nderiv + 1. The value n may also be
+    /// obtained by calling [`ncoeffs`](Self::ncoeffs). Note that function evaluations are
+    /// included as the zeroth order derivatives in dB.
+    ///
+    /// Computing all the basis function derivatives at once is more efficient than computing them
+    /// individually, due to the nature of the defining recurrence relation.
+    #[doc(alias = "gsl_bspline_deriv_eval")]
+    pub fn deriv_eval(&mut self, x: f64, nderiv: usize, dB: &mut MatrixF64) -> Result<(), Value> {
+        let ret = unsafe {
+            sys::gsl_bspline_deriv_eval(x, nderiv, dB.unwrap_unique(), self.unwrap_unique())
+        };
+        result_handler!(ret, ())
+    }
+
+    /// This function evaluates all potentially nonzero B-spline basis function derivatives of
+    /// orders 0 through nderiv (inclusive) at the position x and stores them in the matrix dB.
+    ///
+    /// The (i,j)-th element of dB is d^jB_(istart+i)(x)/dx^j. The last row of dB contains
+    /// d^jB_(iend)(x)/dx^j. The matrix dB must be of size k by at least nderiv + 1. Note that
+    /// function evaluations are included as the zeroth order derivatives in dB.
+    ///
+    /// By returning only the nonzero basis functions, this function allows quantities involving
+    /// linear combinations of the B_i(x) and their derivatives to be computed without unnecessary
+    /// terms.
+    ///
+    /// Returns `(istart, iend)`.
+    #[doc(alias = "gsl_bspline_deriv_eval_nonzero")]
+    pub fn deriv_eval_non_zero(
+        &mut self,
+        x: f64,
+        nderiv: usize,
+        dB: &mut MatrixF64,
+    ) -> Result<(usize, usize), Value> {
+        let mut istart = 0;
+        let mut iend = 0;
+        let ret = unsafe {
+            sys::gsl_bspline_deriv_eval_nonzero(
+                x,
+                nderiv,
+                dB.unwrap_unique(),
+                &mut istart,
+                &mut iend,
+                self.unwrap_unique(),
+            )
+        };
+        result_handler!(ret, (istart, iend))
+    }
+
     /// This function returns the number of B-spline coefficients given by n = nbreak + k - 2.
     #[doc(alias = "gsl_bspline_ncoeffs")]
     pub fn ncoeffs(&mut self) -> usize {
         unsafe { sys::gsl_bspline_ncoeffs(self.unwrap_unique()) }
     }
 
+    /// This function returns the order k of the B-splines.
+    #[doc(alias = "gsl_bspline_order")]
+    pub fn order(&mut self) -> usize {
+        unsafe { sys::gsl_bspline_order(self.unwrap_unique()) }
+    }
+
+    /// This function returns the number of breakpoints nbreak.
+    #[doc(alias = "gsl_bspline_nbreak")]
+    pub fn nbreak(&mut self) -> usize {
+        unsafe { sys::gsl_bspline_nbreak(self.unwrap_unique()) }
+    }
+
+    /// This function returns the location of the i-th breakpoint, once the knots have been set
+    /// with [`knots`](Self::knots) or [`knots_uniform`](Self::knots_uniform).
+    ///
+    /// Asserts that `i < self.nbreak()`.
+    #[doc(alias = "gsl_bspline_breakpoint")]
+    pub fn breakpoint(&mut self, i: usize) -> f64 {
+        assert!(i < self.nbreak(), "breakpoint index out of range");
+        unsafe { sys::gsl_bspline_breakpoint(i, self.unwrap_unique()) }
+    }
+
     /// The Greville abscissae are defined to be the mean location of k-1 consecutive knots in the
     /// knot vector for each basis spline function of order k.
     ///
@@ -21,6 +21,19 @@ pub trait FFFI<T> {
 }
 
 //#[deprecated(note = "Use `Complex64` from the `num_complex` create instead")]
+/// Writes `re+imi` (or `re-imi`), honoring the precision of the formatter for both parts and
+/// right-aligning the whole number to its width.
+fn fmt_complex<T: fmt::Display>(f: &mut Formatter, re: T, im: T) -> fmt::Result {
+    let s = match f.precision() {
+        Some(p) => format!("{:.*}{:+.*}i", p, re, p, im),
+        None => format!("{}{:+}i", re, im),
+    };
+    match f.width() {
+        Some(w) => write!(f, "{:>w$}", s, w = w),
+        None => f.write_str(&s),
+    }
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, PartialEq)]
 pub struct ComplexF64 {
@@ -452,12 +465,80 @@ impl Debug for ComplexF64 {
     }
 }
 
+impl ComplexF64 {
+    // Native binary representation, as used by `gsl_vector_complex_fwrite`.
+    pub(crate) fn write_ne<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+        w.write_all(&self.dat[0].to_ne_bytes())?;
+        w.write_all(&self.dat[1].to_ne_bytes())
+    }
+
+    pub(crate) fn read_ne<R: std::io::Read>(r: &mut R) -> std::io::Result<ComplexF64> {
+        let mut buf = [0; std::mem::size_of::<f64>()];
+        r.read_exact(&mut buf)?;
+        let re = f64::from_ne_bytes(buf);
+        r.read_exact(&mut buf)?;
+        Ok(ComplexF64 {
+            dat: [re, f64::from_ne_bytes(buf)],
+        })
+    }
+}
+
+/// Displays the number as `re+imi`, e.g. `format!("{:.1}", z)` gives `1.0-2.5i`.
+impl fmt::Display for ComplexF64 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fmt_complex(f, self.dat[0], self.dat[1])
+    }
+}
+
 impl Default for ComplexF64 {
     fn default() -> ComplexF64 {
         ComplexF64 { dat: [0f64, 0f64] }
     }
 }
 
+/// The complex number is serialized as the pair `(real, imaginary)`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for ComplexF64 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.dat.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ComplexF64 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<ComplexF64, D::Error> {
+        let dat = <[f64; 2] as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(ComplexF64 { dat })
+    }
+}
+
+/// The real and imaginary parts are compared separately.
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for ComplexF64 {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        f64::EPSILON
+    }
+
+    fn abs_diff_eq(&self, other: &ComplexF64, epsilon: f64) -> bool {
+        self.dat[0].abs_diff_eq(&other.dat[0], epsilon)
+            && self.dat[1].abs_diff_eq(&other.dat[1], epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for ComplexF64 {
+    fn default_max_relative() -> f64 {
+        f64::EPSILON
+    }
+
+    fn relative_eq(&self, other: &ComplexF64, epsilon: f64, max_relative: f64) -> bool {
+        self.dat[0].relative_eq(&other.dat[0], epsilon, max_relative)
+            && self.dat[1].relative_eq(&other.dat[1], epsilon, max_relative)
+    }
+}
+
 impl CFFI<sys::gsl_complex> for ComplexF64 {
     fn wrap(t: sys::gsl_complex) -> ComplexF64 {
         unsafe { std::mem::transmute(t) }
@@ -934,12 +1015,80 @@ impl Debug for ComplexF32 {
     }
 }
 
+impl ComplexF32 {
+    // Native binary representation, as used by `gsl_vector_complex_fwrite`.
+    pub(crate) fn write_ne<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+        w.write_all(&self.dat[0].to_ne_bytes())?;
+        w.write_all(&self.dat[1].to_ne_bytes())
+    }
+
+    pub(crate) fn read_ne<R: std::io::Read>(r: &mut R) -> std::io::Result<ComplexF32> {
+        let mut buf = [0; std::mem::size_of::<f32>()];
+        r.read_exact(&mut buf)?;
+        let re = f32::from_ne_bytes(buf);
+        r.read_exact(&mut buf)?;
+        Ok(ComplexF32 {
+            dat: [re, f32::from_ne_bytes(buf)],
+        })
+    }
+}
+
+/// Displays the number as `re+imi`, e.g. `format!("{:.1}", z)` gives `1.0-2.5i`.
+impl fmt::Display for ComplexF32 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fmt_complex(f, self.dat[0], self.dat[1])
+    }
+}
+
 impl Default for ComplexF32 {
     fn default() -> ComplexF32 {
         ComplexF32 { dat: [0f32, 0f32] }
     }
 }
 
+/// The complex number is serialized as the pair `(real, imaginary)`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for ComplexF32 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.dat.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ComplexF32 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<ComplexF32, D::Error> {
+        let dat = <[f32; 2] as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(ComplexF32 { dat })
+    }
+}
+
+/// The real and imaginary parts are compared separately.
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for ComplexF32 {
+    type Epsilon = f32;
+
+    fn default_epsilon() -> f32 {
+        f32::EPSILON
+    }
+
+    fn abs_diff_eq(&self, other: &ComplexF32, epsilon: f32) -> bool {
+        self.dat[0].abs_diff_eq(&other.dat[0], epsilon)
+            && self.dat[1].abs_diff_eq(&other.dat[1], epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for ComplexF32 {
+    fn default_max_relative() -> f32 {
+        f32::EPSILON
+    }
+
+    fn relative_eq(&self, other: &ComplexF32, epsilon: f32, max_relative: f32) -> bool {
+        self.dat[0].relative_eq(&other.dat[0], epsilon, max_relative)
+            && self.dat[1].relative_eq(&other.dat[1], epsilon, max_relative)
+    }
+}
+
 impl CFFI<sys::gsl_complex> for ComplexF32 {
     fn wrap(s: sys::gsl_complex) -> ComplexF32 {
         ComplexF32 {
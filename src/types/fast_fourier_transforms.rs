@@ -3,11 +3,18 @@
 //
 
 use crate::ffi::FFI;
-use crate::Value;
+use crate::types::matrix::for_each_line;
+use crate::vector::{Vector, VectorMut};
+use crate::{MatrixComplexF32, MatrixComplexF64, Value};
+#[cfg(feature = "complex")]
+use num_complex::Complex;
 use paste::paste;
 
 macro_rules! gsl_fft_wavetable {
-    ($rust_name:ident, $name:ident, $complex_rust_name:ident, $complex_name:ident, $ty:ident $(, $extra:ident)?) => (
+    (
+        $rust_name:ident, $name:ident, $complex_rust_name:ident, $complex_name:ident, $ty:ident,
+        $matrix:ident $(, $extra:ident)?
+    ) => (
 paste! {
 
 ffi_wrapper!(
@@ -154,8 +161,142 @@ impl $complex_rust_name {
         };
         result_handler!(ret, ())
     }
+
+    // Runs the GSL transform `f` on `n` elements of the packed complex array starting at `data`.
+    unsafe fn run(
+        &mut self,
+        f: [<$complex_rust_name Fn>],
+        data: *mut $ty,
+        stride: usize,
+        n: usize,
+        wavetable: &$rust_name,
+    ) -> Result<(), Value> {
+        let ret = f(data, stride, n, wavetable.unwrap_shared(), self.unwrap_unique());
+        result_handler!(ret, ())
+    }
+
+    #[cfg(feature = "complex")]
+    fn run_vector<V: VectorMut<Complex<$ty>> + ?Sized>(
+        &mut self,
+        f: [<$complex_rust_name Fn>],
+        data: &mut V,
+        wavetable: &$rust_name,
+    ) -> Result<(), Value> {
+        let (stride, n) = (V::stride(data), V::len(data));
+        let data = V::as_mut_slice(data).as_mut_ptr() as *mut $ty;
+        unsafe { self.run(f, data, stride, n, wavetable) }
+    }
+
+    fn run_matrix(
+        &mut self,
+        f: [<$complex_rust_name Fn>],
+        m: &mut $matrix,
+        rows: bool,
+        wavetable: &$rust_name,
+    ) -> Result<(), Value> {
+        let Some(m) = (unsafe { m.unwrap_unique().as_ref() }) else {
+            return Ok(());
+        };
+        unsafe {
+            for_each_line(m.data, (m.size1, m.size2, m.tda), 2, rows, |data, stride, n| {
+                self.run(f, data, stride, n, wavetable)
+            })
+        }
+    }
+
+    /// Same as
+    #[doc = "[`forward`](" $complex_rust_name "::forward)"]
+    /// for any [`VectorMut`](crate::vector::VectorMut) of complex numbers, e.g. a slice or a
+    /// (strided) complex vector view, whose length and stride are used for the transform. No
+    /// data is copied.
+    #[cfg(feature = "complex")]
+    pub fn forward_vector<V: VectorMut<Complex<$ty>> + ?Sized>(
+        &mut self,
+        data: &mut V,
+        wavetable: &$rust_name,
+    ) -> Result<(), Value> {
+        self.run_vector(sys::[<$name $($extra)? _forward>], data, wavetable)
+    }
+
+    /// Same as
+    #[doc = "[`forward_vector`](" $complex_rust_name "::forward_vector)"]
+    /// for the backward transform.
+    #[cfg(feature = "complex")]
+    pub fn backward_vector<V: VectorMut<Complex<$ty>> + ?Sized>(
+        &mut self,
+        data: &mut V,
+        wavetable: &$rust_name,
+    ) -> Result<(), Value> {
+        self.run_vector(sys::[<$name $($extra)? _backward>], data, wavetable)
+    }
+
+    /// Same as
+    #[doc = "[`forward_vector`](" $complex_rust_name "::forward_vector)"]
+    /// for the inverse transform.
+    #[cfg(feature = "complex")]
+    pub fn inverse_vector<V: VectorMut<Complex<$ty>> + ?Sized>(
+        &mut self,
+        data: &mut V,
+        wavetable: &$rust_name,
+    ) -> Result<(), Value> {
+        self.run_vector(sys::[<$name $($extra)? _inverse>], data, wavetable)
+    }
+
+    /// Computes in place the forward FFT of each row of `m`. The workspace and the wavetable
+    /// must have been allocated for the number of columns of `m`.
+    pub fn forward_rows(&mut self, m: &mut $matrix, wavetable: &$rust_name) -> Result<(), Value> {
+        self.run_matrix(sys::[<$name $($extra)? _forward>], m, true, wavetable)
+    }
+
+    /// Computes in place the backward FFT of each row of `m`.
+    pub fn backward_rows(&mut self, m: &mut $matrix, wavetable: &$rust_name) -> Result<(), Value> {
+        self.run_matrix(sys::[<$name $($extra)? _backward>], m, true, wavetable)
+    }
+
+    /// Computes in place the inverse FFT of each row of `m`.
+    pub fn inverse_rows(&mut self, m: &mut $matrix, wavetable: &$rust_name) -> Result<(), Value> {
+        self.run_matrix(sys::[<$name $($extra)? _inverse>], m, true, wavetable)
+    }
+
+    /// Computes in place the forward FFT of each column of `m`, using the row stride of the
+    /// matrix. The workspace and the wavetable must have been allocated for the number of rows
+    /// of `m`.
+    pub fn forward_columns(
+        &mut self,
+        m: &mut $matrix,
+        wavetable: &$rust_name,
+    ) -> Result<(), Value> {
+        self.run_matrix(sys::[<$name $($extra)? _forward>], m, false, wavetable)
+    }
+
+    /// Computes in place the backward FFT of each column of `m`.
+    pub fn backward_columns(
+        &mut self,
+        m: &mut $matrix,
+        wavetable: &$rust_name,
+    ) -> Result<(), Value> {
+        self.run_matrix(sys::[<$name $($extra)? _backward>], m, false, wavetable)
+    }
+
+    /// Computes in place the inverse FFT of each column of `m`.
+    pub fn inverse_columns(
+        &mut self,
+        m: &mut $matrix,
+        wavetable: &$rust_name,
+    ) -> Result<(), Value> {
+        self.run_matrix(sys::[<$name $($extra)? _inverse>], m, false, wavetable)
+    }
 }
 
+// Signature of the GSL forward, backward and inverse transforms.
+type [<$complex_rust_name Fn>] = unsafe extern "C" fn(
+    *mut $ty,
+    usize,
+    usize,
+    *const sys::[<$name _wavetable $($extra)?>],
+    *mut sys::$complex_name,
+) -> std::os::raw::c_int;
+
 } // end of paste! block
 ); // end of macro block
 }
@@ -165,7 +306,8 @@ gsl_fft_wavetable!(
     gsl_fft_complex,
     FftComplexF64Workspace,
     gsl_fft_complex_workspace,
-    f64
+    f64,
+    MatrixComplexF64
 );
 gsl_fft_wavetable!(
     FftComplexF32WaveTable,
@@ -173,5 +315,215 @@ gsl_fft_wavetable!(
     FftComplexF32Workspace,
     gsl_fft_complex_workspace_float,
     f32,
+    MatrixComplexF32,
     _float
 );
+
+macro_rules! gsl_fft_real_wavetable {
+    ($real_rust_name:ident, $hc_rust_name:ident, $workspace_rust_name:ident $(, $extra:ident)?) => (
+paste! {
+
+ffi_wrapper!(
+    $real_rust_name,
+    *mut sys::[<gsl_fft_real_wavetable $($extra)?>],
+    [<gsl_fft_real_wavetable $($extra)? _free>],
+    "Trigonometric lookup table for the forward FFT of real data of a given length."
+);
+
+impl $real_rust_name {
+    /// This function prepares a trigonometric lookup table for an FFT of size n real elements.
+    /// The length n is factorized into a product of subtransforms, and the factors and their
+    /// trigonometric coefficients are stored in the wavetable. Returns `None` in case of error.
+    ///
+    /// The wavetable can be used repeatedly for any transform of the same length. It is not
+    /// modified by calls to any of the other FFT functions.
+    #[doc(alias = gsl_fft_real_wavetable $($extra)? _alloc)]
+    pub fn new(n: usize) -> Option<Self> {
+        let tmp = unsafe { sys::[<gsl_fft_real_wavetable $($extra)? _alloc>](n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+}
+
+ffi_wrapper!(
+    $hc_rust_name,
+    *mut sys::[<gsl_fft_halfcomplex_wavetable $($extra)?>],
+    [<gsl_fft_halfcomplex_wavetable $($extra)? _free>],
+    "Trigonometric lookup table for the inverse FFT of half-complex data of a given length."
+);
+
+impl $hc_rust_name {
+    /// This function prepares a trigonometric lookup table for an FFT of size n half-complex
+    /// elements. It is the counterpart of
+    #[doc = "[`" $real_rust_name "::new`]"]
+    /// for the inverse and backward transforms.
+    #[doc(alias = gsl_fft_halfcomplex_wavetable $($extra)? _alloc)]
+    pub fn new(n: usize) -> Option<Self> {
+        let tmp = unsafe { sys::[<gsl_fft_halfcomplex_wavetable $($extra)? _alloc>](n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+}
+
+ffi_wrapper!(
+    $workspace_rust_name,
+    *mut sys::[<gsl_fft_real_workspace $($extra)?>],
+    [<gsl_fft_real_workspace $($extra)? _free>],
+    "Workspace for the FFTs of real and half-complex data of a given length."
+);
+
+impl $workspace_rust_name {
+    /// This function allocates a workspace for a real transform of length n. The same workspace
+    /// can be used for both forward real and inverse halfcomplex transforms.
+    #[doc(alias = gsl_fft_real_workspace $($extra)? _alloc)]
+    pub fn new(n: usize) -> Option<Self> {
+        let tmp = unsafe { sys::[<gsl_fft_real_workspace $($extra)? _alloc>](n) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+}
+
+} // end of paste! block
+); // end of macro block
+}
+
+gsl_fft_real_wavetable!(
+    FftRealF64WaveTable,
+    FftHalfComplexF64WaveTable,
+    FftRealF64Workspace
+);
+gsl_fft_real_wavetable!(
+    FftRealF32WaveTable,
+    FftHalfComplexF32WaveTable,
+    FftRealF32Workspace,
+    _float
+);
+
+/// A plan for FFTs of real `f64` data of a fixed length.
+///
+/// The plan allocates the wavetables and the workspace once, so it can be reused for any number
+/// of transforms of the same length, e.g. when processing a stream of blocks. The data can be
+/// any [`Vector`], whose stride is taken into account.
+///
+/// The forward transform produces a half-complex sequence in the mixed-radix layout, which can
+/// be converted into complex numbers with [`halfcomplex::unpack`](crate::fft::halfcomplex::unpack).
+///
+/// ```
+/// use rgsl::FftPlan;
+///
+/// let mut plan = FftPlan::new(5).unwrap();
+/// let mut data = [1., 2., 3., 4., 5.];
+/// plan.forward(&mut data).unwrap();
+/// plan.inverse(&mut data).unwrap();
+/// ```
+pub struct FftPlan {
+    n: usize,
+    real: FftRealF64WaveTable,
+    halfcomplex: FftHalfComplexF64WaveTable,
+    work: FftRealF64Workspace,
+}
+
+impl FftPlan {
+    /// Creates a plan for transforms of length `n`. Returns `None` if `n` is zero or if the
+    /// allocation fails.
+    pub fn new(n: usize) -> Option<FftPlan> {
+        if n == 0 {
+            return None;
+        }
+        Some(FftPlan {
+            n,
+            real: FftRealF64WaveTable::new(n)?,
+            halfcomplex: FftHalfComplexF64WaveTable::new(n)?,
+            work: FftRealF64Workspace::new(n)?,
+        })
+    }
+
+    /// Returns the length of the transforms computed by the plan.
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Always `false` since plans cannot be created for a length of zero.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    fn check_len<T: Vector<f64> + ?Sized>(&self, data: &T) -> Result<(), Value> {
+        if T::len(data) == self.n {
+            Ok(())
+        } else {
+            Err(Value::BadLength)
+        }
+    }
+
+    /// Computes the forward FFT of the real `data` in place, the result being a half-complex
+    /// sequence. Returns [`Value::BadLength`] if `data` does not have the length of the plan.
+    pub fn forward<T: VectorMut<f64> + ?Sized>(&mut self, data: &mut T) -> Result<(), Value> {
+        self.check_len(data)?;
+        crate::fft::real::transform(data, &self.real, &mut self.work)
+    }
+
+    /// Computes the inverse FFT of the half-complex `data` in place, the result being real.
+    /// Returns [`Value::BadLength`] if `data` does not have the length of the plan.
+    pub fn inverse<T: VectorMut<f64> + ?Sized>(&mut self, data: &mut T) -> Result<(), Value> {
+        self.check_len(data)?;
+        crate::fft::halfcomplex::inverse(data, &self.halfcomplex, &mut self.work)
+    }
+
+    /// Same as [`inverse`](Self::inverse) without the 1/n normalization.
+    pub fn backward<T: VectorMut<f64> + ?Sized>(&mut self, data: &mut T) -> Result<(), Value> {
+        self.check_len(data)?;
+        crate::fft::halfcomplex::backward(data, &self.halfcomplex, &mut self.work)
+    }
+
+    /// Stores the forward FFT of `input` into `output`, leaving `input` untouched. Returns
+    /// [`Value::BadLength`] if either argument does not have the length of the plan.
+    pub fn forward_to<T1, T2>(&mut self, input: &T1, output: &mut T2) -> Result<(), Value>
+    where
+        T1: Vector<f64> + ?Sized,
+        T2: VectorMut<f64> + ?Sized,
+    {
+        self.check_len(input)?;
+        self.check_len(output)?;
+        copy(input, output);
+        self.forward(output)
+    }
+
+    /// Stores the inverse FFT of `input` into `output`, leaving `input` untouched. Returns
+    /// [`Value::BadLength`] if either argument does not have the length of the plan.
+    pub fn inverse_to<T1, T2>(&mut self, input: &T1, output: &mut T2) -> Result<(), Value>
+    where
+        T1: Vector<f64> + ?Sized,
+        T2: VectorMut<f64> + ?Sized,
+    {
+        self.check_len(input)?;
+        self.check_len(output)?;
+        copy(input, output);
+        self.inverse(output)
+    }
+}
+
+// Copies the elements of `x` into `y`, which have the same length.
+fn copy<T1, T2>(x: &T1, y: &mut T2)
+where
+    T1: Vector<f64> + ?Sized,
+    T2: VectorMut<f64> + ?Sized,
+{
+    let (n, sx, sy) = (T1::len(x), T1::stride(x), T2::stride(y));
+    let x = T1::as_slice(x).iter().step_by(sx).take(n);
+    for (y, x) in T2::as_mut_slice(y).iter_mut().step_by(sy).zip(x) {
+        *y = *x;
+    }
+}
@@ -0,0 +1,47 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+//! The function, its derivative and both of them at once, as given to the `set` method of the
+//! solvers and minimizers using derivatives.
+
+use sys::libc::c_void;
+
+/// The three closures, given to GSL through a single `params` pointer.
+pub(crate) struct Fdf<F, DF, FDF> {
+    pub(crate) f: F,
+    pub(crate) df: DF,
+    pub(crate) fdf: FDF,
+}
+
+/// Boxed [`Fdf`] closures whatever their types, kept by the solvers while GSL uses them.
+pub(crate) trait Callbacks {}
+
+impl<F, DF, FDF> Callbacks for Fdf<F, DF, FDF> {}
+
+impl<F, DF, FDF> Fdf<F, DF, FDF> {
+    /// Boxes the closures and returns them along with the `params` pointer to give to GSL.
+    ///
+    /// GSL keeps this pointer, so the box must be kept alive as long as the solver uses it.
+    /// Moving the box does not move the closures.
+    pub(crate) fn boxed<'a>(f: F, df: DF, fdf: FDF) -> (Box<dyn Callbacks + 'a>, *mut c_void)
+    where
+        F: 'a,
+        DF: 'a,
+        FDF: 'a,
+    {
+        let callbacks = Box::new(Fdf { f, df, fdf });
+        let params = &*callbacks as *const Self as *mut c_void;
+        (callbacks, params)
+    }
+
+    /// Gets the closures back from the `params` pointer returned by [`boxed`](Self::boxed).
+    ///
+    /// # Safety
+    ///
+    /// `params` must have been returned by `boxed` for the same types and the box must still
+    /// be alive.
+    pub(crate) unsafe fn from_params<'b>(params: *mut c_void) -> &'b Self {
+        &*(params as *const Self)
+    }
+}
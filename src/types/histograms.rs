@@ -705,6 +705,139 @@ impl Histogram2D {
     }
 }
 
+// `gsl_histogram2d` is opaque in the generated bindings.
+#[cfg(feature = "serde")]
+#[repr(C)]
+struct RawHistogram2D {
+    nx: usize,
+    ny: usize,
+    xrange: *mut f64,
+    yrange: *mut f64,
+    bin: *mut f64,
+}
+
+/// The histogram is serialized as a structure holding the `n + 1` bin limits `range` and the
+/// `n` bin values `bin`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Histogram {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let h = unsafe { &*self.unwrap_shared() };
+        let (range, bin) = unsafe {
+            (
+                std::slice::from_raw_parts(h.range, h.n + 1),
+                std::slice::from_raw_parts(h.bin, h.n),
+            )
+        };
+        let mut s = serializer.serialize_struct("Histogram", 2)?;
+        s.serialize_field("range", range)?;
+        s.serialize_field("bin", bin)?;
+        s.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Histogram {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Histogram, D::Error> {
+        use serde::de::Error;
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Histogram")]
+        struct Raw {
+            range: Vec<f64>,
+            bin: Vec<f64>,
+        }
+
+        let Raw { range, bin } = Raw::deserialize(deserializer)?;
+        if bin.is_empty() {
+            return Err(D::Error::invalid_length(0, &"at least one bin"));
+        }
+        if range.len() != bin.len() + 1 {
+            return Err(D::Error::invalid_length(
+                range.len(),
+                &"one more range than bins",
+            ));
+        }
+        if !is_increasing(&range) {
+            return Err(D::Error::custom("the ranges must be increasing"));
+        }
+        let mut h = Histogram::new(bin.len())
+            .ok_or_else(|| D::Error::custom("cannot allocate the histogram"))?;
+        h.set_ranges(&range)
+            .map_err(|_| D::Error::custom("the ranges must be increasing"))?;
+        unsafe {
+            let raw = &*h.unwrap_shared();
+            std::slice::from_raw_parts_mut(raw.bin, raw.n).copy_from_slice(&bin);
+        }
+        Ok(h)
+    }
+}
+
+/// The histogram is serialized as a structure holding the `nx + 1` and `ny + 1` bin limits
+/// `xrange` and `yrange` and the `nx * ny` bin values `bin` in row-major order (`bin[i * ny + j]`
+/// is the value of the bin `(i, j)`).
+#[cfg(feature = "serde")]
+impl serde::Serialize for Histogram2D {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let h = unsafe { &*(self.unwrap_shared() as *const RawHistogram2D) };
+        let (xrange, yrange, bin) = unsafe {
+            (
+                std::slice::from_raw_parts(h.xrange, h.nx + 1),
+                std::slice::from_raw_parts(h.yrange, h.ny + 1),
+                std::slice::from_raw_parts(h.bin, h.nx * h.ny),
+            )
+        };
+        let mut s = serializer.serialize_struct("Histogram2D", 3)?;
+        s.serialize_field("xrange", xrange)?;
+        s.serialize_field("yrange", yrange)?;
+        s.serialize_field("bin", bin)?;
+        s.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Histogram2D {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Histogram2D, D::Error> {
+        use serde::de::Error;
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Histogram2D")]
+        struct Raw {
+            xrange: Vec<f64>,
+            yrange: Vec<f64>,
+            bin: Vec<f64>,
+        }
+
+        let Raw {
+            xrange,
+            yrange,
+            bin,
+        } = Raw::deserialize(deserializer)?;
+        if xrange.len() < 2 || yrange.len() < 2 {
+            return Err(D::Error::custom("the ranges must have at least two limits"));
+        }
+        if !is_increasing(&xrange) || !is_increasing(&yrange) {
+            return Err(D::Error::custom("the ranges must be increasing"));
+        }
+        let (nx, ny) = (xrange.len() - 1, yrange.len() - 1);
+        if nx.checked_mul(ny) != Some(bin.len()) {
+            return Err(D::Error::invalid_length(bin.len(), &"nx * ny bins"));
+        }
+        let mut h = Histogram2D::new(nx, ny)
+            .ok_or_else(|| D::Error::custom("cannot allocate the histogram"))?;
+        h.set_ranges(&xrange, &yrange)
+            .map_err(|_| D::Error::custom("the ranges must be increasing"))?;
+        unsafe {
+            let raw = &*(h.unwrap_shared() as *const RawHistogram2D);
+            std::slice::from_raw_parts_mut(raw.bin, nx * ny).copy_from_slice(&bin);
+        }
+        Ok(h)
+    }
+}
+
 ffi_wrapper!(
     Histogram2DPdf,
     *mut sys::gsl_histogram2d_pdf,
@@ -760,3 +893,10 @@ impl Histogram2DPdf {
         result_handler!(ret, (x, y))
     }
 }
+
+/// Returns `true` if the values are strictly increasing (and none of them is NaN), as required by
+/// the ranges of the histograms.
+#[cfg(feature = "serde")]
+fn is_increasing(range: &[f64]) -> bool {
+    range.windows(2).all(|w| w[0] < w[1])
+}
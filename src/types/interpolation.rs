@@ -38,9 +38,31 @@ D.M. Young, R.T. Gregory A Survey of Numerical Mathematics (Volume 1), Chapter 6
 !*/
 
 use crate::ffi::FFI;
+use crate::vector::Vector;
 use crate::Value;
+use std::borrow::Cow;
+
+// GSL needs the data as contiguous arrays.
+fn contiguous<V: Vector<f64> + ?Sized>(v: &V) -> Cow<'_, [f64]> {
+    let (len, stride) = (V::len(v), V::stride(v));
+    if stride == 1 {
+        Cow::Borrowed(&V::as_slice(v)[..len])
+    } else {
+        Cow::Owned(
+            V::as_slice(v)
+                .iter()
+                .step_by(stride)
+                .take(len)
+                .copied()
+                .collect(),
+        )
+    }
+}
 
 /// Evaluation accelerator.
+///
+/// An accelerator only holds plain counters, so it is [`Send`] and [`Sync`]: per-thread
+/// accelerators can be kept in a pool and handed out to worker threads.
 #[derive(Clone)]
 pub struct InterpAccel(pub sys::gsl_interp_accel);
 
@@ -59,12 +81,33 @@ impl InterpAccel {
 
     /// This function reinitializes the accelerator object acc. It should be used when the cached
     /// information is no longer applicable-for example, when switching to a new dataset.
+    ///
+    /// The [`hit_count`](Self::hit_count) and [`miss_count`](Self::miss_count) statistics are
+    /// reset as well.
+    #[doc(alias = "gsl_interp_accel_reset")]
     pub fn reset(&mut self) {
         self.0.cache = 0;
         self.0.miss_count = 0;
         self.0.hit_count = 0;
     }
 
+    /// Returns the number of lookups for which the cached interval contained the point.
+    ///
+    /// Evaluating at points in increasing order, or close to each other, increases this count.
+    pub fn hit_count(&self) -> usize {
+        self.0.hit_count
+    }
+
+    /// Returns the number of lookups that required a search of the data array.
+    pub fn miss_count(&self) -> usize {
+        self.0.miss_count
+    }
+
+    /// Returns the index of the interval found by the last lookup.
+    pub fn cache(&self) -> usize {
+        self.0.cache
+    }
+
     /// This function performs a lookup action on the data array x_array of size size, using the
     /// given accelerator a. This is how lookups are performed during evaluation of an
     /// interpolation. The function returns an index i such that `x_array[i] <= x < x_array[i+1]`.
@@ -74,6 +117,12 @@ impl InterpAccel {
     }
 }
 
+// The pooling use case documented on `InterpAccel` relies on this.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<InterpAccel>();
+};
+
 ffi_wrapper!(Interp, *mut sys::gsl_interp, gsl_interp_free);
 
 impl Interp {
@@ -103,9 +152,17 @@ impl Interp {
     /// is always assumed to be strictly ordered, with increasing x values; the behavior for other
     /// arrangements is not defined.
     ///
+    /// `xa` and `ya` may be slices, [`VectorF64`](crate::VectorF64) or views; strided vectors
+    /// are copied first.
+    ///
     /// Asserts that `ya.len() >= xa.len()`.
     #[doc(alias = "gsl_interp_init")]
-    pub fn init(&mut self, xa: &[f64], ya: &[f64]) -> Result<(), Value> {
+    pub fn init<X, Y>(&mut self, xa: &X, ya: &Y) -> Result<(), Value>
+    where
+        X: Vector<f64> + ?Sized,
+        Y: Vector<f64> + ?Sized,
+    {
+        let (xa, ya) = (contiguous(xa), contiguous(ya));
         assert!(ya.len() >= xa.len());
         let ret = unsafe {
             sys::gsl_interp_init(
@@ -210,12 +267,69 @@ impl InterpType {
     pub fn akima_periodic() -> InterpType {
         ffi_wrap!(gsl_interp_akima_periodic)
     }
+
+    /// Steffen’s method guarantees the monotonicity of the interpolating function between the
+    /// given data points. Therefore, minima and maxima can only occur exactly at the data points,
+    /// and there can never be spurious oscillations between data points. The interpolated function
+    /// is piecewise cubic in each interval. The resulting curve and its first derivative are
+    /// guaranteed to be continuous, but the second derivative may be discontinuous.
+    #[doc(alias = "gsl_interp_steffen")]
+    pub fn steffen() -> InterpType {
+        ffi_wrap!(gsl_interp_steffen)
+    }
+
+    /// Returns the interpolation type whose GSL name is `name` (e.g. `"cspline-periodic"`, as
+    /// returned by [`Interp::name`]), or `None` if there is no such type. Underscores are accepted
+    /// in place of hyphens so that the names of the constructors (e.g. `"cspline_periodic"`) can
+    /// be used as well.
+    ///
+    /// ```
+    /// use rgsl::InterpType;
+    ///
+    /// let t = InterpType::from_name("akima_periodic").unwrap();
+    /// assert_eq!(t.name(), "akima-periodic");
+    /// assert!(InterpType::from_name("quintic").is_none());
+    /// ```
+    pub fn from_name(name: &str) -> Option<InterpType> {
+        let name = name.replace('_', "-");
+        INTERP_TYPES
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, t)| t())
+    }
+
+    /// Returns the GSL name of the interpolation type.
+    pub fn name(&self) -> &'static str {
+        INTERP_TYPES
+            .iter()
+            .find(|(_, t)| t().unwrap_shared() == self.unwrap_shared())
+            .map_or("", |(n, _)| n)
+    }
+
+    /// Returns an iterator over all the available interpolation types, e.g. to list them along
+    /// with their [`min_size`](Self::min_size).
+    pub fn all() -> impl Iterator<Item = InterpType> {
+        INTERP_TYPES.iter().map(|(_, t)| t())
+    }
 }
 
+// The interpolation types with their GSL names.
+static INTERP_TYPES: [(&str, fn() -> InterpType); 7] = [
+    ("linear", InterpType::linear),
+    ("polynomial", InterpType::polynomial),
+    ("cspline", InterpType::cspline),
+    ("cspline-periodic", InterpType::cspline_periodic),
+    ("akima", InterpType::akima),
+    ("akima-periodic", InterpType::akima_periodic),
+    ("steffen", InterpType::steffen),
+];
+
 ffi_wrapper!(
     Spline,
     *mut sys::gsl_spline,
-    gsl_spline_free,
+    gsl_spline_free;
+    // Copies of the knots and values, GSL's own arrays being uninitialized until `init` is called.
+    points: (Vec<f64>, Vec<f64>) => (Vec::new(), Vec::new());,
     "General interpolation object."
 );
 
@@ -231,8 +345,18 @@ impl Spline {
         }
     }
 
+    /// Initializes the spline with the data `(xa, ya)`, of which a copy is kept. As for
+    /// [`Interp::init`], `xa` and `ya` may be any [`Vector`].
+    ///
+    /// Asserts that `ya.len() >= xa.len()`.
     #[doc(alias = "gsl_spline_init")]
-    pub fn init(&mut self, xa: &[f64], ya: &[f64]) -> Result<(), Value> {
+    pub fn init<X, Y>(&mut self, xa: &X, ya: &Y) -> Result<(), Value>
+    where
+        X: Vector<f64> + ?Sized,
+        Y: Vector<f64> + ?Sized,
+    {
+        let (xa, ya) = (contiguous(xa), contiguous(ya));
+        assert!(ya.len() >= xa.len());
         let ret = unsafe {
             sys::gsl_spline_init(
                 self.unwrap_unique(),
@@ -241,6 +365,11 @@ impl Spline {
                 xa.len() as _,
             )
         };
+        self.points = if ret == sys::GSL_SUCCESS {
+            (xa.to_vec(), ya[..xa.len()].to_vec())
+        } else {
+            (Vec::new(), Vec::new())
+        };
         result_handler!(ret, ())
     }
 
@@ -260,6 +389,18 @@ impl Spline {
         unsafe { sys::gsl_spline_min_size(self.unwrap_shared()) }
     }
 
+    /// Returns the abscissae of the data points given to [`init`](Self::init), or an empty slice
+    /// if the spline has not been initialized.
+    pub fn knots(&self) -> &[f64] {
+        &self.points.0
+    }
+
+    /// Returns the values of the data points given to [`init`](Self::init), or an empty slice if
+    /// the spline has not been initialized.
+    pub fn values(&self) -> &[f64] {
+        &self.points.1
+    }
+
     #[doc(alias = "gsl_spline_eval")]
     pub fn eval(&self, x: f64, acc: &mut InterpAccel) -> f64 {
         unsafe { sys::gsl_spline_eval(self.unwrap_shared(), x, &mut acc.0) }
@@ -315,4 +456,365 @@ impl Spline {
         };
         result_handler!(ret, result)
     }
+
+    // Stores `f(x)` in `out` for each `x` of `xs`, stopping at the first error.
+    fn eval_batch<F: FnMut(f64) -> Result<f64, Value>>(
+        xs: &[f64],
+        out: &mut [f64],
+        mut f: F,
+    ) -> Result<(), Value> {
+        if xs.len() != out.len() {
+            return Err(Value::BadLength);
+        }
+        for (y, &x) in out.iter_mut().zip(xs) {
+            *y = f(x)?;
+        }
+        Ok(())
+    }
+
+    /// Evaluates the spline at each point of `xs`, storing the values in `out`. The accelerator
+    /// is shared by all the evaluations, so sorting `xs` makes the lookups faster.
+    ///
+    /// Returns [`Value::BadLength`] if `xs` and `out` have different lengths and
+    /// [`Value::Domain`] if a point lies outside of the range of the data, in which case the
+    /// values of `out` from that point on are unspecified.
+    pub fn eval_slice(
+        &self,
+        xs: &[f64],
+        out: &mut [f64],
+        acc: &mut InterpAccel,
+    ) -> Result<(), Value> {
+        Self::eval_batch(xs, out, |x| self.eval_e(x, acc))
+    }
+
+    /// Same as [`eval_slice`](Self::eval_slice) for the derivative of the spline.
+    pub fn eval_deriv_slice(
+        &self,
+        xs: &[f64],
+        out: &mut [f64],
+        acc: &mut InterpAccel,
+    ) -> Result<(), Value> {
+        Self::eval_batch(xs, out, |x| self.eval_deriv_e(x, acc))
+    }
+
+    /// Same as [`eval_slice`](Self::eval_slice) for the second derivative of the spline.
+    pub fn eval_deriv2_slice(
+        &self,
+        xs: &[f64],
+        out: &mut [f64],
+        acc: &mut InterpAccel,
+    ) -> Result<(), Value> {
+        Self::eval_batch(xs, out, |x| self.eval_deriv2_e(x, acc))
+    }
+
+    /// Stores in `out` the integrals of the spline from `a` to each point of `xs`, which must not
+    /// be smaller than `a`. With `a` the first data point, this gives the antiderivative of the
+    /// spline at the points `xs`.
+    ///
+    /// Errors are reported as for [`eval_slice`](Self::eval_slice), points smaller than `a` being
+    /// a [`Value::Domain`] error.
+    pub fn eval_integ_slice(
+        &self,
+        a: f64,
+        xs: &[f64],
+        out: &mut [f64],
+        acc: &mut InterpAccel,
+    ) -> Result<(), Value> {
+        Self::eval_batch(xs, out, |x| self.eval_integ_e(a, x, acc))
+    }
+
+    /// Finds `x` in the interval `bracket` such that the spline takes the value `y` at `x`,
+    /// using Brent's method. This inverts a monotonic calibration curve, for example.
+    ///
+    /// The spline minus `y` must change sign over `bracket` (whose lower bound must not exceed
+    /// the upper one), otherwise [`Value::Invalid`] is returned. [`Value::Domain`] is returned if
+    /// `bracket` is not within the range of the data and [`Value::MaxIteration`] if the root
+    /// could not be located to a relative precision of `1e-12` in 100 iterations.
+    ///
+    /// ```
+    /// use rgsl::{InterpType, Spline};
+    ///
+    /// let x = [0., 1., 2., 3.];
+    /// let y = [0., 1., 4., 9.];
+    /// let mut spline = Spline::new(InterpType::cspline(), x.len()).unwrap();
+    /// spline.init(&x, &y).unwrap();
+    /// let root = spline.solve(4., (1., 3.)).unwrap();
+    /// assert!((root - 2.).abs() < 1e-9);
+    /// ```
+    pub fn solve(&self, y: f64, bracket: (f64, f64)) -> Result<f64, Value> {
+        let (lower, upper) = bracket;
+        let knots = self.knots();
+        match (knots.first(), knots.last()) {
+            (Some(&x0), Some(&x1)) if x0 <= lower && upper <= x1 => {}
+            _ => return Err(Value::Domain),
+        }
+        let acc = std::cell::RefCell::new(InterpAccel::new());
+        let f = |x| self.eval(x, &mut acc.borrow_mut()) - y;
+        // GSL calls the error handler rather than returning an error for these.
+        let (f_lower, f_upper) = (f(lower), f(upper));
+        if lower > upper || (f_lower < 0. && f_upper < 0.) || (f_lower > 0. && f_upper > 0.) {
+            return Err(Value::Invalid);
+        }
+        let mut solver =
+            crate::RootFSolver::new(crate::RootFSolverType::brent()).ok_or(Value::NoMemory)?;
+        solver.set(f, lower, upper)?;
+        for _ in 0..100 {
+            solver.iterate()?;
+            let status = crate::roots::test_interval(solver.x_lower(), solver.x_upper(), 0., 1e-12);
+            if status == Value::Success {
+                return Ok(solver.root());
+            }
+        }
+        Err(Value::MaxIteration)
+    }
+}
+
+/// The spline is serialized as the name of its interpolation type together with its data points
+/// `x` and `y`, from which the coefficients are recomputed on deserialization. The spline must
+/// have been initialized.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Spline {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut s = serializer.serialize_struct("Spline", 3)?;
+        s.serialize_field("name", &self.name())?;
+        s.serialize_field("x", self.knots())?;
+        s.serialize_field("y", self.values())?;
+        s.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Spline {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Spline, D::Error> {
+        use serde::de::Error;
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Spline")]
+        struct Raw {
+            name: String,
+            x: Vec<f64>,
+            y: Vec<f64>,
+        }
+
+        let Raw { name, x, y } = Raw::deserialize(deserializer)?;
+        if x.len() != y.len() {
+            return Err(D::Error::invalid_length(
+                y.len(),
+                &"as many values as knots",
+            ));
+        }
+        let t = InterpType::from_name(&name)
+            .ok_or_else(|| D::Error::custom(format!("unknown interpolation type `{name}`")))?;
+        // GSL calls the error handler for these rather than returning an error.
+        if x.len() < t.min_size() as usize {
+            return Err(D::Error::invalid_length(
+                x.len(),
+                &"at least as many points as required by the interpolation type",
+            ));
+        }
+        if !x.windows(2).all(|w| w[0] < w[1]) {
+            return Err(D::Error::custom("the knots are not strictly increasing"));
+        }
+        let mut s = Spline::new(t, x.len())
+            .ok_or_else(|| D::Error::custom("cannot allocate the spline"))?;
+        s.init(&x, &y)
+            .map_err(|e| D::Error::custom(format!("cannot initialize the spline: {e:?}")))?;
+        Ok(s)
+    }
+}
+
+ffi_wrapper!(
+    Interp2dType,
+    *const sys::gsl_interp2d_type,
+    "Type of two-dimensional interpolation."
+);
+
+impl Interp2dType {
+    /// Bilinear interpolation. This interpolation method does not require any additional memory.
+    #[doc(alias = "gsl_interp2d_bilinear")]
+    pub fn bilinear() -> Interp2dType {
+        ffi_wrap!(gsl_interp2d_bilinear)
+    }
+
+    /// Bicubic interpolation.
+    #[doc(alias = "gsl_interp2d_bicubic")]
+    pub fn bicubic() -> Interp2dType {
+        ffi_wrap!(gsl_interp2d_bicubic)
+    }
+
+    /// This function returns the minimum number of points required by the interpolation type in
+    /// each direction. For example, bicubic interpolation requires a minimum of 4 points.
+    #[doc(alias = "gsl_interp2d_type_min_size")]
+    pub fn min_size(&self) -> usize {
+        unsafe { sys::gsl_interp2d_type_min_size(self.unwrap_shared()) }
+    }
+}
+
+ffi_wrapper!(
+    Spline2D,
+    *mut sys::gsl_spline2d,
+    gsl_spline2d_free,
+    "Two-dimensional interpolation object over a rectangular grid, keeping a copy of the data."
+);
+
+macro_rules! spline2d_eval {
+    ($(#[$attr:meta])* $name:ident) => {
+        paste::paste! {
+            $(#[$attr])*
+            ///
+            /// If (x, y) lies outside of the grid, the error handler is called; use
+            #[doc = "[`" $name "_e`](Self::" $name "_e)"]
+            /// to get an error instead.
+            #[doc(alias = gsl_spline2d_ $name)]
+            pub fn $name(
+                &self,
+                x: f64,
+                y: f64,
+                xacc: &mut InterpAccel,
+                yacc: &mut InterpAccel,
+            ) -> f64 {
+                let (xacc, yacc) = (&mut xacc.0, &mut yacc.0);
+                unsafe { sys::[<gsl_spline2d_ $name>](self.unwrap_shared(), x, y, xacc, yacc) }
+            }
+
+            $(#[$attr])*
+            ///
+            /// Returns [`Value::Domain`] if (x, y) lies outside of the grid.
+            #[doc(alias = gsl_spline2d_ $name _e)]
+            pub fn [<$name _e>](
+                &self,
+                x: f64,
+                y: f64,
+                xacc: &mut InterpAccel,
+                yacc: &mut InterpAccel,
+            ) -> Result<f64, Value> {
+                let mut z = 0.;
+                let ret = unsafe {
+                    sys::[<gsl_spline2d_ $name _e>](
+                        self.unwrap_shared(),
+                        x,
+                        y,
+                        &mut xacc.0,
+                        &mut yacc.0,
+                        &mut z,
+                    )
+                };
+                result_handler!(ret, z)
+            }
+        }
+    };
+}
+
+impl Spline2D {
+    /// This function allocates an interpolation object of type `t` for a grid of `xsize` ×
+    /// `ysize` points.
+    #[doc(alias = "gsl_spline2d_alloc")]
+    pub fn new(t: Interp2dType, xsize: usize, ysize: usize) -> Option<Spline2D> {
+        let tmp = unsafe { sys::gsl_spline2d_alloc(t.unwrap_shared(), xsize, ysize) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Self::wrap(tmp))
+        }
+    }
+
+    /// This function initializes the interpolation object for the grid `(xa, ya)` and the values
+    /// `za`, the value at (xa\[i\], ya\[j\]) being `za[j * xa.len() + i]`. The lengths of `xa` and
+    /// `ya` must be the sizes given to [`new`](Self::new), and both arrays must be strictly
+    /// increasing. The data is copied, so the arrays are not needed anymore afterwards.
+    ///
+    /// Returns [`Value::BadLength`] if `za.len()` is not `xa.len() * ya.len()`.
+    #[doc(alias = "gsl_spline2d_init")]
+    pub fn init(&mut self, xa: &[f64], ya: &[f64], za: &[f64]) -> Result<(), Value> {
+        if za.len() != xa.len() * ya.len() {
+            return Err(Value::BadLength);
+        }
+        let ret = unsafe {
+            sys::gsl_spline2d_init(
+                self.unwrap_unique(),
+                xa.as_ptr(),
+                ya.as_ptr(),
+                za.as_ptr(),
+                xa.len(),
+                ya.len(),
+            )
+        };
+        result_handler!(ret, ())
+    }
+
+    #[doc(alias = "gsl_spline2d_name")]
+    pub fn name(&self) -> String {
+        let tmp = unsafe { sys::gsl_spline2d_name(self.unwrap_shared()) };
+
+        if tmp.is_null() {
+            String::new()
+        } else {
+            unsafe { String::from_utf8_lossy(std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string() }
+        }
+    }
+
+    #[doc(alias = "gsl_spline2d_min_size")]
+    pub fn min_size(&self) -> usize {
+        unsafe { sys::gsl_spline2d_min_size(self.unwrap_shared()) }
+    }
+
+    spline2d_eval!(
+        /// Returns the interpolated value of z at the point (x, y), using the accelerators `xacc`
+        /// and `yacc` for the lookups in each direction.
+        eval
+    );
+
+    spline2d_eval!(
+        /// Returns the interpolated partial derivative ∂z/∂x at the point (x, y).
+        eval_deriv_x
+    );
+
+    spline2d_eval!(
+        /// Returns the interpolated partial derivative ∂z/∂y at the point (x, y).
+        eval_deriv_y
+    );
+
+    spline2d_eval!(
+        /// Returns the interpolated second partial derivative ∂²z/∂x² at the point (x, y).
+        eval_deriv_xx
+    );
+
+    spline2d_eval!(
+        /// Returns the interpolated second partial derivative ∂²z/∂y² at the point (x, y).
+        eval_deriv_yy
+    );
+
+    spline2d_eval!(
+        /// Returns the interpolated cross partial derivative ∂²z/∂x∂y at the point (x, y).
+        eval_deriv_xy
+    );
+
+    /// Same as [`eval_e`](Self::eval_e) but, if (x, y) lies outside of the grid, the value is
+    /// extrapolated instead of returning an error.
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+    #[doc(alias = "gsl_spline2d_eval_extrap_e")]
+    pub fn eval_extrap(
+        &self,
+        x: f64,
+        y: f64,
+        xacc: &mut InterpAccel,
+        yacc: &mut InterpAccel,
+    ) -> Result<f64, Value> {
+        let mut z = 0.;
+        let ret = unsafe {
+            sys::gsl_spline2d_eval_extrap_e(
+                self.unwrap_shared(),
+                x,
+                y,
+                &mut xacc.0,
+                &mut yacc.0,
+                &mut z,
+            )
+        };
+        result_handler!(ret, z)
+    }
 }
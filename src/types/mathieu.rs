@@ -57,6 +57,8 @@ impl MathieuWorkspace {
     }
 
     /// This routine computes a series of Mathieu characteristic values a_n(q), b_n(q) for n from order_min to order_max inclusive, storing the results in the array result_array.
+    ///
+    /// Panics if `result_array` has less than `order_max - order_min + 1` elements.
     #[doc(alias = "gsl_sf_mathieu_a_array")]
     pub fn mathieu_a_array(
         &mut self,
@@ -65,6 +67,7 @@ impl MathieuWorkspace {
         q: f64,
         result_array: &mut [f64],
     ) -> Result<(), Value> {
+        check_array_len("mathieu_a_array", order_min, order_max, result_array.len());
         let ret = unsafe {
             sys::gsl_sf_mathieu_a_array(
                 order_min,
@@ -78,6 +81,8 @@ impl MathieuWorkspace {
     }
 
     /// This routine computes a series of Mathieu characteristic values a_n(q), b_n(q) for n from order_min to order_max inclusive, storing the results in the array result_array.
+    ///
+    /// Panics if `result_array` has less than `order_max - order_min + 1` elements.
     #[doc(alias = "gsl_sf_mathieu_b_array")]
     pub fn mathieu_b_array(
         &mut self,
@@ -86,6 +91,7 @@ impl MathieuWorkspace {
         q: f64,
         result_array: &mut [f64],
     ) -> Result<(), Value> {
+        check_array_len("mathieu_b_array", order_min, order_max, result_array.len());
         let ret = unsafe {
             sys::gsl_sf_mathieu_b_array(
                 order_min,
@@ -117,6 +123,8 @@ impl MathieuWorkspace {
     }
 
     /// This routine computes a series of the angular Mathieu functions ce_n(q,x) and se_n(q,x) of order n from nmin to nmax inclusive, storing the results in the array result_array.
+    ///
+    /// Panics if `result_array` has less than `nmax - nmin + 1` elements.
     #[doc(alias = "gsl_sf_mathieu_ce_array")]
     pub fn mathieu_ce_array(
         &mut self,
@@ -126,6 +134,7 @@ impl MathieuWorkspace {
         x: f64,
         result_array: &mut [f64],
     ) -> Result<(), Value> {
+        check_array_len("mathieu_ce_array", nmin, nmax, result_array.len());
         let ret = unsafe {
             sys::gsl_sf_mathieu_ce_array(
                 nmin,
@@ -140,6 +149,8 @@ impl MathieuWorkspace {
     }
 
     /// This routine computes a series of the angular Mathieu functions ce_n(q,x) and se_n(q,x) of order n from nmin to nmax inclusive, storing the results in the array result_array.
+    ///
+    /// Panics if `result_array` has less than `nmax - nmin + 1` elements.
     #[doc(alias = "gsl_sf_mathieu_se_array")]
     pub fn mathieu_se_array(
         &mut self,
@@ -149,6 +160,7 @@ impl MathieuWorkspace {
         x: f64,
         result_array: &mut [f64],
     ) -> Result<(), Value> {
+        check_array_len("mathieu_se_array", nmin, nmax, result_array.len());
         let ret = unsafe {
             sys::gsl_sf_mathieu_se_array(
                 nmin,
@@ -185,6 +197,8 @@ impl MathieuWorkspace {
     }
 
     /// This routine computes a series of the radial Mathieu functions of kind j, with order from nmin to nmax inclusive, storing the results in the array result_array.
+    ///
+    /// Panics if `result_array` has less than `nmax - nmin + 1` elements.
     #[doc(alias = "gsl_sf_mathieu_Mc_array")]
     pub fn mathieu_Mc_array(
         &mut self,
@@ -195,6 +209,7 @@ impl MathieuWorkspace {
         x: f64,
         result_array: &mut [f64],
     ) -> Result<(), Value> {
+        check_array_len("mathieu_Mc_array", nmin, nmax, result_array.len());
         let ret = unsafe {
             sys::gsl_sf_mathieu_Mc_array(
                 j,
@@ -210,6 +225,8 @@ impl MathieuWorkspace {
     }
 
     /// This routine computes a series of the radial Mathieu functions of kind j, with order from nmin to nmax inclusive, storing the results in the array result_array.
+    ///
+    /// Panics if `result_array` has less than `nmax - nmin + 1` elements.
     #[doc(alias = "gsl_sf_mathieu_Ms_array")]
     pub fn mathieu_Ms_array(
         &mut self,
@@ -220,6 +237,7 @@ impl MathieuWorkspace {
         x: f64,
         result_array: &mut [f64],
     ) -> Result<(), Value> {
+        check_array_len("mathieu_Ms_array", nmin, nmax, result_array.len());
         let ret = unsafe {
             sys::gsl_sf_mathieu_Ms_array(
                 j,
@@ -234,3 +252,13 @@ impl MathieuWorkspace {
         result_handler!(ret, ())
     }
 }
+
+fn check_array_len(name: &str, nmin: i32, nmax: i32, len: usize) {
+    let needed = (nmax - nmin + 1).max(0) as usize;
+    if len < needed {
+        panic!(
+            "rgsl::MathieuWorkspace::{}: `result_array` must have at least {} elements",
+            name, needed
+        );
+    }
+}
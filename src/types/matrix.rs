@@ -51,12 +51,68 @@ B. Stroustrup, The C++ Programming Language (3rd Ed), Section 22.4 Vector Arithm
 !*/
 
 use crate::ffi::{self, FFI};
-use crate::types::{VectorF32, VectorF64, VectorI32, VectorU32};
-use crate::types::{VectorF32View, VectorF64View, VectorI32View, VectorU32View};
+use crate::types::vector::{fmt_matrix, read_value};
+use crate::types::{
+    VectorChar, VectorCharConstView, VectorCharView, VectorF32, VectorF32ConstView, VectorF32View,
+    VectorF64, VectorF64ConstView, VectorF64View, VectorI16, VectorI16ConstView, VectorI16View,
+    VectorI32, VectorI32ConstView, VectorI32View, VectorLong, VectorLongConstView, VectorLongView,
+    VectorU16, VectorU16ConstView, VectorU16View, VectorU32, VectorU32ConstView, VectorU32View,
+    VectorU8, VectorU8ConstView, VectorU8View, VectorULong, VectorULongConstView, VectorULongView,
+};
 use crate::Value;
 use paste::paste;
 use std::fmt::{self, Debug, Formatter};
+use std::io;
 use std::marker::PhantomData;
+use std::os::raw::{c_char, c_long, c_ulong};
+
+/// Returns whether an array of length `len` can hold a `n1 × n2` matrix with `tda` physical
+/// columns.
+fn fits_tda(len: usize, n1: usize, n2: usize, tda: usize) -> bool {
+    n2 <= tda
+        && (n1 == 0
+            || (n1 - 1)
+                .checked_mul(tda)
+                .and_then(|n| n.checked_add(n2))
+                .is_some_and(|n| n <= len))
+}
+
+/// Checks that an array of length `len` can hold a `n1 × n2` matrix with `tda` physical columns.
+fn check_tda(len: usize, n1: usize, n2: usize, tda: usize) {
+    assert!(n1 > 0 && n2 > 0, "n1 and n2 must be positive");
+    assert!(n2 <= tda, "n2 cannot be larger than tda");
+    assert!(
+        fits_tda(len, n1, n2, tda),
+        "(n1 - 1) * tda + n2 cannot be longer than base"
+    );
+}
+
+/// Calls `f(data, stride, n)` on each row (if `rows`) or each column of the `size1 × size2`
+/// matrix with `tda` physical columns whose elements, made of `width` values each (2 for packed
+/// complex numbers), start at `data`. Does nothing if `data` is null (empty view).
+///
+/// This is how the GSL routines working on strided arrays (FFTs, wavelet transforms...) are
+/// applied to the rows or columns of a matrix without copying it.
+pub(crate) unsafe fn for_each_line<T>(
+    data: *mut T,
+    (size1, size2, tda): (usize, usize, usize),
+    width: usize,
+    rows: bool,
+    mut f: impl FnMut(*mut T, usize, usize) -> Result<(), Value>,
+) -> Result<(), Value> {
+    if data.is_null() {
+        return Ok(());
+    }
+    let (count, step, stride, n) = if rows {
+        (size1, tda, 1, size2)
+    } else {
+        (size2, 1, tda, size1)
+    };
+    for i in 0..count {
+        f(data.add(width * i * step), stride, n)?;
+    }
+    Ok(())
+}
 
 macro_rules! gsl_matrix {
     ($rust_name:ident, $name:ident, $rust_ty:ident, $vec_name:ident, $vec_c_name:ident) => (
@@ -79,6 +135,28 @@ impl $rust_name {
         }
     }
 
+    /// Creates a new `n1` × `n2` matrix whose `(i, j)`-th element is `f(i, j)`.
+    ///
+    /// ```
+    #[doc = "use rgsl::" $rust_name ";"]
+    ///
+    #[doc = "let m = " $rust_name "::from_fn(2, 3, |i, j| (i + j) as _).unwrap();"]
+    /// assert_eq!(m.get(1, 2), 3 as _);
+    /// ```
+    pub fn from_fn<F: FnMut(usize, usize) -> $rust_ty>(
+        n1: usize,
+        n2: usize,
+        mut f: F,
+    ) -> Option<$rust_name> {
+        let mut m = $rust_name::new(n1, n2)?;
+        for i in 0..n1 {
+            for j in 0..n2 {
+                m.set(i, j, f(i, j));
+            }
+        }
+        Some(m)
+    }
+
     /// This function returns the (i,j)-th element of the matrix.
     /// If y or x lie outside the allowed range of 0 to n1-1 and 0 to n2-1 then the error handler is
     /// invoked and 0 is returned.
@@ -225,6 +303,55 @@ impl $rust_name {
         }
     }
 
+    /// Builds a new matrix by concatenating the matrices `ms` horizontally (side by side). Empty
+    /// matrices are skipped.
+    ///
+    /// Returns [`Value::BadLength`] if the non-empty matrices do not all have the same number of
+    /// rows and [`Value::Invalid`] if `ms` is empty or if all the matrices are empty.
+    pub fn hstack(ms: &[&$rust_name]) -> Result<$rust_name, Value> {
+        let ms: Vec<_> = ms.iter().filter(|m| m.size1() > 0 && m.size2() > 0).collect();
+        let size1 = ms.first().ok_or(Value::Invalid)?.size1();
+        if ms.iter().any(|m| m.size1() != size1) {
+            return Err(Value::BadLength);
+        }
+        let size2 = ms.iter().map(|m| m.size2()).sum();
+        let mut out = $rust_name::new(size1, size2).ok_or(Value::NoMemory)?;
+        let mut j = 0;
+        for m in ms {
+            out.submatrix(0, j, size1, m.size2()).copy_from(m)?;
+            j += m.size2();
+        }
+        Ok(out)
+    }
+
+    /// Builds a new matrix by concatenating the matrices `ms` vertically (one below the other).
+    /// Empty matrices are skipped.
+    ///
+    /// Returns [`Value::BadLength`] if the non-empty matrices do not all have the same number of
+    /// columns and [`Value::Invalid`] if `ms` is empty or if all the matrices are empty.
+    pub fn vstack(ms: &[&$rust_name]) -> Result<$rust_name, Value> {
+        let ms: Vec<_> = ms.iter().filter(|m| m.size1() > 0 && m.size2() > 0).collect();
+        let size2 = ms.first().ok_or(Value::Invalid)?.size2();
+        if ms.iter().any(|m| m.size2() != size2) {
+            return Err(Value::BadLength);
+        }
+        let size1 = ms.iter().map(|m| m.size1()).sum();
+        let mut out = $rust_name::new(size1, size2).ok_or(Value::NoMemory)?;
+        let mut i = 0;
+        for m in ms {
+            out.submatrix(i, 0, m.size1(), size2).copy_from(m)?;
+            i += m.size1();
+        }
+        Ok(out)
+    }
+
+    /// Returns the transpose of the matrix as a new matrix. Same as
+    #[doc = concat!("[`", stringify!($rust_name), "::transpose_memcpy`].")]
+    #[must_use = "use `transpose` to transpose the matrix in place"]
+    pub fn transposed(&self) -> Result<$rust_name, Value> {
+        self.transpose_memcpy()
+    }
+
     /// This function replaces the matrix m by its transpose by copying the elements of the matrix
     /// in-place. The matrix must be square for this operation to be possible.
     #[doc(alias = $name _transpose)]
@@ -233,6 +360,12 @@ impl $rust_name {
         result_handler!(ret, ())
     }
 
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "::transpose`].")]
+    pub fn transpose_inplace(&mut self) -> Result<(), Value> {
+        self.transpose()
+    }
+
     /// This function adds the elements of the other matrix to the elements of the self matrix.
     /// The result self(i,j) <- self(i,j) + other(i,j) is stored in self and other remains
     /// unchanged. The two matrices must have the same dimensions.
@@ -296,20 +429,29 @@ impl $rust_name {
     }
 
     /// This function returns the maximum value in the self matrix.
+    ///
+    /// Panics if the matrix is empty.
     #[doc(alias = $name _max)]
     pub fn max(&self) -> $rust_ty {
+        self.check_not_empty("max");
         unsafe { sys::[<$name _max>](self.unwrap_shared()) }
     }
 
     /// This function returns the minimum value in the self matrix.
+    ///
+    /// Panics if the matrix is empty.
     #[doc(alias = $name _min)]
     pub fn min(&self) -> $rust_ty {
+        self.check_not_empty("min");
         unsafe { sys::[<$name _min>](self.unwrap_shared()) }
     }
 
     /// This function returns the minimum and maximum values in the self matrix.
+    ///
+    /// Panics if the matrix is empty.
     #[doc(alias = $name _minmax)]
     pub fn minmax(&self) -> ($rust_ty, $rust_ty) {
+        self.check_not_empty("minmax");
         let mut min_out = 0 as _;
         let mut max_out = 0 as _;
         unsafe { sys::[<$name _minmax>](self.unwrap_shared(), &mut min_out, &mut max_out) };
@@ -319,8 +461,11 @@ impl $rust_name {
     /// This function returns the indices of the maximum value in the self matrix. When there are
     /// several equal maximum elements then the first element found is returned, searching in
     /// row-major order.
+    ///
+    /// Panics if the matrix is empty.
     #[doc(alias = $name _max_index)]
     pub fn max_index(&self) -> (usize, usize) {
+        self.check_not_empty("max_index");
         let mut imax = 0;
         let mut jmax = 0;
 
@@ -331,20 +476,26 @@ impl $rust_name {
     /// This function returns the indices of the minimum value in the self matrix. When there are
     /// several equal minimum elements then the first element found is returned, searching in row
     /// major order.
+    ///
+    /// Panics if the matrix is empty.
     #[doc(alias = $name _min_index)]
     pub fn min_index(&self) -> (usize, usize) {
-        let mut imax = 0;
-        let mut jmax = 0;
+        self.check_not_empty("min_index");
+        let mut imin = 0;
+        let mut jmin = 0;
 
-        unsafe { sys::[<$name _min_index>](self.unwrap_shared(), &mut imax, &mut jmax) };
-        (imax, jmax)
+        unsafe { sys::[<$name _min_index>](self.unwrap_shared(), &mut imin, &mut jmin) };
+        (imin, jmin)
     }
 
     /// This function returns the indices of the minimum and maximum values in the self matrix. When
     /// there are several equal minimum or maximum elements then the first elements found are
     /// returned, searching in row-major order.
+    ///
+    /// Panics if the matrix is empty.
     #[doc(alias = $name _minmax_index)]
     pub fn minmax_index(&self) -> (usize, usize, usize, usize) {
+        self.check_not_empty("minmax_index");
         let mut imin = 0;
         let mut jmin = 0;
         let mut imax = 0;
@@ -362,44 +513,100 @@ impl $rust_name {
         (imin, jmin, imax, jmax)
     }
 
-    /// This function returns true if all the elements of the self matrix are stricly zero.
+    /// Returns `true` if all the elements of the matrix are zero.
     #[doc(alias = $name _isnull)]
-    pub fn is_null(&self) -> bool {
+    pub fn is_zero(&self) -> bool {
         unsafe { sys::[<$name _isnull>](self.unwrap_shared()) == 1 }
     }
 
-    /// This function returns true if all the elements of the self matrix are stricly positive.
+    /// Returns `true` if all the elements of the matrix are strictly positive.
     #[doc(alias = $name _ispos)]
-    pub fn is_pos(&self) -> bool {
+    pub fn all_positive(&self) -> bool {
         unsafe { sys::[<$name _ispos>](self.unwrap_shared()) == 1 }
     }
 
-    /// This function returns true if all the elements of the self matrix are stricly negative.
+    /// Returns `true` if all the elements of the matrix are strictly negative.
     #[doc(alias = $name _isneg)]
-    pub fn is_neg(&self) -> bool {
+    pub fn all_negative(&self) -> bool {
         unsafe { sys::[<$name _isneg>](self.unwrap_shared()) == 1 }
     }
 
-    /// This function returns true if all the elements of the self matrix are stricly non-negative.
+    /// Returns `true` if all the elements of the matrix are non-negative.
     #[doc(alias = $name _isnonneg)]
-    pub fn is_non_neg(&self) -> bool {
+    pub fn all_non_negative(&self) -> bool {
         unsafe { sys::[<$name _isnonneg>](self.unwrap_shared()) == 1 }
     }
 
-    /// This function returns true if all elements of the two matrix are equal.
+    #[deprecated(since = "8.0.0", note = "Please use `is_zero` instead")]
+    pub fn is_null(&self) -> bool {
+        self.is_zero()
+    }
+
+    #[deprecated(since = "8.0.0", note = "Please use `all_positive` instead")]
+    pub fn is_pos(&self) -> bool {
+        self.all_positive()
+    }
+
+    #[deprecated(since = "8.0.0", note = "Please use `all_negative` instead")]
+    pub fn is_neg(&self) -> bool {
+        self.all_negative()
+    }
+
+    #[deprecated(since = "8.0.0", note = "Please use `all_non_negative` instead")]
+    pub fn is_non_neg(&self) -> bool {
+        self.all_non_negative()
+    }
+
+    /// Returns `true` if the two matrices have the same dimensions and all their elements are equal.
+    /// This is the same as `==`.
     #[doc(alias = $name _equal)]
     pub fn equal(&self, other: &$rust_name) -> bool {
-        unsafe { sys::[<$name _equal>](self.unwrap_shared(), other.unwrap_shared()) == 1 }
+        // GSL calls the error handler when the dimensions differ.
+        self.size1() == other.size1() && self.size2() == other.size2()
+            && unsafe { sys::[<$name _equal>](self.unwrap_shared(), other.unwrap_shared()) == 1 }
     }
 
+    /// Returns a view of the `i`-th row of the matrix, borrowing it mutably. The view is empty if
+    /// `i` is out of range.
+    ///
+    /// The view implements
+    #[doc = concat!("[`Vector<", stringify!($rust_ty), ">`](crate::vector::Vector)")]
+    /// so it can be passed directly to the [`cblas`](crate::cblas) functions.
     #[doc(alias = $name _row)]
-    pub fn row<F: FnOnce(Option<[<$vec_name View>]>)>(&mut self, i: usize, f: F) {
-        [<$vec_name View>]::wrap(unsafe { sys::[<$name _row>](self.unwrap_unique(), i) }, f)
+    pub fn row(&mut self, i: usize) -> [<$vec_name View>]<'_> {
+        [<$vec_name View>]::new(unsafe { sys::[<$name _row>](self.unwrap_unique(), i) }.vector)
     }
 
+    /// Returns a view of the `j`-th column of the matrix, borrowing it mutably. The view is empty
+    /// if `j` is out of range.
+    ///
+    /// The view implements
+    #[doc = concat!("[`Vector<", stringify!($rust_ty), ">`](crate::vector::Vector)")]
+    /// (with a stride equal to the `tda` of the matrix) so it can be passed directly to the
+    /// [`cblas`](crate::cblas) functions.
     #[doc(alias = $name _column)]
-    pub fn column<F: FnOnce(Option<[<$vec_name View>]>)>(&mut self, j: usize, f: F) {
-        [<$vec_name View>]::wrap(unsafe { sys::[<$name _column>](self.unwrap_unique(), j) }, f)
+    pub fn column(&mut self, j: usize) -> [<$vec_name View>]<'_> {
+        [<$vec_name View>]::new(unsafe { sys::[<$name _column>](self.unwrap_unique(), j) }.vector)
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "::row`]")]
+    /// but only borrows the matrix immutably.
+    #[doc(alias = $name _const_row)]
+    pub fn const_row(&self, i: usize) -> [<$vec_name ConstView>]<'_> {
+        [<$vec_name ConstView>]::new(
+            unsafe { sys::[<$name _const_row>](self.unwrap_shared(), i) }.vector,
+        )
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "::column`]")]
+    /// but only borrows the matrix immutably.
+    #[doc(alias = $name _const_column)]
+    pub fn const_column(&self, j: usize) -> [<$vec_name ConstView>]<'_> {
+        [<$vec_name ConstView>]::new(
+            unsafe { sys::[<$name _const_column>](self.unwrap_shared(), j) }.vector,
+        )
     }
 
     #[doc(alias = $name _diagonal)]
@@ -427,6 +634,9 @@ impl $rust_name {
         [<$vec_name View>]::wrap(unsafe { sys::[<$name _subcolumn>](self.unwrap_unique(), i, offset, n) }, f)
     }
 
+    /// Returns a view of the `n1 × n2` submatrix whose upper-left element is `(k1, k2)`,
+    /// borrowing the matrix mutably. See
+    #[doc = concat!("[`", stringify!($rust_name), "View::from_matrix`].")]
     #[doc(alias = $name _submatrix)]
     pub fn submatrix(
         &mut self,
@@ -438,6 +648,39 @@ impl $rust_name {
         [<$rust_name View>]::from_matrix(self, k1, k2, n1, n2)
     }
 
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "::submatrix`]")]
+    /// but only borrows the matrix immutably.
+    #[doc(alias = $name _const_submatrix)]
+    pub fn const_submatrix(
+        &self,
+        k1: usize,
+        k2: usize,
+        n1: usize,
+        n2: usize,
+    ) -> [<$rust_name ConstView>]<'_> {
+        [<$rust_name ConstView>]::from_matrix(self, k1, k2, n1, n2)
+    }
+
+    fn dims(&self) -> (usize, usize) {
+        (self.size1(), self.size2())
+    }
+
+    // GSL reads the first element unconditionally in the min/max functions.
+    fn check_not_empty(&self, f: &str) {
+        assert!(
+            self.size1() > 0 && self.size2() > 0 && !self.is_empty_view(),
+            "rgsl::{}::{}: empty matrix",
+            stringify!($rust_name),
+            f
+        );
+    }
+
+    // Views whose parameters overrun the underlying memory have a null data pointer.
+    fn is_empty_view(&self) -> bool {
+        self.is_ptr_null() || unsafe { (*self.unwrap_shared()).data.is_null() }
+    }
+
     pub fn size1(&self) -> usize {
         if self.unwrap_shared().is_null() {
             0
@@ -454,6 +697,224 @@ impl $rust_name {
         }
     }
 
+    /// Returns an iterator over the rows of the matrix, each row being a slice of `size2`
+    /// elements.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[$rust_ty]> + ExactSizeIterator {
+        let (size2, tda) = self.row_layout();
+        self.tda_slice().chunks(tda).map(move |row| &row[..size2])
+    }
+
+    /// Returns an iterator over the rows of the matrix allowing to modify them.
+    pub fn rows_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = &mut [$rust_ty]> + ExactSizeIterator {
+        let (size2, tda) = self.row_layout();
+        self.tda_slice_mut().chunks_mut(tda).map(move |row| &mut row[..size2])
+    }
+
+    /// Returns an iterator over the columns of the matrix, each column being itself an iterator
+    /// over its `size1` elements. The iterator is empty if the matrix has no rows.
+    pub fn columns(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = std::iter::StepBy<std::slice::Iter<'_, $rust_ty>>>
+           + ExactSizeIterator {
+        let (size2, tda) = self.row_layout();
+        let data = self.tda_slice();
+        let ncols = if data.is_empty() { 0 } else { size2 };
+        (0..ncols).map(move |j| data[j..].iter().step_by(tda))
+    }
+
+    fn row_layout(&self) -> (usize, usize) {
+        if self.unwrap_shared().is_null() {
+            (0, 1)
+        } else {
+            unsafe { ((*self.unwrap_shared()).size2, (*self.unwrap_shared()).tda.max(1)) }
+        }
+    }
+
+    /// Returns a new matrix whose elements are the images of the elements of `self` by `f`.
+    ///
+    /// Returns `None` if the allocation of the new matrix fails.
+    pub fn map<F: FnMut($rust_ty) -> $rust_ty>(&self, mut f: F) -> Option<$rust_name> {
+        let mut m = $rust_name::new(self.size1(), self.size2())?;
+        for (y, &x) in m.rows_mut().flatten().zip(self.rows().flatten()) {
+            *y = f(x);
+        }
+        Some(m)
+    }
+
+    /// Replaces each element `x` of the matrix by `f(x)`.
+    pub fn map_inplace<F: FnMut($rust_ty) -> $rust_ty>(&mut self, mut f: F) {
+        for x in self.rows_mut().flatten() {
+            *x = f(*x);
+        }
+    }
+
+    /// Returns a new matrix whose elements are `f(x, y)` where `x` and `y` are the elements of
+    /// `self` and `other` at the same position.
+    ///
+    /// Returns [`Value::BadLength`] if the matrices do not have the same dimensions.
+    pub fn zip_map<F: FnMut($rust_ty, $rust_ty) -> $rust_ty>(
+        &self,
+        other: &$rust_name,
+        mut f: F,
+    ) -> Result<$rust_name, Value> {
+        if self.size1() != other.size1() || self.size2() != other.size2() {
+            return Err(Value::BadLength);
+        }
+        let mut m = $rust_name::new(self.size1(), self.size2()).ok_or(Value::NoMemory)?;
+        let xy = self.rows().flatten().zip(other.rows().flatten());
+        for (z, (&x, &y)) in m.rows_mut().flatten().zip(xy) {
+            *z = f(x, y);
+        }
+        Ok(m)
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "::map`]")]
+    /// but the rows are processed in parallel.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "rayon")))]
+    pub fn par_map<F: Fn($rust_ty) -> $rust_ty + Sync + Send>(&self, f: F) -> Option<$rust_name> {
+        use rayon::prelude::*;
+
+        let mut m = $rust_name::new(self.size1(), self.size2())?;
+        m.par_rows_mut()
+            .zip(self.par_rows())
+            .for_each(|(ys, xs)| ys.iter_mut().zip(xs).for_each(|(y, &x)| *y = f(x)));
+        Some(m)
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "::map_inplace`]")]
+    /// but the rows are processed in parallel.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "rayon")))]
+    pub fn par_map_inplace<F: Fn($rust_ty) -> $rust_ty + Sync + Send>(&mut self, f: F) {
+        use rayon::prelude::*;
+
+        self.par_rows_mut()
+            .for_each(|xs| xs.iter_mut().for_each(|x| *x = f(*x)));
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "::zip_map`]")]
+    /// but the rows are processed in parallel.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "rayon")))]
+    pub fn par_zip_map<F: Fn($rust_ty, $rust_ty) -> $rust_ty + Sync + Send>(
+        &self,
+        other: &$rust_name,
+        f: F,
+    ) -> Result<$rust_name, Value> {
+        use rayon::prelude::*;
+
+        if self.size1() != other.size1() || self.size2() != other.size2() {
+            return Err(Value::BadLength);
+        }
+        let mut m = $rust_name::new(self.size1(), self.size2()).ok_or(Value::NoMemory)?;
+        m.par_rows_mut()
+            .zip(self.par_rows().zip(other.par_rows()))
+            .for_each(|(zs, (xs, ys))| {
+                for ((z, &x), &y) in zs.iter_mut().zip(xs).zip(ys) {
+                    *z = f(x, y);
+                }
+            });
+        Ok(m)
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_rows(&self) -> impl rayon::iter::IndexedParallelIterator<Item = &[$rust_ty]> {
+        use rayon::prelude::*;
+
+        let (size2, tda) = self.row_layout();
+        self.tda_slice().par_chunks(tda).map(move |row| &row[..size2])
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_rows_mut(
+        &mut self,
+    ) -> impl rayon::iter::IndexedParallelIterator<Item = &mut [$rust_ty]> {
+        use rayon::prelude::*;
+
+        let (size2, tda) = self.row_layout();
+        self.tda_slice_mut().par_chunks_mut(tda).map(move |row| &mut row[..size2])
+    }
+
+    /// This function writes the elements of the matrix to `w` in binary format, row by row, using
+    /// the native representation of the numbers (the format of `gsl_matrix_fwrite`). Since the
+    /// data is written in the native binary format it may not be portable between different
+    /// architectures.
+    pub fn write_binary<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        for x in self.rows().flatten() {
+            w.write_all(&x.to_ne_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// This function reads into the matrix the binary data written by
+    #[doc = concat!("[`", stringify!($rust_name), "::write_binary`].")]
+    /// The matrix must be preallocated with the correct dimensions since the format contains no
+    /// size information.
+    pub fn read_binary<R: io::Read>(&mut self, r: &mut R) -> io::Result<()> {
+        for x in self.rows_mut().flatten() {
+            let mut buf = [0; std::mem::size_of::<$rust_ty>()];
+            r.read_exact(&mut buf)?;
+            *x = <$rust_ty>::from_ne_bytes(buf);
+        }
+        Ok(())
+    }
+
+    /// This function writes the elements of the matrix to `w`, one per line in row-major order
+    /// (the format of `gsl_matrix_fprintf`).
+    pub fn to_writer<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        for x in self.rows().flatten() {
+            writeln!(w, "{}", x)?;
+        }
+        Ok(())
+    }
+
+    /// This function reads whitespace separated values from `r` into the matrix (the format of
+    /// `gsl_matrix_fscanf`). The matrix must be preallocated with the correct dimensions since
+    /// the format contains no size information.
+    pub fn from_reader<R: io::BufRead>(&mut self, r: &mut R) -> io::Result<()> {
+        for x in self.rows_mut().flatten() {
+            *x = read_value(r)?;
+        }
+        Ok(())
+    }
+
+    // The memory spanned by the matrix elements, `(size1 - 1) * tda + size2` values.
+    fn tda_slice(&self) -> &[$rust_ty] {
+        let (size1, size2) = (self.size1(), self.size2());
+        if size1 == 0 || size2 == 0 {
+            return &[];
+        }
+        unsafe {
+            let m = &*self.unwrap_shared();
+            if m.data.is_null() {
+                &[]
+            } else {
+                std::slice::from_raw_parts(m.data, (size1 - 1) * m.tda + size2)
+            }
+        }
+    }
+
+    fn tda_slice_mut(&mut self) -> &mut [$rust_ty] {
+        let (size1, size2) = (self.size1(), self.size2());
+        if size1 == 0 || size2 == 0 {
+            return &mut [];
+        }
+        unsafe {
+            let m = &*self.unwrap_unique();
+            if m.data.is_null() {
+                &mut []
+            } else {
+                std::slice::from_raw_parts_mut(m.data, (size1 - 1) * m.tda + size2)
+            }
+        }
+    }
+
     pub fn clone(&self) -> Option<Self> {
         if self.unwrap_shared().is_null() {
             None
@@ -511,39 +972,178 @@ impl FFI<sys::$name> for $rust_name {
 }
 
 impl Debug for $rust_name {
-    #[allow(unused_must_use)]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        let ptr = self.unwrap_shared();
-        if ptr.is_null() {
+        if self.unwrap_shared().is_null() {
             write!(f, "<null>")
         } else {
-            let size1 = self.size1();
-            let size2 = self.size2();
-            for y in 0..size1 {
-                write!(f, "[");
-                for x in 0..size2 {
-                    if x < size2 - 1 {
-                        write!(f, "{}, ", self.get(y, x));
-                    } else {
-                        write!(f, "{}", self.get(y, x));
-                    }
-                }
-                if y < size1 - 1 {
-                    write!(f, "]\n");
-                }
+            let (size1, size2) = (self.size1(), self.size2());
+            write!(f, "{} {{ size: ({}, {}), data: ", stringify!($rust_name), size1, size2)?;
+            fmt_matrix(f, size1, size2, true, |f, i, j| Debug::fmt(&self.get(i, j), f))?;
+            write!(f, " }}")
+        }
+    }
+}
+
+/// The elements are displayed with the width and precision given to the formatter, one row per
+/// line. Large matrices are truncated, except with the alternate flag `{:#}`.
+impl fmt::Display for $rust_name {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.unwrap_shared().is_null() {
+            write!(f, "<null>")
+        } else {
+            let (size1, size2) = (self.size1(), self.size2());
+            fmt_matrix(f, size1, size2, !f.alternate(), |f, i, j| {
+                fmt::Display::fmt(&self.get(i, j), f)
+            })
+        }
+    }
+}
+
+impl std::ops::Index<(usize, usize)> for $rust_name {
+    type Output = $rust_ty;
+
+    fn index(&self, (i, j): (usize, usize)) -> &$rust_ty {
+        let (size1, size2) = (self.size1(), self.size2());
+        assert!(
+            i < size1 && j < size2,
+            "index out of bounds: the size is ({}, {}) but the index is ({}, {})",
+            size1, size2, i, j
+        );
+        unsafe {
+            let m = &*self.unwrap_shared();
+            &*m.data.add(i * m.tda + j)
+        }
+    }
+}
+
+impl std::ops::IndexMut<(usize, usize)> for $rust_name {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut $rust_ty {
+        let (size1, size2) = (self.size1(), self.size2());
+        assert!(
+            i < size1 && j < size2,
+            "index out of bounds: the size is ({}, {}) but the index is ({}, {})",
+            size1, size2, i, j
+        );
+        unsafe {
+            let m = &*self.unwrap_unique();
+            &mut *m.data.add(i * m.tda + j)
+        }
+    }
+}
+
+/// Two matrices are equal when they have the same dimensions and the same elements, whatever
+/// their `tda`.
+impl PartialEq for $rust_name {
+    fn eq(&self, other: &$rust_name) -> bool {
+        self.size1() == other.size1()
+            && self.size2() == other.size2()
+            && self.rows().eq(other.rows())
+    }
+}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for $rust_name {
+    type Epsilon = <$rust_ty as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        <$rust_ty as approx::AbsDiffEq>::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &$rust_name, epsilon: Self::Epsilon) -> bool {
+        self.size1() == other.size1()
+            && self.size2() == other.size2()
+            && self
+                .rows()
+                .flatten()
+                .zip(other.rows().flatten())
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+/// The matrix is serialized as a structure holding its dimensions `size1` and `size2` and the
+/// sequence of its elements in row-major order.
+#[cfg(feature = "serde")]
+impl serde::Serialize for $rust_name {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let data = self.rows().flatten().copied().collect::<Vec<_>>();
+        let mut s = serializer.serialize_struct(stringify!($rust_name), 3)?;
+        s.serialize_field("size1", &self.size1())?;
+        s.serialize_field("size2", &self.size2())?;
+        s.serialize_field("data", &data)?;
+        s.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for $rust_name {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<$rust_name, D::Error> {
+        use serde::de::Error;
+
+        #[derive(serde::Deserialize)]
+        struct Raw {
+            size1: usize,
+            size2: usize,
+            data: Vec<$rust_ty>,
+        }
+
+        let Raw { size1, size2, data } = Raw::deserialize(deserializer)?;
+        if size1 == 0 || size2 == 0 {
+            return Err(D::Error::custom("the dimensions of the matrix must be positive"));
+        }
+        if size1.checked_mul(size2) != Some(data.len()) {
+            return Err(D::Error::invalid_length(data.len(), &"size1 * size2 elements"));
+        }
+        let mut m = $rust_name::new(size1, size2)
+            .ok_or_else(|| D::Error::custom("cannot allocate the matrix"))?;
+        for (row, values) in m.rows_mut().zip(data.chunks(size2)) {
+            for (x, &v) in row.iter_mut().zip(values) {
+                *x = v;
             }
-            write!(f, "]")
         }
+        Ok(m)
     }
 }
 
+/// A mutable view of (a part of) a matrix, an array or a vector, borrowing it for `'a`.
+///
+/// The view dereferences to an immutable
+#[doc = concat!("[`", stringify!($rust_name), "`]")]
+/// and provides the methods modifying the elements itself. Writing into the view writes into the
+/// memory it borrows.
+///
+/// If the parameters used to create the view overrun the underlying memory, GSL reports an error
+/// and the view is empty.
 pub struct [<$rust_name View>]<'a> {
-    mat: sys::[<$name _view>],
-    #[allow(dead_code)]
-    phantom: PhantomData<&'a ()>,
+    // Soft wrapper around a heap copy of the `gsl_matrix` struct returned by GSL (so that the
+    // view can be moved around), freed on drop.
+    inner: $rust_name,
+    phantom: PhantomData<&'a mut [$rust_ty]>,
+}
+
+impl<'a> Drop for [<$rust_name View>]<'a> {
+    fn drop(&mut self) {
+        unsafe { drop(Box::from_raw(self.inner.mat)) };
+    }
+}
+
+impl<'a> std::ops::Deref for [<$rust_name View>]<'a> {
+    type Target = $rust_name;
+
+    fn deref(&self) -> &$rust_name {
+        &self.inner
+    }
 }
 
 impl<'a> [<$rust_name View>]<'a> {
+    fn new(m: sys::$name) -> Self {
+        Self {
+            inner: $rust_name::soft_wrap(Box::into_raw(Box::new(m))),
+            phantom: PhantomData,
+        }
+    }
+
     /// These functions return a matrix view of a submatrix of the matrix m. The upper-left element
     /// of the submatrix is the element (k1,k2) of the original matrix. The submatrix has n1 rows
     /// and n2 columns. The physical number of columns in memory given by tda is unchanged.
@@ -553,19 +1153,11 @@ impl<'a> [<$rust_name View>]<'a> {
     ///
     /// where the index i runs from 0 to n1-1 and the index j runs from 0 to n2-1.
     ///
-    /// The data pointer of the returned matrix struct is set to null if the combined parameters
-    /// (i,j,n1,n2,tda) overrun the ends of the original
-    /// matrix.
-    ///
-    /// The new matrix view is only a view of the block underlying the existing matrix, m. The
-    /// block containing the elements of m is not
-    /// owned by the new matrix view. When the view goes out of scope the original matrix m and its
-    /// block will continue to exist. The original memory can only be deallocated by freeing the
-    /// original matrix. Of course, the original matrix should not be deallocated while the view
-    /// is still in use.
+    /// The view is empty if the combined parameters (i,j,n1,n2,tda) overrun the ends of the
+    /// original matrix.
     ///
-    /// The function gsl_matrix_const_submatrix is equivalent to gsl_matrix_submatrix but can be
-    /// used for matrices which are declared const.
+    /// The new matrix view is only a view of the block underlying the existing matrix, m, which
+    /// stays mutably borrowed as long as the view is alive.
     #[doc(alias = $name _submatrix)]
     pub fn from_matrix(
         m: &'a mut $rust_name,
@@ -574,12 +1166,7 @@ impl<'a> [<$rust_name View>]<'a> {
         n1: usize,
         n2: usize,
     ) -> Self {
-        unsafe {
-            Self {
-                mat: sys::[<$name _submatrix>](m.mat, k1, k2, n1, n2),
-                phantom: PhantomData,
-            }
-        }
+        Self::new(unsafe { sys::[<$name _submatrix>](m.unwrap_unique(), k1, k2, n1, n2) }.matrix)
     }
 
     /// These functions return a matrix view of the array base. The matrix has n1 rows and n2
@@ -590,25 +1177,14 @@ impl<'a> [<$rust_name View>]<'a> {
     ///
     /// where the index i runs from 0 to n1-1 and the index j runs from 0 to n2-1.
     ///
-    /// The new matrix is only a view of the array base. When the view goes out of scope the
-    /// original array base will continue to exist. The original memory can only be deallocated by
-    /// freeing the original array. Of course, the original array should not be deallocated while
-    /// the view is still in use.
+    /// The array stays mutably borrowed as long as the view is alive.
     ///
-    /// The function gsl_matrix_const_view_array is equivalent to gsl_matrix_view_array but can be
-    /// used for matrices which are declared const.
+    /// Panics if `n1` or `n2` is zero or if `base` has less than `n1 * n2` elements.
     #[doc(alias = $name _view_array)]
     pub fn from_array(base: &'a mut [$rust_ty], n1: usize, n2: usize) -> Self {
-        assert!(
-            n1 * n2 <= base.len() as _,
-            "n1 * n2 cannot be longer than base"
-        );
-        unsafe {
-            Self {
-                mat: sys::[<$name _view_array>](base.as_mut_ptr(), n1, n2),
-                phantom: PhantomData,
-            }
-        }
+        assert!(n1 > 0 && n2 > 0, "n1 and n2 must be positive");
+        assert!(n1 * n2 <= base.len(), "n1 * n2 cannot be longer than base");
+        Self::new(unsafe { sys::[<$name _view_array>](base.as_mut_ptr(), n1, n2) }.matrix)
     }
 
     /// These functions return a matrix view of the array base with a physical number of columns tda
@@ -620,20 +1196,49 @@ impl<'a> [<$rust_name View>]<'a> {
     ///
     /// where the index i runs from 0 to n1-1 and the index j runs from 0 to n2-1.
     ///
-    /// The new matrix is only a view of the array base. When the view goes out of scope the
-    /// original array base will continue to exist. The original memory can only be deallocated by
-    /// freeing the original array. Of course, the original array should not be deallocated while
-    /// the view is still in use.
+    /// The array stays mutably borrowed as long as the view is alive.
     ///
-    /// The function gsl_matrix_const_view_array_with_tda is equivalent to
-    /// gsl_matrix_view_array_with_tda but can be used for matrices which are declared const.
+    /// Panics if `n1` or `n2` is zero, if `n2 > tda` or if `base` is too short to hold the `n1`
+    /// rows.
     #[doc(alias = $name _view_array_with_tda)]
     pub fn from_array_with_tda(base: &'a mut [$rust_ty], n1: usize, n2: usize, tda: usize) -> Self {
-        unsafe {
-            Self {
-                mat: sys::[<$name _view_array_with_tda>](base.as_mut_ptr(), n1, n2, tda),
-                phantom: PhantomData,
-            }
+        check_tda(base.len(), n1, n2, tda);
+        Self::new(
+            unsafe { sys::[<$name _view_array_with_tda>](base.as_mut_ptr(), n1, n2, tda) }.matrix,
+        )
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "View::from_array`]")]
+    /// but returns `Value::Invalid` instead of panicking if `n1` or `n2` is zero and
+    /// `Value::BadLength` if `base` has less than `n1 * n2` elements.
+    pub fn try_from_array(base: &'a mut [$rust_ty], n1: usize, n2: usize) -> Result<Self, Value> {
+        if n1 == 0 || n2 == 0 {
+            return Err(Value::Invalid);
+        }
+        match n1.checked_mul(n2) {
+            Some(n) if n <= base.len() => Ok(Self::from_array(base, n1, n2)),
+            _ => Err(Value::BadLength),
+        }
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "View::from_array_with_tda`]")]
+    /// but returns `Value::Invalid` instead of panicking if `n1` or `n2` is zero and
+    /// `Value::BadLength` if `n2 > tda` or if `base` is too short to hold the `n1` rows.
+    pub fn try_from_array_with_tda(
+        base: &'a mut [$rust_ty],
+        n1: usize,
+        n2: usize,
+        tda: usize,
+    ) -> Result<Self, Value> {
+        if n1 == 0 || n2 == 0 {
+            return Err(Value::Invalid);
+        }
+        if fits_tda(base.len(), n1, n2, tda) {
+            Ok(Self::from_array_with_tda(base, n1, n2, tda))
+        } else {
+            Err(Value::BadLength)
         }
     }
 
@@ -645,21 +1250,10 @@ impl<'a> [<$rust_name View>]<'a> {
     ///
     /// where the index i runs from 0 to n1-1 and the index j runs from 0 to n2-1.
     ///
-    /// The new matrix is only a view of the vector v. When the view goes out of scope the original
-    /// vector v will continue to exist. The original memory can only be deallocated by freeing the
-    /// original vector. Of course, the original vector should not be deallocated while the view
-    /// is still in use.
-    ///
-    /// The function gsl_matrix_const_view_vector is equivalent to gsl_matrix_view_vector but can be
-    /// used for matrices which are declared const.
+    /// The vector stays mutably borrowed as long as the view is alive.
     #[doc(alias = $name _view_vector)]
     pub fn from_vector(v: &'a mut $vec_name, n1: usize, n2: usize) -> Self {
-        unsafe {
-            Self {
-                mat: sys::[<$name _view_vector>](v.unwrap_unique(), n1, n2),
-                phantom: PhantomData,
-            }
-        }
+        Self::new(unsafe { sys::[<$name _view_vector>](v.unwrap_unique(), n1, n2) }.matrix)
     }
 
     /// These functions return a matrix view of the vector v with a physical number of columns tda
@@ -671,43 +1265,317 @@ impl<'a> [<$rust_name View>]<'a> {
     ///
     /// where the index i runs from 0 to n1-1 and the index j runs from 0 to n2-1.
     ///
-    /// The new matrix is only a view of the vector v. When the view goes out of scope the original
-    /// vector v will continue to exist. The original memory can only be deallocated by freeing the
-    /// original vector. Of course, the original vector should not be deallocated while the view
-    /// is still in use.
-    ///
-    /// The function gsl_matrix_const_view_vector_with_tda is equivalent to
-    /// gsl_matrix_view_vector_with_tda but can be used for matrices which are declared const.
+    /// The vector stays mutably borrowed as long as the view is alive.
     #[doc(alias = $name _view_vector_with_tda)]
     pub fn from_vector_with_tda(v: &'a mut $vec_name, n1: usize, n2: usize, tda: usize) -> Self {
-        unsafe {
-            Self {
-                mat: sys::[<$name _view_vector_with_tda>](v.unwrap_unique(), n1, n2, tda),
-                phantom: PhantomData,
-            }
-        }
+        Self::new(
+            unsafe { sys::[<$name _view_vector_with_tda>](v.unwrap_unique(), n1, n2, tda) }.matrix,
+        )
     }
 
     pub fn matrix<F: FnOnce(Option<&$rust_name>)>(&self, f: F) {
-        let tmp = &self.mat.matrix;
-        let tmp_mat = $rust_name::soft_wrap(tmp as *const _ as usize as *mut _);
-        if tmp_mat.is_ptr_null() {
+        if self.inner.is_empty_view() {
             f(None)
         } else {
-            f(Some(&tmp_mat))
+            f(Some(&self.inner))
         }
     }
 
     pub fn matrix_mut<F: FnOnce(Option<&mut $rust_name>)>(&mut self, f: F) {
-        let tmp = &mut self.mat.matrix;
-        let mut tmp_mat = $rust_name::soft_wrap(tmp as *mut _);
-        if tmp_mat.is_ptr_null() {
+        // A temporary wrapper is handed out so that the view keeps its own one whatever `f` does
+        // with the reference.
+        let mut tmp = $rust_name::soft_wrap(self.inner.mat);
+        if tmp.is_empty_view() {
             f(None)
         } else {
-            f(Some(&mut tmp_mat))
+            f(Some(&mut tmp))
         }
     }
 } // end of impl block
+
+// As for vector views, `DerefMut` is not implemented because a `&mut` to the wrapped matrix would
+// allow to replace it. The methods modifying the elements are forwarded instead.
+impl<'a> [<$rust_name View>]<'a> {
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::set`].")]
+    pub fn set(&mut self, y: usize, x: usize, value: $rust_ty) -> &mut Self {
+        self.inner.set(y, x, value);
+        self
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::set_all`].")]
+    pub fn set_all(&mut self, x: $rust_ty) -> &mut Self {
+        self.inner.set_all(x);
+        self
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::set_zero`].")]
+    pub fn set_zero(&mut self) -> &mut Self {
+        self.inner.set_zero();
+        self
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::set_identity`].")]
+    pub fn set_identity(&mut self) -> &mut Self {
+        self.inner.set_identity();
+        self
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::copy_from`].")]
+    pub fn copy_from(&mut self, other: &$rust_name) -> Result<(), Value> {
+        self.inner.copy_from(other)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::swap`].")]
+    pub fn swap(&mut self, other: &mut $rust_name) -> Result<(), Value> {
+        self.inner.swap(other)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::set_row`].")]
+    pub fn set_row(&mut self, y: usize, v: &$vec_name) -> Result<(), Value> {
+        self.inner.set_row(y, v)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::set_col`].")]
+    pub fn set_col(&mut self, x: usize, v: &$vec_name) -> Result<(), Value> {
+        self.inner.set_col(x, v)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::swap_rows`].")]
+    pub fn swap_rows(&mut self, y1: usize, y2: usize) -> Result<(), Value> {
+        self.inner.swap_rows(y1, y2)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::swap_columns`].")]
+    pub fn swap_columns(&mut self, x1: usize, x2: usize) -> Result<(), Value> {
+        self.inner.swap_columns(x1, x2)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::swap_row_col`].")]
+    pub fn swap_row_col(&mut self, i: usize, j: usize) -> Result<(), Value> {
+        self.inner.swap_row_col(i, j)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::transpose`].")]
+    pub fn transpose(&mut self) -> Result<(), Value> {
+        self.inner.transpose()
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::transpose_inplace`].")]
+    pub fn transpose_inplace(&mut self) -> Result<(), Value> {
+        self.inner.transpose_inplace()
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::add`].")]
+    pub fn add(&mut self, other: &$rust_name) -> Result<(), Value> {
+        self.inner.add(other)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::sub`].")]
+    pub fn sub(&mut self, other: &$rust_name) -> Result<(), Value> {
+        self.inner.sub(other)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::mul_elements`].")]
+    pub fn mul_elements(&mut self, other: &$rust_name) -> Result<(), Value> {
+        self.inner.mul_elements(other)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::div_elements`].")]
+    pub fn div_elements(&mut self, other: &$rust_name) -> Result<(), Value> {
+        self.inner.div_elements(other)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::scale`].")]
+    pub fn scale(&mut self, x: $rust_ty) -> Result<(), Value> {
+        self.inner.scale(x)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::add_constant`].")]
+    pub fn add_constant(&mut self, x: $rust_ty) -> Result<(), Value> {
+        self.inner.add_constant(x)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::add_diagonal`].")]
+    pub fn add_diagonal(&mut self, x: $rust_ty) -> Result<(), Value> {
+        self.inner.add_diagonal(x)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::row`].")]
+    pub fn row(&mut self, i: usize) -> [<$vec_name View>]<'_> {
+        self.inner.row(i)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::column`].")]
+    pub fn column(&mut self, j: usize) -> [<$vec_name View>]<'_> {
+        self.inner.column(j)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::submatrix`].")]
+    pub fn submatrix(
+        &mut self,
+        k1: usize,
+        k2: usize,
+        n1: usize,
+        n2: usize,
+    ) -> [<$rust_name View>]<'_> {
+        self.inner.submatrix(k1, k2, n1, n2)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::rows_mut`].")]
+    pub fn rows_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = &mut [$rust_ty]> + ExactSizeIterator {
+        self.inner.rows_mut()
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::map_inplace`].")]
+    pub fn map_inplace<F: FnMut($rust_ty) -> $rust_ty>(&mut self, f: F) {
+        self.inner.map_inplace(f)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::par_map_inplace`].")]
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "rayon")))]
+    pub fn par_map_inplace<F: Fn($rust_ty) -> $rust_ty + Sync + Send>(&mut self, f: F) {
+        self.inner.par_map_inplace(f)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::read_binary`].")]
+    pub fn read_binary<R: io::Read>(&mut self, r: &mut R) -> io::Result<()> {
+        self.inner.read_binary(r)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::from_reader`].")]
+    pub fn from_reader<R: io::BufRead>(&mut self, r: &mut R) -> io::Result<()> {
+        self.inner.from_reader(r)
+    }
+}
+
+/// A read-only view of (a part of) a matrix, an array or a vector, borrowing it for `'a`.
+///
+/// The view dereferences to a
+#[doc = concat!("[`", stringify!($rust_name), "`]")]
+/// so it can be used like any (immutable) matrix.
+///
+/// If the parameters used to create the view overrun the underlying memory, GSL reports an error
+/// and the view is empty.
+pub struct [<$rust_name ConstView>]<'a> {
+    // Same as for the mutable view. The wrapped matrix is never handed out mutably.
+    inner: $rust_name,
+    phantom: PhantomData<&'a [$rust_ty]>,
+}
+
+impl<'a> Drop for [<$rust_name ConstView>]<'a> {
+    fn drop(&mut self) {
+        unsafe { drop(Box::from_raw(self.inner.mat)) };
+    }
+}
+
+impl<'a> std::ops::Deref for [<$rust_name ConstView>]<'a> {
+    type Target = $rust_name;
+
+    fn deref(&self) -> &$rust_name {
+        &self.inner
+    }
+}
+
+impl<'a> [<$rust_name ConstView>]<'a> {
+    fn new(m: sys::$name) -> Self {
+        Self {
+            inner: $rust_name::soft_wrap(Box::into_raw(Box::new(m))),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "View::from_matrix`]")]
+    /// for a matrix which is only borrowed immutably.
+    #[doc(alias = $name _const_submatrix)]
+    pub fn from_matrix(m: &'a $rust_name, k1: usize, k2: usize, n1: usize, n2: usize) -> Self {
+        Self::new(
+            unsafe { sys::[<$name _const_submatrix>](m.unwrap_shared(), k1, k2, n1, n2) }.matrix,
+        )
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "View::from_array`]")]
+    /// for an array which is only borrowed immutably.
+    ///
+    /// Panics if `n1` or `n2` is zero or if `base` has less than `n1 * n2` elements.
+    #[doc(alias = $name _const_view_array)]
+    pub fn from_array(base: &'a [$rust_ty], n1: usize, n2: usize) -> Self {
+        assert!(n1 > 0 && n2 > 0, "n1 and n2 must be positive");
+        assert!(n1 * n2 <= base.len(), "n1 * n2 cannot be longer than base");
+        Self::new(unsafe { sys::[<$name _const_view_array>](base.as_ptr(), n1, n2) }.matrix)
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "View::from_array_with_tda`]")]
+    /// for an array which is only borrowed immutably.
+    ///
+    /// Panics if `n1` or `n2` is zero, if `n2 > tda` or if `base` is too short to hold the `n1`
+    /// rows.
+    #[doc(alias = $name _const_view_array_with_tda)]
+    pub fn from_array_with_tda(base: &'a [$rust_ty], n1: usize, n2: usize, tda: usize) -> Self {
+        check_tda(base.len(), n1, n2, tda);
+        Self::new(
+            unsafe { sys::[<$name _const_view_array_with_tda>](base.as_ptr(), n1, n2, tda) }.matrix,
+        )
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "ConstView::from_array`]")]
+    /// but returns `Value::Invalid` instead of panicking if `n1` or `n2` is zero and
+    /// `Value::BadLength` if `base` has less than `n1 * n2` elements.
+    pub fn try_from_array(base: &'a [$rust_ty], n1: usize, n2: usize) -> Result<Self, Value> {
+        if n1 == 0 || n2 == 0 {
+            return Err(Value::Invalid);
+        }
+        match n1.checked_mul(n2) {
+            Some(n) if n <= base.len() => Ok(Self::from_array(base, n1, n2)),
+            _ => Err(Value::BadLength),
+        }
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "ConstView::from_array_with_tda`]")]
+    /// but returns `Value::Invalid` instead of panicking if `n1` or `n2` is zero and
+    /// `Value::BadLength` if `n2 > tda` or if `base` is too short to hold the `n1` rows.
+    pub fn try_from_array_with_tda(
+        base: &'a [$rust_ty],
+        n1: usize,
+        n2: usize,
+        tda: usize,
+    ) -> Result<Self, Value> {
+        if n1 == 0 || n2 == 0 {
+            return Err(Value::Invalid);
+        }
+        if fits_tda(base.len(), n1, n2, tda) {
+            Ok(Self::from_array_with_tda(base, n1, n2, tda))
+        } else {
+            Err(Value::BadLength)
+        }
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "View::from_vector`]")]
+    /// for a vector which is only borrowed immutably.
+    #[doc(alias = $name _const_view_vector)]
+    pub fn from_vector(v: &'a $vec_name, n1: usize, n2: usize) -> Self {
+        Self::new(unsafe { sys::[<$name _const_view_vector>](v.unwrap_shared(), n1, n2) }.matrix)
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "View::from_vector_with_tda`]")]
+    /// for a vector which is only borrowed immutably.
+    #[doc(alias = $name _const_view_vector_with_tda)]
+    pub fn from_vector_with_tda(v: &'a $vec_name, n1: usize, n2: usize, tda: usize) -> Self {
+        Self::new(
+            unsafe { sys::[<$name _const_view_vector_with_tda>](v.unwrap_shared(), n1, n2, tda) }
+                .matrix,
+        )
+    }
+} // end of impl block
 } // end of paste! block
 
     ); // end of the gsl_matrix macro
@@ -723,3 +1591,188 @@ gsl_matrix!(
 gsl_matrix!(MatrixF64, gsl_matrix, f64, VectorF64, gsl_vector);
 gsl_matrix!(MatrixI32, gsl_matrix_int, i32, VectorI32, gsl_vector_int);
 gsl_matrix!(MatrixU32, gsl_matrix_uint, u32, VectorU32, gsl_vector_uint);
+// See the vector types for the platform dependent `long` and `char` element types.
+gsl_matrix!(
+    MatrixLong,
+    gsl_matrix_long,
+    c_long,
+    VectorLong,
+    gsl_vector_long
+);
+gsl_matrix!(
+    MatrixULong,
+    gsl_matrix_ulong,
+    c_ulong,
+    VectorULong,
+    gsl_vector_ulong
+);
+gsl_matrix!(
+    MatrixI16,
+    gsl_matrix_short,
+    i16,
+    VectorI16,
+    gsl_vector_short
+);
+gsl_matrix!(
+    MatrixU16,
+    gsl_matrix_ushort,
+    u16,
+    VectorU16,
+    gsl_vector_ushort
+);
+gsl_matrix!(
+    MatrixChar,
+    gsl_matrix_char,
+    c_char,
+    VectorChar,
+    gsl_vector_char
+);
+gsl_matrix!(MatrixU8, gsl_matrix_uchar, u8, VectorU8, gsl_vector_uchar);
+
+// Arithmetic operators: `*` and `/` act element-wise (see `dot` for the matrix product).
+macro_rules! matrix_ops {
+    ($($rust_name:ident, $rust_ty:ty;)*) => {
+        $(
+            elementwise_ops!(
+                $rust_name, dims,
+                Add add, AddAssign add_assign => add;
+                Sub sub, SubAssign sub_assign => sub;
+                Mul mul, MulAssign mul_assign => mul_elements;
+                Div div, DivAssign div_assign => div_elements;
+            );
+            scalar_ops!($rust_name, $rust_ty);
+        )*
+    };
+}
+
+matrix_ops!(
+    MatrixF32, f32; MatrixF64, f64; MatrixI32, i32; MatrixU32, u32;
+    MatrixLong, c_long; MatrixULong, c_ulong; MatrixI16, i16; MatrixU16, u16;
+    MatrixChar, c_char; MatrixU8, u8;
+);
+scalar_ops!(@neg MatrixF32, f32);
+scalar_ops!(@neg MatrixF64, f64);
+scalar_ops!(@neg MatrixI32, i32);
+scalar_ops!(@neg MatrixLong, c_long);
+scalar_ops!(@neg MatrixI16, i16);
+
+macro_rules! matrix_dot {
+    ($rust_name:ident, $rust_ty:ty, $blas:ident) => {
+        paste! {
+            impl $rust_name {
+                /// Returns the matrix product of `self` and `other`. Note that the `*` operator
+                /// multiplies matrices element-wise.
+                ///
+                /// Returns [`Value::BadLength`] if the number of columns of `self` is not the
+                /// number of rows of `other`.
+                #[doc(alias = gsl_blas_ $blas)]
+                pub fn dot(&self, other: &$rust_name) -> Result<$rust_name, Value> {
+                    if self.size2() != other.size1() {
+                        return Err(Value::BadLength);
+                    }
+                    let mut m = $rust_name::new(self.size1(), other.size2())
+                        .ok_or(Value::NoMemory)?;
+                    crate::blas::level3::$blas(
+                        crate::CblasTranspose::NoTranspose,
+                        crate::CblasTranspose::NoTranspose,
+                        1.,
+                        self,
+                        other,
+                        0.,
+                        &mut m,
+                    )?;
+                    Ok(m)
+                }
+            }
+        }
+    };
+}
+
+matrix_dot!(MatrixF32, f32, sgemm);
+matrix_dot!(MatrixF64, f64, dgemm);
+
+permute_ops!(@matrix MatrixF32, matrix_float);
+permute_ops!(@matrix MatrixF64, matrix);
+permute_ops!(@matrix MatrixI32, matrix_int);
+permute_ops!(@matrix MatrixU32, matrix_uint);
+permute_ops!(@matrix MatrixLong, matrix_long);
+permute_ops!(@matrix MatrixULong, matrix_ulong);
+permute_ops!(@matrix MatrixI16, matrix_short);
+permute_ops!(@matrix MatrixU16, matrix_ushort);
+permute_ops!(@matrix MatrixChar, matrix_char);
+permute_ops!(@matrix MatrixU8, matrix_uchar);
+
+macro_rules! matrix_norms {
+    ($rust_name:ident, $rust_ty:ty, $asum:ident, $nrm2:ident) => {
+        impl $rust_name {
+            // Unlike `max`, propagates NaN.
+            fn nan_max(m: $rust_ty, x: $rust_ty) -> $rust_ty {
+                if x > m || x.is_nan() {
+                    x
+                } else {
+                    m
+                }
+            }
+
+            /// Returns the 1-norm of the matrix, that is the maximum absolute column sum. Returns
+            /// NaN if the matrix contains a NaN.
+            pub fn norm1(&self) -> $rust_ty {
+                (0..self.size2())
+                    .map(|j| crate::cblas::level1::$asum(&self.const_column(j)))
+                    .fold(0., Self::nan_max)
+            }
+
+            /// Returns the infinity norm of the matrix, that is the maximum absolute row sum.
+            /// Returns NaN if the matrix contains a NaN.
+            pub fn norm_inf(&self) -> $rust_ty {
+                self.rows()
+                    .map(|row| crate::cblas::level1::$asum(row))
+                    .fold(0., Self::nan_max)
+            }
+
+            /// Returns the Frobenius norm of the matrix, that is the square root of the sum of the
+            /// squares of its elements (computed without undue overflow or underflow).
+            pub fn norm_frobenius(&self) -> $rust_ty {
+                self.rows()
+                    .map(|row| crate::cblas::level1::$nrm2(row))
+                    .fold(0., <$rust_ty>::hypot)
+            }
+        }
+    };
+}
+
+matrix_norms!(MatrixF32, f32, sasum, snrm2);
+matrix_norms!(MatrixF64, f64, dasum, dnrm2);
+
+// `RelativeEq` only makes sense for floating point elements.
+#[cfg(feature = "approx")]
+macro_rules! impl_relative_eq {
+    ($rust_name:ident, $rust_ty:ty) => {
+        #[cfg(feature = "approx")]
+        impl approx::RelativeEq for $rust_name {
+            fn default_max_relative() -> Self::Epsilon {
+                <$rust_ty as approx::RelativeEq>::default_max_relative()
+            }
+
+            fn relative_eq(
+                &self,
+                other: &$rust_name,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                self.size1() == other.size1()
+                    && self.size2() == other.size2()
+                    && self
+                        .rows()
+                        .flatten()
+                        .zip(other.rows().flatten())
+                        .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "approx")]
+impl_relative_eq!(MatrixF32, f32);
+#[cfg(feature = "approx")]
+impl_relative_eq!(MatrixF64, f64);
@@ -3,9 +3,11 @@
 //
 
 use crate::ffi::FFI;
+use crate::types::vector::{fmt_matrix, read_value};
 use crate::Value;
 use paste::paste;
 use std::fmt::{self, Debug, Formatter};
+use std::io;
 
 macro_rules! gsl_matrix_complex {
     ($rust_name:ident, $name:ident, $complex:ident, $complex_c:ident) => (
@@ -203,6 +205,13 @@ impl $rust_name {
         }
     }
 
+    /// Returns the transpose of the matrix as a new matrix. Same as
+    #[doc = concat!("[`", stringify!($rust_name), "::transpose_memcpy`].")]
+    #[must_use = "use `transpose` to transpose the matrix in place"]
+    pub fn transposed(&self) -> Result<$rust_name, Value> {
+        self.transpose_memcpy()
+    }
+
     /// This function replaces the matrix m by its transpose by copying the elements of the matrix
     /// in-place. The matrix must be square for this operation to be possible.
     #[doc(alias = $name _transpose)]
@@ -211,6 +220,41 @@ impl $rust_name {
         result_handler!(ret, ())
     }
 
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "::transpose`].")]
+    pub fn transpose_inplace(&mut self) -> Result<(), Value> {
+        self.transpose()
+    }
+
+    /// This function replaces each element of the matrix by its complex conjugate.
+    pub fn conjugate(&mut self) {
+        for z in self.rows_mut().flatten() {
+            z.dat[1] = -z.dat[1];
+        }
+    }
+
+    /// This function returns the conjugate transpose (or adjoint) of the matrix by copying the
+    /// elements into a new matrix.
+    #[doc(alias = $name _conjtrans_memcpy)]
+    pub fn adjoint(&self) -> Result<$rust_name, Value> {
+        #[cfg(feature = "v2_7")]
+        {
+            // Wrapped first so that it is freed if the copy fails.
+            let mut dest = Self::new(self.size2(), self.size1()).ok_or(Value::NoMemory)?;
+            let ret = unsafe {
+                sys::[<$name _conjtrans_memcpy>](dest.unwrap_unique(), self.unwrap_shared())
+            };
+
+            result_handler!(ret, dest)
+        }
+        #[cfg(not(feature = "v2_7"))]
+        {
+            let mut m = self.transpose_memcpy()?;
+            m.conjugate();
+            Ok(m)
+        }
+    }
+
     /// This function adds the elements of the other matrix to the elements of the `self` matrix.
     /// The result self(i,j) <- self(i,j) + other(i,j) is stored in `self` and other remains
     /// unchanged. The two matrices must have the same dimensions.
@@ -275,34 +319,57 @@ impl $rust_name {
         result_handler!(ret, ())
     }
 
-    /// This function returns true if all the elements of the self matrix are stricly zero.
+    /// Returns `true` if all the elements of the matrix are zero.
     #[doc(alias = $name _isnull)]
-    pub fn is_null(&self) -> bool {
+    pub fn is_zero(&self) -> bool {
         unsafe { sys::[<$name _isnull>](self.unwrap_shared()) == 1 }
     }
 
-    /// This function returns true if all the elements of the self matrix are stricly positive.
+    /// Returns `true` if all the elements of the matrix are strictly positive.
     #[doc(alias = $name _ispos)]
-    pub fn is_pos(&self) -> bool {
+    pub fn all_positive(&self) -> bool {
         unsafe { sys::[<$name _ispos>](self.unwrap_shared()) == 1 }
     }
 
-    /// This function returns true if all the elements of the self matrix are stricly negative.
+    /// Returns `true` if all the elements of the matrix are strictly negative.
     #[doc(alias = $name _isneg)]
-    pub fn is_neg(&self) -> bool {
+    pub fn all_negative(&self) -> bool {
         unsafe { sys::[<$name _isneg>](self.unwrap_shared()) == 1 }
     }
 
-    /// This function returns true if all the elements of the self matrix are stricly non-negative.
+    /// Returns `true` if all the elements of the matrix are non-negative.
     #[doc(alias = $name _isnonneg)]
-    pub fn is_non_neg(&self) -> bool {
+    pub fn all_non_negative(&self) -> bool {
         unsafe { sys::[<$name _isnonneg>](self.unwrap_shared()) == 1 }
     }
 
-    /// This function returns true if all elements of the two matrix are equal.
+    #[deprecated(since = "8.0.0", note = "Please use `is_zero` instead")]
+    pub fn is_null(&self) -> bool {
+        self.is_zero()
+    }
+
+    #[deprecated(since = "8.0.0", note = "Please use `all_positive` instead")]
+    pub fn is_pos(&self) -> bool {
+        self.all_positive()
+    }
+
+    #[deprecated(since = "8.0.0", note = "Please use `all_negative` instead")]
+    pub fn is_neg(&self) -> bool {
+        self.all_negative()
+    }
+
+    #[deprecated(since = "8.0.0", note = "Please use `all_non_negative` instead")]
+    pub fn is_non_neg(&self) -> bool {
+        self.all_non_negative()
+    }
+
+    /// Returns `true` if the two matrices have the same dimensions and all their elements are equal.
+    /// This is the same as `==`.
     #[doc(alias = $name _equal)]
     pub fn equal(&self, other: &$rust_name) -> bool {
-        unsafe { sys::[<$name _equal>](self.unwrap_shared(), other.unwrap_shared()) == 1 }
+        // GSL calls the error handler when the dimensions differ.
+        self.size1() == other.size1() && self.size2() == other.size2()
+            && unsafe { sys::[<$name _equal>](self.unwrap_shared(), other.unwrap_shared()) == 1 }
     }
 
     #[doc(alias = $name _row)]
@@ -356,6 +423,227 @@ impl $rust_name {
         }
     }
 
+    /// Returns an iterator over the rows of the matrix, each row being a slice of `size2`
+    /// elements.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[$complex]> + ExactSizeIterator {
+        let (size2, tda) = self.row_layout();
+        self.tda_slice().chunks(tda).map(move |row| &row[..size2])
+    }
+
+    /// Returns an iterator over the rows of the matrix allowing to modify them.
+    pub fn rows_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = &mut [$complex]> + ExactSizeIterator {
+        let (size2, tda) = self.row_layout();
+        self.tda_slice_mut().chunks_mut(tda).map(move |row| &mut row[..size2])
+    }
+
+    /// Returns an iterator over the columns of the matrix, each column being itself an iterator
+    /// over its `size1` elements. The iterator is empty if the matrix has no rows.
+    pub fn columns(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = std::iter::StepBy<std::slice::Iter<'_, $complex>>>
+           + ExactSizeIterator {
+        let (size2, tda) = self.row_layout();
+        let data = self.tda_slice();
+        let ncols = if data.is_empty() { 0 } else { size2 };
+        (0..ncols).map(move |j| data[j..].iter().step_by(tda))
+    }
+
+    fn dims(&self) -> (usize, usize) {
+        (self.size1(), self.size2())
+    }
+
+    fn row_layout(&self) -> (usize, usize) {
+        if self.unwrap_shared().is_null() {
+            (0, 1)
+        } else {
+            unsafe { ((*self.unwrap_shared()).size2, (*self.unwrap_shared()).tda.max(1)) }
+        }
+    }
+
+    /// Returns a new matrix whose elements are the images of the elements of `self` by `f`.
+    ///
+    /// Returns `None` if the allocation of the new matrix fails.
+    pub fn map<F: FnMut($complex) -> $complex>(&self, mut f: F) -> Option<$rust_name> {
+        let mut m = $rust_name::new(self.size1(), self.size2())?;
+        for (y, &x) in m.rows_mut().flatten().zip(self.rows().flatten()) {
+            *y = f(x);
+        }
+        Some(m)
+    }
+
+    /// Replaces each element `x` of the matrix by `f(x)`.
+    pub fn map_inplace<F: FnMut($complex) -> $complex>(&mut self, mut f: F) {
+        for x in self.rows_mut().flatten() {
+            *x = f(*x);
+        }
+    }
+
+    /// Returns a new matrix whose elements are `f(x, y)` where `x` and `y` are the elements of
+    /// `self` and `other` at the same position.
+    ///
+    /// Returns [`Value::BadLength`] if the matrices do not have the same dimensions.
+    pub fn zip_map<F: FnMut($complex, $complex) -> $complex>(
+        &self,
+        other: &$rust_name,
+        mut f: F,
+    ) -> Result<$rust_name, Value> {
+        if self.size1() != other.size1() || self.size2() != other.size2() {
+            return Err(Value::BadLength);
+        }
+        let mut m = $rust_name::new(self.size1(), self.size2()).ok_or(Value::NoMemory)?;
+        let xy = self.rows().flatten().zip(other.rows().flatten());
+        for (z, (&x, &y)) in m.rows_mut().flatten().zip(xy) {
+            *z = f(x, y);
+        }
+        Ok(m)
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "::map`]")]
+    /// but the rows are processed in parallel.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "rayon")))]
+    pub fn par_map<F: Fn($complex) -> $complex + Sync + Send>(&self, f: F) -> Option<$rust_name> {
+        use rayon::prelude::*;
+
+        let mut m = $rust_name::new(self.size1(), self.size2())?;
+        m.par_rows_mut()
+            .zip(self.par_rows())
+            .for_each(|(ys, xs)| ys.iter_mut().zip(xs).for_each(|(y, &x)| *y = f(x)));
+        Some(m)
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "::map_inplace`]")]
+    /// but the rows are processed in parallel.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "rayon")))]
+    pub fn par_map_inplace<F: Fn($complex) -> $complex + Sync + Send>(&mut self, f: F) {
+        use rayon::prelude::*;
+
+        self.par_rows_mut()
+            .for_each(|xs| xs.iter_mut().for_each(|x| *x = f(*x)));
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "::zip_map`]")]
+    /// but the rows are processed in parallel.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "rayon")))]
+    pub fn par_zip_map<F: Fn($complex, $complex) -> $complex + Sync + Send>(
+        &self,
+        other: &$rust_name,
+        f: F,
+    ) -> Result<$rust_name, Value> {
+        use rayon::prelude::*;
+
+        if self.size1() != other.size1() || self.size2() != other.size2() {
+            return Err(Value::BadLength);
+        }
+        let mut m = $rust_name::new(self.size1(), self.size2()).ok_or(Value::NoMemory)?;
+        m.par_rows_mut()
+            .zip(self.par_rows().zip(other.par_rows()))
+            .for_each(|(zs, (xs, ys))| {
+                for ((z, &x), &y) in zs.iter_mut().zip(xs).zip(ys) {
+                    *z = f(x, y);
+                }
+            });
+        Ok(m)
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_rows(&self) -> impl rayon::iter::IndexedParallelIterator<Item = &[$complex]> {
+        use rayon::prelude::*;
+
+        let (size2, tda) = self.row_layout();
+        self.tda_slice().par_chunks(tda).map(move |row| &row[..size2])
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_rows_mut(
+        &mut self,
+    ) -> impl rayon::iter::IndexedParallelIterator<Item = &mut [$complex]> {
+        use rayon::prelude::*;
+
+        let (size2, tda) = self.row_layout();
+        self.tda_slice_mut().par_chunks_mut(tda).map(move |row| &mut row[..size2])
+    }
+
+    /// This function writes the elements of the matrix to `w` in binary format, row by row, using
+    /// the native representation of the numbers (the format of `gsl_matrix_fwrite`). Since the
+    /// data is written in the native binary format it may not be portable between different
+    /// architectures.
+    pub fn write_binary<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        for x in self.rows().flatten() {
+            x.write_ne(w)?;
+        }
+        Ok(())
+    }
+
+    /// This function reads into the matrix the binary data written by
+    #[doc = concat!("[`", stringify!($rust_name), "::write_binary`].")]
+    /// The matrix must be preallocated with the correct dimensions since the format contains no
+    /// size information.
+    pub fn read_binary<R: io::Read>(&mut self, r: &mut R) -> io::Result<()> {
+        for x in self.rows_mut().flatten() {
+            *x = $complex::read_ne(r)?;
+        }
+        Ok(())
+    }
+
+    /// This function writes the elements of the matrix to `w`, one per line in row-major order
+    /// (the format of `gsl_matrix_fprintf`).
+    pub fn to_writer<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        for x in self.rows().flatten() {
+            writeln!(w, "{} {}", x.dat[0], x.dat[1])?;
+        }
+        Ok(())
+    }
+
+    /// This function reads whitespace separated values from `r` into the matrix (the format of
+    /// `gsl_matrix_fscanf`). The matrix must be preallocated with the correct dimensions since
+    /// the format contains no size information.
+    pub fn from_reader<R: io::BufRead>(&mut self, r: &mut R) -> io::Result<()> {
+        for x in self.rows_mut().flatten() {
+            x.dat[0] = read_value(r)?;
+            x.dat[1] = read_value(r)?;
+        }
+        Ok(())
+    }
+
+    // The memory spanned by the matrix elements, `(size1 - 1) * tda + size2` values.
+    fn tda_slice(&self) -> &[$complex] {
+        let (size1, size2) = (self.size1(), self.size2());
+        if size1 == 0 || size2 == 0 {
+            return &[];
+        }
+        unsafe {
+            let m = &*self.unwrap_shared();
+            if m.data.is_null() {
+                &[]
+            } else {
+                std::slice::from_raw_parts(m.data as *const $complex, (size1 - 1) * m.tda + size2)
+            }
+        }
+    }
+
+    fn tda_slice_mut(&mut self) -> &mut [$complex] {
+        let (size1, size2) = (self.size1(), self.size2());
+        if size1 == 0 || size2 == 0 {
+            return &mut [];
+        }
+        unsafe {
+            let m = &*self.unwrap_unique();
+            if m.data.is_null() {
+                &mut []
+            } else {
+                std::slice::from_raw_parts_mut(m.data as *mut $complex, (size1 - 1) * m.tda + size2)
+            }
+        }
+    }
+
     pub fn clone(&self) -> Option<Self> {
         if self.unwrap_shared().is_null() {
             None
@@ -373,27 +661,159 @@ impl $rust_name {
     }
 }
 
+impl std::ops::Index<(usize, usize)> for $rust_name {
+    type Output = $complex;
+
+    fn index(&self, (i, j): (usize, usize)) -> &$complex {
+        let (size1, size2) = (self.size1(), self.size2());
+        assert!(
+            i < size1 && j < size2,
+            "index out of bounds: the size is ({}, {}) but the index is ({}, {})",
+            size1, size2, i, j
+        );
+        unsafe {
+            let m = &*self.unwrap_shared();
+            &*(m.data.add(2 * (i * m.tda + j)) as *const $complex)
+        }
+    }
+}
+
+impl std::ops::IndexMut<(usize, usize)> for $rust_name {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut $complex {
+        let (size1, size2) = (self.size1(), self.size2());
+        assert!(
+            i < size1 && j < size2,
+            "index out of bounds: the size is ({}, {}) but the index is ({}, {})",
+            size1, size2, i, j
+        );
+        unsafe {
+            let m = &*self.unwrap_unique();
+            &mut *(m.data.add(2 * (i * m.tda + j)) as *mut $complex)
+        }
+    }
+}
+
+/// Two matrices are equal when they have the same dimensions and the same elements, whatever
+/// their `tda`.
+impl PartialEq for $rust_name {
+    fn eq(&self, other: &$rust_name) -> bool {
+        self.size1() == other.size1()
+            && self.size2() == other.size2()
+            && self.rows().eq(other.rows())
+    }
+}
+
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for $rust_name {
+    type Epsilon = <$complex as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        <$complex as approx::AbsDiffEq>::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &$rust_name, epsilon: Self::Epsilon) -> bool {
+        self.size1() == other.size1()
+            && self.size2() == other.size2()
+            && self
+                .rows()
+                .flatten()
+                .zip(other.rows().flatten())
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for $rust_name {
+    fn default_max_relative() -> Self::Epsilon {
+        <$complex as approx::RelativeEq>::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &$rust_name,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.size1() == other.size1()
+            && self.size2() == other.size2()
+            && self
+                .rows()
+                .flatten()
+                .zip(other.rows().flatten())
+                .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+/// The matrix is serialized as a structure holding its dimensions `size1` and `size2` and the
+/// sequence of its elements in row-major order.
+#[cfg(feature = "serde")]
+impl serde::Serialize for $rust_name {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let data = self.rows().flatten().copied().collect::<Vec<_>>();
+        let mut s = serializer.serialize_struct(stringify!($rust_name), 3)?;
+        s.serialize_field("size1", &self.size1())?;
+        s.serialize_field("size2", &self.size2())?;
+        s.serialize_field("data", &data)?;
+        s.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for $rust_name {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<$rust_name, D::Error> {
+        use serde::de::Error;
+
+        #[derive(serde::Deserialize)]
+        struct Raw {
+            size1: usize,
+            size2: usize,
+            data: Vec<$complex>,
+        }
+
+        let Raw { size1, size2, data } = Raw::deserialize(deserializer)?;
+        if size1 == 0 || size2 == 0 {
+            return Err(D::Error::custom("the dimensions of the matrix must be positive"));
+        }
+        if size1.checked_mul(size2) != Some(data.len()) {
+            return Err(D::Error::invalid_length(data.len(), &"size1 * size2 elements"));
+        }
+        let mut m = $rust_name::new(size1, size2)
+            .ok_or_else(|| D::Error::custom("cannot allocate the matrix"))?;
+        for (row, values) in m.rows_mut().zip(data.chunks(size2)) {
+            for (x, &v) in row.iter_mut().zip(values) {
+                *x = v;
+            }
+        }
+        Ok(m)
+    }
+}
+
 impl Debug for $rust_name {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         if self.unwrap_shared().is_null() {
             write!(f, "<null>")
         } else {
-            let size1 = self.size1();
-            let size2 = self.size2();
-            for y in 0..size1 {
-                write!(f, "[")?;
-                for x in 0..size2 {
-                    if x < size2 - 1 {
-                        write!(f, "{:?}, ", self.get(y, x))?;
-                    } else {
-                        write!(f, "{:?}", self.get(y, x))?;
-                    }
-                }
-                if y < size1 - 1 {
-                    write!(f, "]\n")?;
-                }
-            }
-            write!(f, "]")
+            let (size1, size2) = (self.size1(), self.size2());
+            write!(f, "{} {{ size: ({}, {}), data: ", stringify!($rust_name), size1, size2)?;
+            fmt_matrix(f, size1, size2, true, |f, i, j| Debug::fmt(&self.get(i, j), f))?;
+            write!(f, " }}")
+        }
+    }
+}
+
+/// The elements are displayed with the width and precision given to the formatter, one row per
+/// line. Large matrices are truncated, except with the alternate flag `{:#}`.
+impl fmt::Display for $rust_name {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.unwrap_shared().is_null() {
+            write!(f, "<null>")
+        } else {
+            let (size1, size2) = (self.size1(), self.size2());
+            fmt_matrix(f, size1, size2, !f.alternate(), |f, i, j| {
+                fmt::Display::fmt(&self.get(i, j), f)
+            })
         }
     }
 }
@@ -414,3 +834,23 @@ gsl_matrix_complex!(
     ComplexF32,
     gsl_vector_complex_float
 );
+
+// Element-wise arithmetic operators.
+macro_rules! matrix_complex_ops {
+    ($($rust_name:ident),*) => {
+        $(
+            elementwise_ops!(
+                $rust_name, dims,
+                Add add, AddAssign add_assign => add;
+                Sub sub, SubAssign sub_assign => sub;
+                Mul mul, MulAssign mul_assign => mul_elements;
+                Div div, DivAssign div_assign => div_elements;
+            );
+        )*
+    };
+}
+
+matrix_complex_ops!(MatrixComplexF64, MatrixComplexF32);
+
+permute_ops!(@matrix MatrixComplexF32, matrix_complex_float);
+permute_ops!(@matrix MatrixComplexF64, matrix_complex);
@@ -144,8 +144,10 @@ impl<'a> Minimizer<'a> {
         x_lower: f64,
         x_upper: f64,
     ) -> Result<(), Value> {
-        self.inner_call = wrap_callback!(f, F + 'a);
-        self.inner_closure = Some(Box::new(f));
+        // GSL keeps a pointer to the closure, so it must not move once boxed.
+        let f = Box::new(f);
+        self.inner_call = wrap_callback!(*f, F + 'a);
+        self.inner_closure = Some(f);
 
         let ret = unsafe {
             sys::gsl_min_fminimizer_set(
@@ -172,8 +174,10 @@ impl<'a> Minimizer<'a> {
         x_upper: f64,
         f_upper: f64,
     ) -> Result<(), Value> {
-        self.inner_call = wrap_callback!(f, F + 'a);
-        self.inner_closure = Some(Box::new(f));
+        // GSL keeps a pointer to the closure, so it must not move once boxed.
+        let f = Box::new(f);
+        self.inner_call = wrap_callback!(*f, F + 'a);
+        self.inner_closure = Some(f);
 
         let ret = unsafe {
             sys::gsl_min_fminimizer_set_with_values(
@@ -259,6 +263,105 @@ impl<'a> Minimizer<'a> {
         let ret = unsafe { sys::gsl_min_fminimizer_iterate(self.unwrap_unique()) };
         result_handler!(ret, ())
     }
+
+    /// Appends the current state of the minimizer to `trace`: the best estimate of the minimum,
+    /// the value of the function there and, as step size, the width of the current interval.
+    /// The gradient norm is not available and is recorded as NaN.
+    pub fn record(&self, trace: &mut MinimizerTrace) {
+        trace.push(
+            [self.x_minimum()],
+            self.f_minimum(),
+            f64::NAN,
+            self.x_upper() - self.x_lower(),
+        );
+    }
+}
+
+/// A reusable buffer holding the history of a minimization, one entry per iteration, for later
+/// plotting or diagnosis.
+///
+/// Entries are usually added by the `record` method of the minimizers, such as
+/// [`Minimizer::record`], after each iteration. Calling [`clear`](Self::clear) empties the
+/// buffer while keeping its memory for the next run.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MinimizerTrace {
+    dim: usize,
+    x: Vec<f64>,
+    f: Vec<f64>,
+    gradient_norm: Vec<f64>,
+    step_size: Vec<f64>,
+}
+
+impl MinimizerTrace {
+    /// Creates an empty trace.
+    pub fn new() -> MinimizerTrace {
+        MinimizerTrace::default()
+    }
+
+    /// Removes all the entries, keeping the allocated memory.
+    pub fn clear(&mut self) {
+        self.dim = 0;
+        self.x.clear();
+        self.f.clear();
+        self.gradient_norm.clear();
+        self.step_size.clear();
+    }
+
+    /// Appends an entry with location `x`, function value `f`, gradient norm `gradient_norm`
+    /// (NaN if not available) and step size `step_size`.
+    ///
+    /// # Panics
+    ///
+    /// If `x` does not have the same number of elements as the previous entries.
+    pub fn push<X: IntoIterator<Item = f64>>(
+        &mut self,
+        x: X,
+        f: f64,
+        gradient_norm: f64,
+        step_size: f64,
+    ) {
+        let start = self.x.len();
+        self.x.extend(x);
+        let dim = self.x.len() - start;
+        if self.f.is_empty() {
+            self.dim = dim;
+        } else {
+            assert_eq!(dim, self.dim, "the dimension of x changed");
+        }
+        self.f.push(f);
+        self.gradient_norm.push(gradient_norm);
+        self.step_size.push(step_size);
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.f.len()
+    }
+
+    /// Returns `true` if there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.f.is_empty()
+    }
+
+    /// Returns the location recorded in entry `i`.
+    pub fn x(&self, i: usize) -> &[f64] {
+        &self.x[i * self.dim..(i + 1) * self.dim]
+    }
+
+    /// Returns the recorded function values.
+    pub fn f(&self) -> &[f64] {
+        &self.f
+    }
+
+    /// Returns the recorded gradient norms.
+    pub fn gradient_norm(&self) -> &[f64] {
+        &self.gradient_norm
+    }
+
+    /// Returns the recorded step sizes.
+    pub fn step_size(&self) -> &[f64] {
+        &self.step_size
+    }
 }
 
 ffi_wrapper!(MinimizerType, *const sys::gsl_min_fminimizer_type);
@@ -16,6 +16,8 @@ pub use self::eigen_symmetric_workspace::{
 };
 pub use self::fast_fourier_transforms::{
     FftComplexF32WaveTable, FftComplexF32Workspace, FftComplexF64WaveTable, FftComplexF64Workspace,
+    FftHalfComplexF32WaveTable, FftHalfComplexF64WaveTable, FftPlan, FftRealF32WaveTable,
+    FftRealF32Workspace, FftRealF64WaveTable, FftRealF64Workspace,
 };
 #[cfg(feature = "v2_5")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
@@ -27,18 +29,34 @@ pub use self::integration::{
     CquadWorkspace, GLFixedTable, IntegrationFixedType, IntegrationFixedWorkspace,
     IntegrationQawoTable, IntegrationQawsTable, IntegrationWorkspace,
 };
-pub use self::interpolation::{Interp, InterpAccel, InterpType, Spline};
+pub use self::interpolation::{Interp, Interp2dType, InterpAccel, InterpType, Spline, Spline2D};
 pub use self::mathieu::MathieuWorkspace;
 pub use self::matrix::{
-    MatrixF32, MatrixF32View, MatrixF64, MatrixF64View, MatrixI32, MatrixI32View, MatrixU32,
-    MatrixU32View,
+    MatrixChar, MatrixCharConstView, MatrixCharView, MatrixF32, MatrixF32ConstView, MatrixF32View,
+    MatrixF64, MatrixF64ConstView, MatrixF64View, MatrixI16, MatrixI16ConstView, MatrixI16View,
+    MatrixI32, MatrixI32ConstView, MatrixI32View, MatrixLong, MatrixLongConstView, MatrixLongView,
+    MatrixU16, MatrixU16ConstView, MatrixU16View, MatrixU32, MatrixU32ConstView, MatrixU32View,
+    MatrixU8, MatrixU8ConstView, MatrixU8View, MatrixULong, MatrixULongConstView, MatrixULongView,
 };
 pub use self::matrix_complex::{MatrixComplexF32, MatrixComplexF64};
-pub use self::minimizer::{Minimizer, MinimizerType};
+pub use self::minimizer::{Minimizer, MinimizerTrace, MinimizerType};
 pub use self::monte_carlo::{
-    MiserMonteCarlo, MiserParams, PlainMonteCarlo, VegasMonteCarlo, VegasParams,
+    Integrand, MiserMonteCarlo, MiserParams, MonteCarloIntegrator, PlainMonteCarlo, VegasIteration,
+    VegasMonteCarlo, VegasParams,
 };
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+pub use self::movstat::MovstatWorkspace;
+#[cfg(feature = "v2_3")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
+pub use self::multifit_linear::MultifitLinearGcv;
 pub use self::multifit_linear::MultifitLinearWorkspace;
+#[cfg(feature = "v2_2")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+pub use self::multifit_nlinear::{
+    MultiFitNlinearFdf, MultiFitNlinearParameters, MultiFitNlinearScale, MultiFitNlinearSolver,
+    MultiFitNlinearTrs, MultiFitNlinearType, MultiFitNlinearWorkspace,
+};
 pub use self::multifit_solver::{
     MultiFitFdfSolver, MultiFitFdfSolverType, MultiFitFunction, MultiFitFunctionFdf,
 };
@@ -46,28 +64,36 @@ pub use self::multifit_solver::{
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
 pub use self::multilarge_linear::{MultilargeLinearType, MultilargeLinearWorkspace};
 pub use self::multiset::MultiSet;
+#[cfg(feature = "v2_2")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+pub use self::multivariate_gaussian::MultivariateGaussian;
 pub use self::n_tuples::{ReadNTuples, WriteNTuples};
 pub use self::ordinary_differential_equations::{
     ODEiv2Control, ODEiv2Driver, ODEiv2Evolve, ODEiv2Step, ODEiv2StepType, ODEiv2System,
 };
 pub use self::permutation::Permutation;
-pub use self::polynomial::PolyComplexWorkspace;
+pub use self::polynomial::{Poly, PolyComplexWorkspace};
 pub use self::qrng::{QRng, QRngType};
 pub use self::ran_discrete::RanDiscrete;
 pub use self::result::{Result, ResultE10};
 pub use self::rng::{Rng, RngType};
 pub use self::roots::{RootFSolver, RootFSolverType, RootFdfSolver, RootFdfSolverType};
-pub use self::rstat::{RStatQuantileWorkspace, RStatWorkspace};
+pub use self::rstat::{
+    RStatCovariance, RStatMultiQuantileWorkspace, RStatQuantileWorkspace, RStatWorkspace,
+};
 pub use self::series_acceleration::{LevinUTruncWorkspace, LevinUWorkspace};
 pub use self::siman::{SimAnnealing, SimAnnealingParams};
 pub use self::vector::{
-    VectorF32, VectorF32View, VectorF64, VectorF64View, VectorI32, VectorI32View, VectorU32,
-    VectorU32View,
+    VectorChar, VectorCharConstView, VectorCharView, VectorF32, VectorF32ConstView, VectorF32View,
+    VectorF64, VectorF64ConstView, VectorF64View, VectorI16, VectorI16ConstView, VectorI16View,
+    VectorI32, VectorI32ConstView, VectorI32View, VectorLong, VectorLongConstView, VectorLongView,
+    VectorU16, VectorU16ConstView, VectorU16View, VectorU32, VectorU32ConstView, VectorU32View,
+    VectorU8, VectorU8ConstView, VectorU8View, VectorULong, VectorULongConstView, VectorULongView,
 };
 pub use self::vector_complex::{
     VectorComplexF32, VectorComplexF32View, VectorComplexF64, VectorComplexF64View,
 };
-pub use self::wavelet_transforms::{Wavelet, WaveletType, WaveletWorkspace};
+pub use self::wavelet_transforms::{Wavelet, Wavelet2D, WaveletType, WaveletWorkspace};
 
 pub mod basis_spline;
 pub mod chebyshev;
@@ -76,6 +102,7 @@ pub mod complex;
 pub mod discrete_hankel;
 pub mod eigen_symmetric_workspace;
 pub mod fast_fourier_transforms;
+mod fdf;
 #[cfg(feature = "v2_5")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
 pub mod filter;
@@ -87,7 +114,13 @@ pub mod matrix;
 pub mod matrix_complex;
 pub mod minimizer;
 pub mod monte_carlo;
+#[cfg(feature = "v2_5")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_5")))]
+pub mod movstat;
 pub mod multifit_linear;
+#[cfg(feature = "v2_2")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+pub mod multifit_nlinear;
 pub mod multifit_solver;
 #[cfg(feature = "v2_1")]
 #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_1")))]
@@ -95,6 +128,9 @@ pub mod multilarge_linear;
 pub mod multimin;
 pub mod multiroot;
 pub mod multiset;
+#[cfg(feature = "v2_2")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+pub mod multivariate_gaussian;
 pub mod n_tuples;
 pub mod ordinary_differential_equations;
 pub mod permutation;
@@ -80,6 +80,67 @@ use std::mem::transmute;
 use std::os::raw::c_void;
 use std::slice;
 
+/// A function to integrate with the Monte Carlo algorithms. It is implemented by all the
+/// closures `Fn(&[f64]) -> f64`.
+pub trait Integrand {
+    /// Returns the value of the function at the point `x`.
+    fn eval(&self, x: &[f64]) -> f64;
+}
+
+impl<F: Fn(&[f64]) -> f64 + ?Sized> Integrand for F {
+    fn eval(&self, x: &[f64]) -> f64 {
+        self(x)
+    }
+}
+
+/// Interface shared by [`PlainMonteCarlo`], [`MiserMonteCarlo`] and [`VegasMonteCarlo`], so
+/// that code can be written independently of the algorithm:
+///
+/// ```
+/// use rgsl::{MonteCarloIntegrator, MiserMonteCarlo, Rng, RngType};
+///
+/// let mut r = Rng::new(RngType::default()).unwrap();
+/// let mut s = MiserMonteCarlo::new(2).unwrap();
+/// let f = |x: &[f64]| x[0] * x[1];
+/// let (res, err) = s.integrate_ranges(&f, &[(0., 1.), (0., 2.)], 100_000, &mut r).unwrap();
+/// assert!((res - 1.).abs() < 5. * err);
+/// ```
+pub trait MonteCarloIntegrator {
+    /// Integrates `f` over the hypercube whose side along the i-th axis is `ranges[i]`, using
+    /// `calls` function calls and the random number generator `r`. The number of ranges must be
+    /// the dimension the integrator was created with.
+    ///
+    /// Returns `(result, abserr)`.
+    fn integrate_ranges<I: Integrand + ?Sized>(
+        &mut self,
+        f: &I,
+        ranges: &[(f64, f64)],
+        calls: usize,
+        r: &mut crate::Rng,
+    ) -> Result<(f64, f64), Value>;
+}
+
+macro_rules! impl_monte_carlo_integrator {
+    ($name:ident) => {
+        impl MonteCarloIntegrator for $name {
+            fn integrate_ranges<I: Integrand + ?Sized>(
+                &mut self,
+                f: &I,
+                ranges: &[(f64, f64)],
+                calls: usize,
+                r: &mut crate::Rng,
+            ) -> Result<(f64, f64), Value> {
+                let (xl, xu): (Vec<f64>, Vec<f64>) = ranges.iter().copied().unzip();
+                self.integrate(|x| f.eval(x), &xl, &xu, calls, r)
+            }
+        }
+    };
+}
+
+impl_monte_carlo_integrator!(PlainMonteCarlo);
+impl_monte_carlo_integrator!(MiserMonteCarlo);
+impl_monte_carlo_integrator!(VegasMonteCarlo);
+
 ffi_wrapper!(PlainMonteCarlo, *mut sys::gsl_monte_plain_state, gsl_monte_plain_free,
 "The plain Monte Carlo algorithm samples points randomly from the integration region to estimate
 the integral and its error. Using this algorithm the estimate of the integral E(f; N) for N
@@ -294,10 +355,82 @@ impl MiserMonteCarlo {
     }
 }
 
+/// Parameters of the MISER algorithm, see [`MiserMonteCarlo::set_params`].
+///
+/// The parameters are built from the defaults of GSL with [`MiserParams::new`] and modified by
+/// chaining the setters. They can also be read and written through the fields of the inner
+/// `gsl_monte_miser_params` structure.
+///
+/// ```
+/// use rgsl::{MiserMonteCarlo, MiserParams};
+///
+/// let mut s = MiserMonteCarlo::new(3).unwrap();
+/// s.set_params(&MiserParams::new(3).dither(0.1).alpha(1.5));
+/// ```
 #[derive(Debug, Clone)]
 #[repr(C)]
 pub struct MiserParams(pub sys::gsl_monte_miser_params);
 
+impl MiserParams {
+    /// Returns the default parameters for integrals in `dim` dimensions, which are those of a
+    /// newly allocated [`MiserMonteCarlo`]: `estimate_frac = 0.1`, `min_calls = 16 dim`,
+    /// `min_calls_per_bisection = 32 min_calls`, `alpha = 2` and `dither = 0`.
+    pub fn new(dim: usize) -> MiserParams {
+        MiserParams(sys::gsl_monte_miser_params {
+            estimate_frac: 0.1,
+            min_calls: 16 * dim,
+            min_calls_per_bisection: 32 * 16 * dim,
+            alpha: 2.,
+            dither: 0.,
+        })
+    }
+
+    /// Sets the fraction of the currently available number of function calls which are
+    /// allocated to estimating the variance at each recursive step. The default value is 0.1.
+    pub fn estimate_frac(mut self, estimate_frac: f64) -> Self {
+        self.0.estimate_frac = estimate_frac;
+        self
+    }
+
+    /// Sets the minimum number of function calls required for each estimate of the variance. If
+    /// the number of function calls allocated to the estimate using `estimate_frac` falls below
+    /// `min_calls` then `min_calls` are used instead. This ensures that each estimate maintains
+    /// a reasonable level of accuracy. The default value is 16 times the dimension.
+    pub fn min_calls(mut self, min_calls: usize) -> Self {
+        self.0.min_calls = min_calls;
+        self
+    }
+
+    /// Sets the minimum number of function calls required to proceed with a bisection step.
+    /// When a recursive step has fewer calls available than `min_calls_per_bisection` it
+    /// performs a plain Monte Carlo estimate of the current sub-region and terminates its
+    /// branch of the recursion. The default value is 32 times `min_calls`.
+    pub fn min_calls_per_bisection(mut self, min_calls_per_bisection: usize) -> Self {
+        self.0.min_calls_per_bisection = min_calls_per_bisection;
+        self
+    }
+
+    /// Sets how the estimated variances for the two sub-regions of a bisection are combined
+    /// when allocating points. With recursive sampling the overall variance should scale
+    /// better than 1/N, since the values from the sub-regions will be obtained using a
+    /// procedure which explicitly minimizes their variance. To accommodate this behavior the
+    /// MISER algorithm allows the total variance to depend on a scaling parameter alpha,
+    /// `Var(f) = sigma_a / N_a^alpha + sigma_b / N_b^alpha`. The default value is 2.
+    pub fn alpha(mut self, alpha: f64) -> Self {
+        self.0.alpha = alpha;
+        self
+    }
+
+    /// Sets the random fraction by which the bisection point is moved off the center of each
+    /// region, i.e. `0.5 ± dither`. This can be used to break the symmetry of integrands which
+    /// are concentrated near the exact center of the hypercubic integration region. The default
+    /// value is 0 (no dithering); a value of about 0.1 is suggested otherwise.
+    pub fn dither(mut self, dither: f64) -> Self {
+        self.0.dither = dither;
+        self
+    }
+}
+
 ffi_wrapper!(VegasMonteCarlo, *mut sys::gsl_monte_vegas_state, gsl_monte_vegas_free,
 "The VEGAS algorithm of Lepage is based on importance sampling. It samples points from the probability
 distribution described by the function |f|, so that the points are concentrated in the regions that
@@ -466,8 +599,165 @@ impl VegasMonteCarlo {
             sys::gsl_monte_vegas_params_set(self.unwrap_unique(), &params.inner as *const _);
         }
     }
+
+    /// Same as [`integrate`](Self::integrate) but also returns the estimate of each iteration,
+    /// to judge the convergence of the algorithm.
+    ///
+    /// The iterations are performed one call at a time, the first one starting at the stage of
+    /// the parameters of `self` and the following ones continuing it, which gives the same
+    /// result as a single call. The parameters of `self` are left unchanged.
+    ///
+    /// Returns `(result, abserr, iterations)`.
+    pub fn integrate_iterations<F: FnMut(&[f64]) -> f64>(
+        &mut self,
+        mut f: F,
+        xl: &[f64],
+        xu: &[f64],
+        t_calls: usize,
+        r: &mut crate::Rng,
+    ) -> Result<(f64, f64, Vec<VegasIteration>), Value> {
+        // Detached from the borrow of `self`, the stream (if any) being kept by `self`.
+        let saved = VegasParams {
+            inner: self.get_params().inner,
+            lt: PhantomData,
+        };
+        let mut params = VegasParams {
+            inner: saved.inner,
+            lt: PhantomData,
+        };
+        params.inner.iterations = 1;
+        let mut result = (0., 0.);
+        let mut history = Vec::with_capacity(saved.inner.iterations);
+        for i in 0..saved.inner.iterations {
+            if i == 1 {
+                params.inner.stage = crate::VegasStage::Continue.into();
+            }
+            self.set_params(&params);
+            let ret = self.integrate(&mut f, xl, xu, t_calls, r);
+            result = match ret {
+                Ok(result) => result,
+                Err(e) => {
+                    self.set_params(&saved);
+                    return Err(e);
+                }
+            };
+            let (res, sigma) = self.runval();
+            history.push(VegasIteration {
+                result: res,
+                sigma,
+                chisq: self.chisq(),
+            });
+        }
+        self.set_params(&saved);
+        Ok((result.0, result.1, history))
+    }
+
+    /// Same as [`integrate`](Self::integrate) but the `t_calls` function calls are split into
+    /// `batches` independent VEGAS runs which are evaluated in parallel. The estimates of the
+    /// runs are then combined by a weighted average, as VEGAS does for its own iterations.
+    ///
+    /// Each run uses a generator of the same type as `r`, seeded with a value drawn from `r`, so
+    /// that the result is reproducible for a given state of `r`. The parameters of `self` (alpha,
+    /// iterations and mode) are used by all the runs, without any output. Since each run adapts
+    /// its own grid, every batch needs enough calls for the grid to converge. The calls are
+    /// distributed as evenly as possible among the batches.
+    ///
+    /// The state of `self` is not modified. Returns [`Value::Invalid`] if `batches` is zero or if
+    /// there are fewer than 2 calls per batch.
+    ///
+    /// ```
+    /// use rgsl::{Rng, RngType, VegasMonteCarlo};
+    ///
+    /// let mut r = Rng::new(RngType::default()).unwrap();
+    /// let s = VegasMonteCarlo::new(2).unwrap();
+    /// let (res, err) = s
+    ///     .par_integrate(|x: &[f64]| x[0] * x[1], &[0., 0.], &[1., 1.], 400_000, 8, &mut r)
+    ///     .unwrap();
+    /// assert!((res - 0.25).abs() < 5. * err);
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "rayon")))]
+    pub fn par_integrate<F: Integrand + Sync>(
+        &self,
+        f: F,
+        xl: &[f64],
+        xu: &[f64],
+        t_calls: usize,
+        batches: usize,
+        r: &mut crate::Rng,
+    ) -> Result<(f64, f64), Value> {
+        use rayon::prelude::*;
+
+        assert!(xl.len() == xu.len());
+        if batches == 0 || t_calls / batches < 2 {
+            return Err(Value::Invalid);
+        }
+        let sys::gsl_monte_vegas_params {
+            alpha,
+            iterations,
+            mode,
+            ..
+        } = self.get_params().inner;
+        let rng_name = r.name();
+        // The first `t_calls % batches` runs get one more call.
+        let batch_calls = |i| t_calls / batches + usize::from(i < t_calls % batches);
+        let seeds: Vec<(usize, usize)> = (0..batches).map(|i| (r.get(), batch_calls(i))).collect();
+
+        let runs = seeds
+            .into_par_iter()
+            .map(|(seed, calls)| {
+                let t = crate::RngType::from_name(&rng_name).ok_or(Value::Failure)?;
+                let mut r = crate::Rng::new(t).ok_or(Value::NoMemory)?;
+                r.set(seed);
+                let mut s = VegasMonteCarlo::new(xl.len()).ok_or(Value::NoMemory)?;
+                let mut params = VegasParams::default();
+                params.inner.alpha = alpha;
+                params.inner.iterations = iterations;
+                params.inner.mode = mode;
+                s.set_params(&params);
+                s.integrate(|x| f.eval(x), xl, xu, calls, &mut r)
+            })
+            .collect::<Result<Vec<_>, Value>>()?;
+
+        Ok(weighted_average(&runs))
+    }
+}
+
+// Combines independent estimates `(result, sigma)` by weighting them with `1 / sigma^2`. A zero
+// error being an infinite weight, if some of the errors are zero the arithmetic mean of the
+// corresponding estimates is returned with a zero error.
+#[cfg(feature = "rayon")]
+fn weighted_average(runs: &[(f64, f64)]) -> (f64, f64) {
+    let exact: Vec<f64> = runs
+        .iter()
+        .filter(|(_, sigma)| *sigma == 0.)
+        .map(|(res, _)| *res)
+        .collect();
+    if !exact.is_empty() {
+        return (exact.iter().sum::<f64>() / exact.len() as f64, 0.);
+    }
+    let (mut sum_wr, mut sum_w) = (0., 0.);
+    for &(res, sigma) in runs {
+        let w = 1. / (sigma * sigma);
+        sum_wr += w * res;
+        sum_w += w;
+    }
+    (sum_wr / sum_w, 1. / sum_w.sqrt())
 }
 
+/// Parameters of the VEGAS algorithm, see [`VegasMonteCarlo::set_params`].
+///
+/// They are built from the default values by chaining the setters:
+///
+/// ```
+/// use rgsl::{VegasMode, VegasParams, VegasStage};
+///
+/// let params = VegasParams::default()
+///     .alpha(1.2)
+///     .iterations(10)
+///     .stage(VegasStage::KeepGrid)
+///     .mode(VegasMode::Importance);
+/// ```
 pub struct VegasParams<'a> {
     inner: sys::gsl_monte_vegas_params,
     lt: PhantomData<&'a ()>,
@@ -498,6 +788,10 @@ impl<'a> VegasParams<'a> {
     /// stratified sampling is chosen if there are fewer than 2 bins per box).
     ///
     /// verbosity + stream: These parameters set the level of information printed by vegas.
+    #[deprecated(
+        since = "8.0.0",
+        note = "Please use `VegasParams::default()` and its setters instead"
+    )]
     pub fn new(
         alpha: f64,
         iterations: usize,
@@ -506,6 +800,59 @@ impl<'a> VegasParams<'a> {
         verbosity: VegasVerbosity,
         stream: Option<&'a mut crate::IOStream>,
     ) -> Result<VegasParams, String> {
+        let mut params = VegasParams::default()
+            .alpha(alpha)
+            .iterations(iterations)
+            .mode(mode)
+            .verbosity(verbosity, stream)?;
+        params.inner.stage = stage;
+        Ok(params)
+    }
+
+    /// Sets the stiffness of the rebinning algorithm. It is typically set between one and two. A
+    /// value of zero prevents rebinning of the grid. The default value is 1.5.
+    pub fn alpha(mut self, alpha: f64) -> Self {
+        self.inner.alpha = alpha;
+        self
+    }
+
+    /// Sets the number of iterations to perform for each call to the routine. The default value
+    /// is 5 iterations.
+    pub fn iterations(mut self, iterations: usize) -> Self {
+        self.inner.iterations = iterations;
+        self
+    }
+
+    /// Sets the stage at which the calculation starts. The default is [`VegasStage::NewGrid`].
+    ///
+    /// [`VegasStage::KeepAverage`] continues a previous run, keeping its grid and weighted
+    /// average, which is how more iterations are added until the chi-squared per degree of
+    /// freedom is consistent with 1.
+    ///
+    /// [`VegasStage::NewGrid`]: crate::VegasStage::NewGrid
+    /// [`VegasStage::KeepAverage`]: crate::VegasStage::KeepAverage
+    pub fn stage(mut self, stage: crate::VegasStage) -> Self {
+        self.inner.stage = stage.into();
+        self
+    }
+
+    /// Sets whether importance sampling or stratified sampling is used, or whether VEGAS can
+    /// pick on its own. In low dimensions VEGAS uses strict stratified sampling (more precisely,
+    /// stratified sampling is chosen if there are fewer than 2 bins per box). The default is
+    /// [`VegasMode::ImportanceOnly`](crate::VegasMode::ImportanceOnly).
+    pub fn mode(mut self, mode: crate::VegasMode) -> Self {
+        self.inner.mode = mode.into();
+        self
+    }
+
+    /// Sets the level of information printed by VEGAS and the stream it is written to. The
+    /// stream is required, and must be opened in write mode, unless `verbosity` is
+    /// [`VegasVerbosity::Off`] (the default).
+    pub fn verbosity(
+        mut self,
+        verbosity: VegasVerbosity,
+        stream: Option<&'a mut crate::IOStream>,
+    ) -> Result<Self, String> {
         if !verbosity.is_off() && stream.is_none() {
             return Err(
                 "rust-GSL: need to provide an input stream for Vegas Monte Carlo \
@@ -528,17 +875,9 @@ impl<'a> VegasParams<'a> {
         } else {
             std::ptr::null_mut()
         };
-        Ok(VegasParams {
-            inner: sys::gsl_monte_vegas_params {
-                alpha,
-                iterations,
-                stage,
-                mode: mode.into(),
-                verbose: verbosity.to_int(),
-                ostream: stream,
-            },
-            lt: PhantomData,
-        })
+        self.inner.verbose = verbosity.to_int();
+        self.inner.ostream = stream;
+        Ok(self)
     }
 }
 
@@ -558,6 +897,19 @@ impl<'a> std::default::Default for VegasParams<'a> {
     }
 }
 
+/// Estimate of the integral after one iteration of the VEGAS algorithm, see
+/// [`VegasMonteCarlo::integrate_iterations`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VegasIteration {
+    /// Estimate of the integral computed by this iteration alone.
+    pub result: f64,
+    /// Error of `result`.
+    pub sigma: f64,
+    /// Chi-squared per degree of freedom of the weighted average of the iterations up to this
+    /// one.
+    pub chisq: f64,
+}
+
 /// The default setting of verbose is `Off`, which turns off all output.
 /// A verbose value of `Summary` prints summary information about the weighted average
 /// and final result, while a value of `Grid` also displays the grid coordinates.
@@ -814,3 +1166,38 @@ fn vegas() {
         assert_eq!(&format!("{:.6}", err), "0.000335");
     }
 }
+
+#[cfg(feature = "rayon")]
+#[test]
+fn vegas_par_integrate() {
+    crate::RngType::env_setup();
+    let mut r = crate::Rng::new(crate::RngType::default()).unwrap();
+    let s = VegasMonteCarlo::new(2).unwrap();
+    let (xl, xu) = ([0., 0.], [1., 1.]);
+
+    assert_eq!(
+        s.par_integrate(|x: &[f64]| x[0], &xl, &xu, 1000, 0, &mut r),
+        Err(Value::Invalid)
+    );
+    assert_eq!(
+        s.par_integrate(|x: &[f64]| x[0], &xl, &xu, 7, 4, &mut r),
+        Err(Value::Invalid)
+    );
+
+    let (res, err) = s
+        .par_integrate(|x: &[f64]| x[0] * x[1], &xl, &xu, 400_003, 8, &mut r)
+        .unwrap();
+    assert!(err > 0.);
+    assert!((res - 0.25).abs() < 5. * err);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn vegas_weighted_average() {
+    // Exact estimates have an infinite weight.
+    assert_eq!(weighted_average(&[(1., 0.), (3., 1.), (2., 0.)]), (1.5, 0.));
+
+    let (res, err) = weighted_average(&[(1., 1.), (4., 2.)]);
+    assert!((res - 1.6).abs() < 1e-15);
+    assert!((err - 0.8f64.sqrt()).abs() < 1e-15);
+}
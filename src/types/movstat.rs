@@ -0,0 +1,370 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Moving Window Statistics
+
+The functions of this module compute statistics of a signal over a window
+which moves along the signal. For each sample x_i, the window
+W_i^{H,J} = {x_{i-H}, ..., x_i, ..., x_{i+J}} contains the H samples
+before x_i and the J samples after it. A symmetric window of size K
+has H = J = K / 2 (K is rounded up to an odd number).
+
+Near the end points of the signal, the window lacks samples which are
+supplied according to [`MovstatEnd`]:
+
+- [`MovstatEnd::PadZero`] pads the signal with zeros,
+- [`MovstatEnd::PadValue`] pads the signal with its first and last values,
+- [`MovstatEnd::Truncate`] only uses the available samples.
+
+All the functions accept slices as well as [`VectorF64`](crate::VectorF64)
+for the input and output signals, which must have the same length. The
+result is always written to a separate output signal: the input is
+borrowed immutably, so the computations cannot be done in place.
+
+```
+use rgsl::{MovstatEnd, MovstatWorkspace};
+
+let x = [1., 2., 3., 4., 5.];
+let mut y = [0.; 5];
+let mut w = MovstatWorkspace::new(3).unwrap();
+w.mean(MovstatEnd::Truncate, &x, &mut y).unwrap();
+assert_eq!(y, [1.5, 2., 3., 4., 4.5]);
+```
+!*/
+
+use crate::ffi::FFI;
+use crate::vector::{self, Vector, VectorMut};
+use crate::{MovstatEnd, Value};
+use std::any::Any;
+use std::os::raw::c_void;
+use std::panic::{self, AssertUnwindSafe};
+
+ffi_wrapper!(
+    MovstatWorkspace,
+    *mut sys::gsl_movstat_workspace,
+    gsl_movstat_free
+);
+
+macro_rules! movstat_fn {
+    ($(#[$attr:meta])* $name:ident, $gsl_name:ident) => {
+        $(#[$attr])*
+        pub fn $name<T1, T2>(&mut self, endtype: MovstatEnd, x: &T1, y: &mut T2) -> Result<(), Value>
+        where
+            T1: Vector<f64> + ?Sized,
+            T2: VectorMut<f64> + ?Sized,
+        {
+            let x = vector::as_gsl_vector(x);
+            let mut y = vector::as_gsl_vector_mut(y);
+            let ret = unsafe { sys::$gsl_name(endtype.into(), &x, &mut y, self.unwrap_unique()) };
+            result_handler!(ret, ())
+        }
+    };
+}
+
+impl MovstatWorkspace {
+    /// This function allocates a workspace for computing symmetric, centered moving statistics
+    /// with a window length of K samples. In this case, H = J = \lfloor K/2 \rfloor. If K is
+    /// even, it is rounded up to the next odd number to ensure a symmetric window.
+    #[doc(alias = "gsl_movstat_alloc")]
+    pub fn new(K: usize) -> Option<Self> {
+        let s = unsafe { sys::gsl_movstat_alloc(K) };
+        if s.is_null() {
+            None
+        } else {
+            Some(Self::wrap(s))
+        }
+    }
+
+    /// This function allocates a workspace for computing moving statistics using a window with
+    /// H samples prior to the current sample, and J samples after the current sample. The total
+    /// window size is K = H + J + 1.
+    #[doc(alias = "gsl_movstat_alloc2")]
+    pub fn new2(H: usize, J: usize) -> Option<Self> {
+        let s = unsafe { sys::gsl_movstat_alloc2(H, J) };
+        if s.is_null() {
+            None
+        } else {
+            Some(Self::wrap(s))
+        }
+    }
+
+    movstat_fn!(
+        /// This function computes the moving window mean of the input vector `x`, storing the
+        /// output in `y`.
+        #[doc(alias = "gsl_movstat_mean")]
+        mean,
+        gsl_movstat_mean
+    );
+
+    movstat_fn!(
+        /// This function computes the moving window variance of the input vector `x`, storing
+        /// the output in `y`.
+        #[doc(alias = "gsl_movstat_variance")]
+        variance,
+        gsl_movstat_variance
+    );
+
+    movstat_fn!(
+        /// This function computes the moving window standard deviation of the input vector `x`,
+        /// storing the output in `y`.
+        #[doc(alias = "gsl_movstat_sd")]
+        sd,
+        gsl_movstat_sd
+    );
+
+    movstat_fn!(
+        /// This function computes the moving median of the input vector `x`, storing the output
+        /// in `y`.
+        #[doc(alias = "gsl_movstat_median")]
+        median,
+        gsl_movstat_median
+    );
+
+    movstat_fn!(
+        /// This function computes the moving minimum of the input vector `x`, storing the result
+        /// in `y`.
+        #[doc(alias = "gsl_movstat_min")]
+        min,
+        gsl_movstat_min
+    );
+
+    movstat_fn!(
+        /// This function computes the moving maximum of the input vector `x`, storing the result
+        /// in `y`.
+        #[doc(alias = "gsl_movstat_max")]
+        max,
+        gsl_movstat_max
+    );
+
+    movstat_fn!(
+        /// This function computes the moving window sum of the input vector `x`, storing the
+        /// output in `y`.
+        #[doc(alias = "gsl_movstat_sum")]
+        sum,
+        gsl_movstat_sum
+    );
+
+    movstat_fn!(
+        /// This function computes the moving S_n statistic of the input vector `x`, storing the
+        /// output in `y`. The S_n statistic is scaled to be an unbiased estimate of the
+        /// standard deviation for Gaussian data.
+        #[doc(alias = "gsl_movstat_Sn")]
+        Sn,
+        gsl_movstat_Sn
+    );
+
+    movstat_fn!(
+        /// This function computes the moving Q_n statistic of the input vector `x`, storing the
+        /// output in `y`. The Q_n statistic is scaled to be an unbiased estimate of the
+        /// standard deviation for Gaussian data.
+        #[doc(alias = "gsl_movstat_Qn")]
+        Qn,
+        gsl_movstat_Qn
+    );
+
+    /// This function computes the moving minimum and maximum of the input vector `x`, storing
+    /// the window minimums in `y_min` and the window maximums in `y_max`.
+    #[doc(alias = "gsl_movstat_minmax")]
+    pub fn minmax<T1, T2, T3>(
+        &mut self,
+        endtype: MovstatEnd,
+        x: &T1,
+        y_min: &mut T2,
+        y_max: &mut T3,
+    ) -> Result<(), Value>
+    where
+        T1: Vector<f64> + ?Sized,
+        T2: VectorMut<f64> + ?Sized,
+        T3: VectorMut<f64> + ?Sized,
+    {
+        let x = vector::as_gsl_vector(x);
+        let mut y_min = vector::as_gsl_vector_mut(y_min);
+        let mut y_max = vector::as_gsl_vector_mut(y_max);
+        let ret = unsafe {
+            sys::gsl_movstat_minmax(
+                endtype.into(),
+                &x,
+                &mut y_min,
+                &mut y_max,
+                self.unwrap_unique(),
+            )
+        };
+        result_handler!(ret, ())
+    }
+
+    /// This function computes the moving MAD of the input vector `x` and stores the result in
+    /// `xmad`. The medians of each window W_i^{H,J} are stored in `xmedian` on output. The MAD is
+    /// scaled by 1.4826 to be an unbiased estimate of the standard deviation for Gaussian data.
+    #[doc(alias = "gsl_movstat_mad")]
+    pub fn mad<T1, T2, T3>(
+        &mut self,
+        endtype: MovstatEnd,
+        x: &T1,
+        xmedian: &mut T2,
+        xmad: &mut T3,
+    ) -> Result<(), Value>
+    where
+        T1: Vector<f64> + ?Sized,
+        T2: VectorMut<f64> + ?Sized,
+        T3: VectorMut<f64> + ?Sized,
+    {
+        let x = vector::as_gsl_vector(x);
+        let mut xmedian = vector::as_gsl_vector_mut(xmedian);
+        let mut xmad = vector::as_gsl_vector_mut(xmad);
+        let ret = unsafe {
+            sys::gsl_movstat_mad(
+                endtype.into(),
+                &x,
+                &mut xmedian,
+                &mut xmad,
+                self.unwrap_unique(),
+            )
+        };
+        result_handler!(ret, ())
+    }
+
+    /// Same as [`MovstatWorkspace::mad`] but without the scale factor of 1.4826.
+    #[doc(alias = "gsl_movstat_mad0")]
+    pub fn mad0<T1, T2, T3>(
+        &mut self,
+        endtype: MovstatEnd,
+        x: &T1,
+        xmedian: &mut T2,
+        xmad: &mut T3,
+    ) -> Result<(), Value>
+    where
+        T1: Vector<f64> + ?Sized,
+        T2: VectorMut<f64> + ?Sized,
+        T3: VectorMut<f64> + ?Sized,
+    {
+        let x = vector::as_gsl_vector(x);
+        let mut xmedian = vector::as_gsl_vector_mut(xmedian);
+        let mut xmad = vector::as_gsl_vector_mut(xmad);
+        let ret = unsafe {
+            sys::gsl_movstat_mad0(
+                endtype.into(),
+                &x,
+                &mut xmedian,
+                &mut xmad,
+                self.unwrap_unique(),
+            )
+        };
+        result_handler!(ret, ())
+    }
+
+    /// This function applies the user-defined moving window function `f` to the input vector
+    /// `x`, storing the output in `y`. For each sample, `f` receives the samples of the current
+    /// window (padded according to `endtype`) and returns the value of the statistic for this
+    /// window. The window may be modified by `f`, for example to sort it. If `f` panics, the
+    /// panic is propagated once GSL has returned.
+    ///
+    /// ```
+    /// use rgsl::{MovstatEnd, MovstatWorkspace};
+    ///
+    /// let x = [1., 5., 2., 4., 3.];
+    /// let mut y = [0.; 5];
+    /// let mut w = MovstatWorkspace::new(3).unwrap();
+    /// // Moving range.
+    /// w.apply(MovstatEnd::Truncate, |window| {
+    ///     let max = window.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    ///     let min = window.iter().cloned().fold(f64::INFINITY, f64::min);
+    ///     max - min
+    /// }, &x, &mut y).unwrap();
+    /// assert_eq!(y, [4., 4., 3., 2., 1.]);
+    /// ```
+    #[doc(alias = "gsl_movstat_apply")]
+    pub fn apply<F, T1, T2>(
+        &mut self,
+        endtype: MovstatEnd,
+        f: F,
+        x: &T1,
+        y: &mut T2,
+    ) -> Result<(), Value>
+    where
+        F: FnMut(&mut [f64]) -> f64,
+        T1: Vector<f64> + ?Sized,
+        T2: VectorMut<f64> + ?Sized,
+    {
+        // `gsl_movstat_function` is opaque in the bindings.
+        #[repr(C)]
+        struct MovstatFunction {
+            function: unsafe extern "C" fn(usize, *mut f64, *mut c_void) -> f64,
+            params: *mut c_void,
+        }
+
+        struct Params<F> {
+            f: F,
+            panicked: Option<Box<dyn Any + Send>>,
+        }
+
+        // A panic cannot unwind through GSL: it is caught here, the remaining windows are
+        // skipped and the panic is resumed once `gsl_movstat_apply` returns.
+        unsafe extern "C" fn trampoline<F: FnMut(&mut [f64]) -> f64>(
+            n: usize,
+            x: *mut f64,
+            params: *mut c_void,
+        ) -> f64 {
+            let params = &mut *(params as *mut Params<F>);
+            if params.panicked.is_some() {
+                return f64::NAN;
+            }
+            let window = if n == 0 {
+                &mut []
+            } else {
+                std::slice::from_raw_parts_mut(x, n)
+            };
+            match panic::catch_unwind(AssertUnwindSafe(|| (params.f)(window))) {
+                Ok(v) => v,
+                Err(e) => {
+                    params.panicked = Some(e);
+                    f64::NAN
+                }
+            }
+        }
+
+        let mut params = Params { f, panicked: None };
+        let function = MovstatFunction {
+            function: trampoline::<F>,
+            params: &mut params as *mut Params<F> as *mut c_void,
+        };
+        let x = vector::as_gsl_vector(x);
+        let mut y = vector::as_gsl_vector_mut(y);
+        let ret = unsafe {
+            sys::gsl_movstat_apply(
+                endtype.into(),
+                &function as *const MovstatFunction as *const sys::gsl_movstat_function,
+                &x,
+                &mut y,
+                self.unwrap_unique(),
+            )
+        };
+        if let Some(e) = params.panicked {
+            panic::resume_unwind(e);
+        }
+        result_handler!(ret, ())
+    }
+
+    /// This function computes the moving q-quantile range (QQR) of the input vector `x` and
+    /// stores the result in `xqqr`. The quantile parameter `q` must be between 0 and 0.5. The
+    /// input `q = 0.25` corresponds to the interquartile range.
+    #[doc(alias = "gsl_movstat_qqr")]
+    pub fn qqr<T1, T2>(
+        &mut self,
+        endtype: MovstatEnd,
+        x: &T1,
+        q: f64,
+        xqqr: &mut T2,
+    ) -> Result<(), Value>
+    where
+        T1: Vector<f64> + ?Sized,
+        T2: VectorMut<f64> + ?Sized,
+    {
+        let x = vector::as_gsl_vector(x);
+        let mut xqqr = vector::as_gsl_vector_mut(xqqr);
+        let ret =
+            unsafe { sys::gsl_movstat_qqr(endtype.into(), &x, q, &mut xqqr, self.unwrap_unique()) };
+        result_handler!(ret, ())
+    }
+}
@@ -45,7 +45,12 @@ impl MultifitLinearWorkspace {
         result_handler!(ret, chisq)
     }
 
-    /// Returns `(chisq, rank)`.
+    /// Computes the best-fit parameters `c` of the model `y = X c` using a truncated SVD of the
+    /// design matrix `x`. Singular values which satisfy `s_i <= tol × s_0` are discarded, which
+    /// makes the fit robust to ill-conditioned (nearly collinear) design matrices. The
+    /// covariance matrix of the parameters is stored in `cov`.
+    ///
+    /// Returns `(chisq, rank)`, the sum of squared residuals and the effective rank of `x`.
     #[cfg(feature = "v2_3")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     #[doc(alias = "gsl_multifit_linear_tsvd")]
@@ -86,6 +91,8 @@ impl MultifitLinearWorkspace {
         result_handler!(ret, ())
     }
 
+    /// Returns the effective rank of the design matrix from the singular values computed by the
+    /// last SVD, i.e. the number of singular values satisfying `s_i > tol × s_0`.
     #[cfg(feature = "v2_3")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     #[doc(alias = "gsl_multifit_linear_rank")]
@@ -310,6 +317,82 @@ impl MultifitLinearWorkspace {
         result_handler!(ret, ())
     }
 
+    /// Computes the curvature `kappa` of the L-curve at each point `(rho_i, eta_i)` returned by
+    /// [`linear_lcurve`](Self::linear_lcurve). The corner of the L-curve is located at the
+    /// maximum curvature.
+    #[cfg(feature = "v2_7")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_7")))]
+    #[doc(alias = "gsl_multifit_linear_lcurvature")]
+    pub fn linear_lcurvature(
+        &mut self,
+        y: &VectorF64,
+        reg_param: &VectorF64,
+        rho: &VectorF64,
+        eta: &VectorF64,
+        kappa: &mut VectorF64,
+    ) -> Result<(), Value> {
+        let ret = unsafe {
+            sys::gsl_multifit_linear_lcurvature(
+                y.unwrap_shared(),
+                reg_param.unwrap_shared(),
+                rho.unwrap_shared(),
+                eta.unwrap_shared(),
+                kappa.unwrap_unique(),
+                self.unwrap_unique(),
+            )
+        };
+        result_handler!(ret, ())
+    }
+
+    /// Solves the ridge (standard form Tikhonov) regression problem
+    ///
+    /// ```text
+    /// min_c ||y - X c||^2 + lambda^2 ||c||^2
+    /// ```
+    ///
+    /// by computing the SVD of `x` and calling [`linear_solve`](Self::linear_solve). Problems
+    /// with a general regularization matrix `L` can be handled by first converting them with
+    /// [`linear_stdform1`](Self::linear_stdform1) or [`linear_stdform2`](Self::linear_stdform2)
+    /// and converting the solution back with [`linear_genform1`](Self::linear_genform1) or
+    /// [`linear_genform2`](Self::linear_genform2).
+    ///
+    /// Returns `(rnorm, snorm)`, the residual norm `||y - X c||` and solution norm `||c||`.
+    pub fn linear_ridge(
+        &mut self,
+        lambda: f64,
+        x: &MatrixF64,
+        y: &VectorF64,
+        c: &mut VectorF64,
+    ) -> Result<(f64, f64), Value> {
+        let ret = unsafe { sys::gsl_multifit_linear_svd(x.unwrap_shared(), self.unwrap_unique()) };
+        result_handler!(ret, ())?;
+        self.linear_solve(lambda, x, y, c)
+    }
+
+    /// Solves the ridge regression problem (see [`linear_ridge`](Self::linear_ridge)) choosing
+    /// the regularization parameter `lambda` at the corner of the L-curve, sampled at `npoints`
+    /// values (at least 3).
+    ///
+    /// Returns `lambda`.
+    pub fn linear_ridge_lcurve(
+        &mut self,
+        x: &MatrixF64,
+        y: &VectorF64,
+        npoints: usize,
+        c: &mut VectorF64,
+    ) -> Result<f64, Value> {
+        let ret = unsafe { sys::gsl_multifit_linear_svd(x.unwrap_shared(), self.unwrap_unique()) };
+        result_handler!(ret, ())?;
+        let mut reg_param = VectorF64::new(npoints).ok_or(Value::NoMemory)?;
+        let mut rho = VectorF64::new(npoints).ok_or(Value::NoMemory)?;
+        let mut eta = VectorF64::new(npoints).ok_or(Value::NoMemory)?;
+        self.linear_lcurve(y, &mut reg_param, &mut rho, &mut eta)?;
+        let idx = crate::multifit::linear_lcorner(&rho, &eta)?;
+        let lambda = reg_param.get(idx);
+        self.linear_solve(lambda, x, y, c)?;
+        Ok(lambda)
+    }
+
     #[doc(alias = "gsl_multifit_linear_Lsobolev")]
     pub fn linear_Lsobolev(
         &mut self,
@@ -355,6 +438,8 @@ impl MultifitLinearWorkspace {
         result_handler!(ret, chisq)
     }
 
+    /// Same as [`linear_tsvd`](Self::linear_tsvd) but for a weighted fit with the weights `w`.
+    ///
     /// Returns `(chisq, rank)`.
     #[cfg(feature = "v2_3")]
     #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
@@ -452,6 +537,8 @@ impl MultifitLinearWorkspace {
     }
 
     /// Returns `delta0`.
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     #[doc(alias = "gsl_multifit_linear_gcv_init")]
     pub fn linear_gcv_init(
         &mut self,
@@ -472,6 +559,8 @@ impl MultifitLinearWorkspace {
         result_handler!(ret, delta0)
     }
 
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     #[doc(alias = "gsl_multifit_linear_gcv_curve")]
     pub fn linear_gcv_curve(
         &mut self,
@@ -493,6 +582,8 @@ impl MultifitLinearWorkspace {
     }
 
     /// Returns `lambda`.
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     #[doc(alias = "gsl_multifit_linear_gcv_min")]
     pub fn linear_gcv_min(
         &mut self,
@@ -515,6 +606,8 @@ impl MultifitLinearWorkspace {
         result_handler!(ret, lambda)
     }
 
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     #[doc(alias = "gsl_multifit_linear_gcv_calc")]
     pub fn linear_gcv_calc(&mut self, lambda: f64, UTy: &VectorF64, delta0: f64) -> f64 {
         unsafe {
@@ -527,7 +620,14 @@ impl MultifitLinearWorkspace {
         }
     }
 
-    /// Returns `(lambda, g_lambda)`.
+    /// Computes the generalized cross validation (GCV) curve `g` at the regularization
+    /// parameters `reg_param` (which are computed from the singular values, so the SVD of the
+    /// design matrix must have been computed beforehand) and finds its minimum.
+    ///
+    /// Returns `(lambda, g_lambda)`, the optimal regularization parameter and the value of the
+    /// GCV function at that point.
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
     #[doc(alias = "gsl_multifit_linear_gcv")]
     pub fn linear_gcv(
         &mut self,
@@ -549,4 +649,45 @@ impl MultifitLinearWorkspace {
         };
         result_handler!(ret, (lambda, g_lambda))
     }
+
+    /// Solves the ridge regression problem (see [`linear_ridge`](Self::linear_ridge)) choosing
+    /// the regularization parameter which minimizes the generalized cross validation function,
+    /// sampled at `npoints` values. The sampled GCV curve is returned along with the selected
+    /// parameter so that it can be inspected.
+    #[cfg(feature = "v2_3")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
+    pub fn linear_ridge_gcv(
+        &mut self,
+        x: &MatrixF64,
+        y: &VectorF64,
+        npoints: usize,
+        c: &mut VectorF64,
+    ) -> Result<MultifitLinearGcv, Value> {
+        let ret = unsafe { sys::gsl_multifit_linear_svd(x.unwrap_shared(), self.unwrap_unique()) };
+        result_handler!(ret, ())?;
+        let mut reg_param = VectorF64::new(npoints).ok_or(Value::NoMemory)?;
+        let mut g = VectorF64::new(npoints).ok_or(Value::NoMemory)?;
+        let (lambda, g_lambda) = self.linear_gcv(y, &mut reg_param, &mut g)?;
+        self.linear_solve(lambda, x, y, c)?;
+        Ok(MultifitLinearGcv {
+            lambda,
+            g_lambda,
+            reg_param,
+            g,
+        })
+    }
+}
+
+/// Result of [`MultifitLinearWorkspace::linear_ridge_gcv`].
+#[cfg(feature = "v2_3")]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v2_3")))]
+pub struct MultifitLinearGcv {
+    /// Regularization parameter minimizing the GCV function.
+    pub lambda: f64,
+    /// Value of the GCV function at `lambda`.
+    pub g_lambda: f64,
+    /// Regularization parameters at which the GCV function was sampled.
+    pub reg_param: VectorF64,
+    /// Values of the GCV function at `reg_param`.
+    pub g: VectorF64,
 }
@@ -0,0 +1,768 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+# Nonlinear Least-Squares Fitting (trust region methods)
+
+This module wraps the `gsl_multifit_nlinear` interface introduced in GSL 2.2. It minimizes the
+sum of squared residuals of `n` functions `f_i` in `p` parameters `x_j`,
+
+```text
+\Phi(x) = (1/2) || f(x) ||^2 = (1/2) \sum_{i=1}^{n} f_i(x_1, ..., x_p)^2
+```
+
+using trust region methods. At each iteration a step `δ` is computed by (approximately)
+minimizing a quadratic model of `Φ` inside a region of radius `Δ` around the current position.
+The available trust region subproblem methods are Levenberg-Marquardt, Levenberg-Marquardt with
+geodesic acceleration, dogleg, double dogleg and 2D subspace (see [`MultiFitNlinearTrs`]).
+
+The residual function and, optionally, its Jacobian and second directional derivative are given
+as closures through [`MultiFitNlinearFdf`]. When the Jacobian is not supplied, it is approximated
+with finite differences.
+
+## Example
+
+```
+use rgsl::{
+    MultiFitNlinearFdf, MultiFitNlinearParameters, MultiFitNlinearType, MultiFitNlinearWorkspace,
+    Value, VectorF64,
+};
+
+// Fit y = A exp(-lambda t) + b to some data.
+let t: Vec<f64> = (0..40).map(|i| i as f64).collect();
+let y: Vec<f64> = t.iter().map(|t| 5. * (-0.1 * t).exp() + 1.).collect();
+
+let fdf = MultiFitNlinearFdf::new(t.len(), 3, |x: &VectorF64, f: &mut VectorF64| {
+    let (a, lambda, b) = (x.get(0), x.get(1), x.get(2));
+    for (i, (t, y)) in t.iter().zip(&y).enumerate() {
+        f.set(i, a * (-lambda * t).exp() + b - y);
+    }
+    Value::Success
+});
+
+let params = MultiFitNlinearParameters::default();
+let mut w = MultiFitNlinearWorkspace::new(MultiFitNlinearType::trust(), &params, t.len(), 3)
+    .unwrap();
+w.init(&VectorF64::from_slice(&[1., 0., 0.]).unwrap(), fdf).unwrap();
+w.driver(100, 1e-8, 1e-8, 0., None).unwrap();
+
+let x = w.position();
+assert!((x.get(0) - 5.).abs() < 1e-6);
+assert!((x.get(1) - 0.1).abs() < 1e-6);
+assert!((x.get(2) - 1.).abs() < 1e-6);
+```
+!*/
+
+use crate::ffi::FFI;
+use crate::{MatrixF64, MultiFitNlinearFdType, Value, VectorF64, View};
+use std::ffi::CStr;
+use std::mem::ManuallyDrop;
+use std::os::raw::{c_int, c_void};
+
+ffi_wrapper!(
+    MultiFitNlinearType,
+    *const sys::gsl_multifit_nlinear_type,
+    "Type of the nonlinear least-squares solver."
+);
+
+impl MultiFitNlinearType {
+    /// Trust region method. This is currently the only type provided by GSL.
+    #[doc(alias = "gsl_multifit_nlinear_trust")]
+    pub fn trust() -> MultiFitNlinearType {
+        ffi_wrap!(gsl_multifit_nlinear_trust)
+    }
+}
+
+ffi_wrapper!(
+    MultiFitNlinearTrs,
+    *const sys::gsl_multifit_nlinear_trs,
+    "Method used to solve the trust region subproblem."
+);
+
+impl MultiFitNlinearTrs {
+    /// Levenberg-Marquardt algorithm.
+    #[doc(alias = "gsl_multifit_nlinear_trs_lm")]
+    pub fn lm() -> MultiFitNlinearTrs {
+        ffi_wrap!(gsl_multifit_nlinear_trs_lm)
+    }
+
+    /// Levenberg-Marquardt algorithm with geodesic acceleration. The second directional
+    /// derivative `fvv` is used when provided, otherwise it is approximated by finite differences.
+    #[doc(alias = "gsl_multifit_nlinear_trs_lmaccel")]
+    pub fn lm_accel() -> MultiFitNlinearTrs {
+        ffi_wrap!(gsl_multifit_nlinear_trs_lmaccel)
+    }
+
+    /// Powell's dogleg algorithm.
+    #[doc(alias = "gsl_multifit_nlinear_trs_dogleg")]
+    pub fn dogleg() -> MultiFitNlinearTrs {
+        ffi_wrap!(gsl_multifit_nlinear_trs_dogleg)
+    }
+
+    /// Double dogleg algorithm, which improves upon the dogleg method by moving the Gauss-Newton
+    /// point closer to the steepest descent direction.
+    #[doc(alias = "gsl_multifit_nlinear_trs_ddogleg")]
+    pub fn ddogleg() -> MultiFitNlinearTrs {
+        ffi_wrap!(gsl_multifit_nlinear_trs_ddogleg)
+    }
+
+    /// Two dimensional subspace method, which searches the subspace spanned by the Gauss-Newton
+    /// and steepest descent directions.
+    #[doc(alias = "gsl_multifit_nlinear_trs_subspace2D")]
+    pub fn subspace_2d() -> MultiFitNlinearTrs {
+        ffi_wrap!(gsl_multifit_nlinear_trs_subspace2D)
+    }
+}
+
+ffi_wrapper!(
+    MultiFitNlinearScale,
+    *const sys::gsl_multifit_nlinear_scale,
+    "Scaling method used to define the trust region geometry."
+);
+
+impl MultiFitNlinearScale {
+    /// Levenberg scaling: the scaling matrix is the identity, so the trust region is a sphere.
+    /// This is not invariant under rescaling of the model parameters.
+    #[doc(alias = "gsl_multifit_nlinear_scale_levenberg")]
+    pub fn levenberg() -> MultiFitNlinearScale {
+        ffi_wrap!(gsl_multifit_nlinear_scale_levenberg)
+    }
+
+    /// Marquardt scaling: the scaling matrix is the diagonal of `J^T J`. This is scale invariant
+    /// but prone to setting some parameters to zero when the Jacobian has zero columns.
+    #[doc(alias = "gsl_multifit_nlinear_scale_marquardt")]
+    pub fn marquardt() -> MultiFitNlinearScale {
+        ffi_wrap!(gsl_multifit_nlinear_scale_marquardt)
+    }
+
+    /// Moré scaling: the diagonal of `J^T J` is updated with the largest values seen so far.
+    /// This is the default.
+    #[doc(alias = "gsl_multifit_nlinear_scale_more")]
+    pub fn more() -> MultiFitNlinearScale {
+        ffi_wrap!(gsl_multifit_nlinear_scale_more)
+    }
+}
+
+ffi_wrapper!(
+    MultiFitNlinearSolver,
+    *const sys::gsl_multifit_nlinear_solver,
+    "Linear solver used for the trust region subproblems."
+);
+
+impl MultiFitNlinearSolver {
+    /// Cholesky decomposition of the normal equations. This is the fastest method but is
+    /// sensitive to ill-conditioned Jacobians.
+    #[doc(alias = "gsl_multifit_nlinear_solver_cholesky")]
+    pub fn cholesky() -> MultiFitNlinearSolver {
+        ffi_wrap!(gsl_multifit_nlinear_solver_cholesky)
+    }
+
+    /// Modified Cholesky decomposition of the normal equations, which can handle matrices that
+    /// are not positive definite.
+    #[cfg(feature = "v2_6")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_6")))]
+    #[doc(alias = "gsl_multifit_nlinear_solver_mcholesky")]
+    pub fn mcholesky() -> MultiFitNlinearSolver {
+        ffi_wrap!(gsl_multifit_nlinear_solver_mcholesky)
+    }
+
+    /// QR decomposition of the Jacobian. This is the default.
+    #[doc(alias = "gsl_multifit_nlinear_solver_qr")]
+    pub fn qr() -> MultiFitNlinearSolver {
+        ffi_wrap!(gsl_multifit_nlinear_solver_qr)
+    }
+
+    /// Singular value decomposition of the Jacobian. This is the slowest but most robust method
+    /// for ill-conditioned problems.
+    #[doc(alias = "gsl_multifit_nlinear_solver_svd")]
+    pub fn svd() -> MultiFitNlinearSolver {
+        ffi_wrap!(gsl_multifit_nlinear_solver_svd)
+    }
+}
+
+/// Tuning parameters of the trust region solver.
+///
+/// The [`Default`] implementation returns the same values as
+/// `gsl_multifit_nlinear_default_parameters`.
+#[doc(alias = "gsl_multifit_nlinear_parameters")]
+#[doc(alias = "gsl_multifit_nlinear_default_parameters")]
+#[derive(Clone, Copy)]
+pub struct MultiFitNlinearParameters {
+    /// Trust region subproblem method.
+    pub trs: MultiFitNlinearTrs,
+    /// Scaling method.
+    pub scale: MultiFitNlinearScale,
+    /// Linear solver.
+    pub solver: MultiFitNlinearSolver,
+    /// Finite difference method used when the Jacobian is not given.
+    pub fdtype: MultiFitNlinearFdType,
+    /// Factor by which the trust region radius is increased after a successful step.
+    pub factor_up: f64,
+    /// Factor by which the trust region radius is decreased after a rejected step.
+    pub factor_down: f64,
+    /// Maximum allowed ratio of the acceleration to the velocity `|a| / |v|` for geodesic
+    /// acceleration.
+    pub avmax: f64,
+    /// Step size for finite difference Jacobian approximations.
+    pub h_df: f64,
+    /// Step size for the finite difference approximation of `fvv`.
+    pub h_fvv: f64,
+}
+
+impl Default for MultiFitNlinearParameters {
+    fn default() -> MultiFitNlinearParameters {
+        MultiFitNlinearParameters {
+            trs: MultiFitNlinearTrs::lm(),
+            scale: MultiFitNlinearScale::more(),
+            solver: MultiFitNlinearSolver::qr(),
+            fdtype: MultiFitNlinearFdType::ForwardDiff,
+            factor_up: 3.,
+            factor_down: 2.,
+            avmax: 0.75,
+            h_df: f64::EPSILON.sqrt(),
+            h_fvv: 0.02,
+        }
+    }
+}
+
+// `gsl_multifit_nlinear_parameters` is opaque in the generated bindings.
+#[repr(C)]
+struct RawParameters {
+    trs: *const sys::gsl_multifit_nlinear_trs,
+    scale: *const sys::gsl_multifit_nlinear_scale,
+    solver: *const sys::gsl_multifit_nlinear_solver,
+    fdtype: sys::gsl_multifit_nlinear_fdtype,
+    factor_up: f64,
+    factor_down: f64,
+    avmax: f64,
+    h_df: f64,
+    h_fvv: f64,
+}
+
+impl MultiFitNlinearParameters {
+    fn to_raw(self) -> RawParameters {
+        RawParameters {
+            trs: self.trs.unwrap_shared(),
+            scale: self.scale.unwrap_shared(),
+            solver: self.solver.unwrap_shared(),
+            fdtype: self.fdtype.into(),
+            factor_up: self.factor_up,
+            factor_down: self.factor_down,
+            avmax: self.avmax,
+            h_df: self.h_df,
+            h_fvv: self.h_fvv,
+        }
+    }
+}
+
+// `gsl_multifit_nlinear_fdf` is opaque in the generated bindings.
+#[repr(C)]
+struct RawFdf {
+    f: unsafe extern "C" fn(*const sys::gsl_vector, *mut c_void, *mut sys::gsl_vector) -> c_int,
+    df: Option<
+        unsafe extern "C" fn(*const sys::gsl_vector, *mut c_void, *mut sys::gsl_matrix) -> c_int,
+    >,
+    fvv: Option<
+        unsafe extern "C" fn(
+            *const sys::gsl_vector,
+            *const sys::gsl_vector,
+            *mut c_void,
+            *mut sys::gsl_vector,
+        ) -> c_int,
+    >,
+    n: usize,
+    p: usize,
+    params: *mut c_void,
+    nevalf: usize,
+    nevaldf: usize,
+    nevalfvv: usize,
+}
+
+type FdfF<'a> = dyn Fn(&VectorF64, &mut VectorF64) -> Value + 'a;
+type FdfDf<'a> = dyn Fn(&VectorF64, &mut MatrixF64) -> Value + 'a;
+type FdfFvv<'a> = dyn Fn(&VectorF64, &VectorF64, &mut VectorF64) -> Value + 'a;
+
+/// The system of `n` residual functions in `p` parameters to be fitted.
+///
+/// Besides the residual function `f`, the Jacobian `df` and the second directional derivative
+/// `fvv` (only used for geodesic acceleration) can be supplied. Those which are not given are
+/// approximated with finite differences.
+#[doc(alias = "gsl_multifit_nlinear_fdf")]
+pub struct MultiFitNlinearFdf<'a> {
+    f: Box<FdfF<'a>>,
+    df: Option<Box<FdfDf<'a>>>,
+    fvv: Option<Box<FdfFvv<'a>>>,
+    n: usize,
+    p: usize,
+}
+
+impl<'a> MultiFitNlinearFdf<'a> {
+    /// Creates a system of `n` functions in `p` parameters. The closure `f(x, f)` must store in
+    /// `f` the `n` residuals at the position `x` (of length `p`).
+    pub fn new<F: Fn(&VectorF64, &mut VectorF64) -> Value + 'a>(
+        n: usize,
+        p: usize,
+        f: F,
+    ) -> MultiFitNlinearFdf<'a> {
+        MultiFitNlinearFdf {
+            f: Box::new(f),
+            df: None,
+            fvv: None,
+            n,
+            p,
+        }
+    }
+
+    /// Sets the Jacobian function. The closure `df(x, J)` must store in the `n`-by-`p` matrix `J`
+    /// the Jacobian `J_ij = ∂f_i(x) / ∂x_j`.
+    pub fn df<F: Fn(&VectorF64, &mut MatrixF64) -> Value + 'a>(
+        mut self,
+        df: F,
+    ) -> MultiFitNlinearFdf<'a> {
+        self.df = Some(Box::new(df));
+        self
+    }
+
+    /// Sets the second directional derivative function. The closure `fvv(x, v, fvv)` must store
+    /// in `fvv` the vector `fvv_i = \sum_{αβ} v_α v_β ∂_α ∂_β f_i(x)`. It is only used by
+    /// [`MultiFitNlinearTrs::lm_accel`].
+    pub fn fvv<F: Fn(&VectorF64, &VectorF64, &mut VectorF64) -> Value + 'a>(
+        mut self,
+        fvv: F,
+    ) -> MultiFitNlinearFdf<'a> {
+        self.fvv = Some(Box::new(fvv));
+        self
+    }
+}
+
+unsafe extern "C" fn fdf_f(
+    x: *const sys::gsl_vector,
+    params: *mut c_void,
+    f: *mut sys::gsl_vector,
+) -> c_int {
+    let fdf = &*(params as *const MultiFitNlinearFdf<'_>);
+    let x = VectorF64::soft_wrap(x as *mut _);
+    Value::into((fdf.f)(&x, &mut VectorF64::soft_wrap(f)))
+}
+
+unsafe extern "C" fn fdf_df(
+    x: *const sys::gsl_vector,
+    params: *mut c_void,
+    df: *mut sys::gsl_matrix,
+) -> c_int {
+    let fdf = &*(params as *const MultiFitNlinearFdf<'_>);
+    let x = VectorF64::soft_wrap(x as *mut _);
+    match fdf.df {
+        Some(ref g) => Value::into(g(&x, &mut MatrixF64::soft_wrap(df))),
+        None => sys::GSL_EFAULT,
+    }
+}
+
+unsafe extern "C" fn fdf_fvv(
+    x: *const sys::gsl_vector,
+    v: *const sys::gsl_vector,
+    params: *mut c_void,
+    fvv: *mut sys::gsl_vector,
+) -> c_int {
+    let fdf = &*(params as *const MultiFitNlinearFdf<'_>);
+    let x = VectorF64::soft_wrap(x as *mut _);
+    let v = VectorF64::soft_wrap(v as *mut _);
+    match fdf.fvv {
+        Some(ref g) => Value::into(g(&x, &v, &mut VectorF64::soft_wrap(fvv))),
+        None => sys::GSL_EFAULT,
+    }
+}
+
+// Kept boxed so that the pointer stored by GSL in the workspace stays valid.
+struct FdfState<'a> {
+    raw: RawFdf,
+    closures: MultiFitNlinearFdf<'a>,
+}
+
+impl<'a> FdfState<'a> {
+    fn new(closures: MultiFitNlinearFdf<'a>) -> Box<FdfState<'a>> {
+        let mut state = Box::new(FdfState {
+            raw: RawFdf {
+                f: fdf_f,
+                df: closures.df.as_ref().map(|_| fdf_df as _),
+                fvv: closures.fvv.as_ref().map(|_| fdf_fvv as _),
+                n: closures.n,
+                p: closures.p,
+                params: std::ptr::null_mut(),
+                nevalf: 0,
+                nevaldf: 0,
+                nevalfvv: 0,
+            },
+            closures,
+        });
+        state.raw.params = &mut state.closures as *mut MultiFitNlinearFdf<'a> as *mut c_void;
+        state
+    }
+
+    fn as_ptr(&mut self) -> *mut sys::gsl_multifit_nlinear_fdf {
+        &mut self.raw as *mut RawFdf as *mut _
+    }
+}
+
+ffi_wrapper!(
+    MultiFitNlinearWorkspace<'a>,
+    *mut sys::gsl_multifit_nlinear_workspace,
+    gsl_multifit_nlinear_free
+    ;fdf: Option<Box<FdfState<'a>>> => None;
+    ;initialized: bool => false;,
+    "Workspace of the trust region nonlinear least-squares solver."
+);
+
+impl<'a> MultiFitNlinearWorkspace<'a> {
+    /// Allocates a workspace of type `t` for `n` observations and `p` parameters, with the
+    /// tuning parameters `params`. The number of observations `n` must be greater than or equal
+    /// to the number of parameters `p`.
+    #[doc(alias = "gsl_multifit_nlinear_alloc")]
+    pub fn new(
+        t: MultiFitNlinearType,
+        params: &MultiFitNlinearParameters,
+        n: usize,
+        p: usize,
+    ) -> Option<MultiFitNlinearWorkspace<'a>> {
+        let raw = params.to_raw();
+        let ptr = unsafe {
+            sys::gsl_multifit_nlinear_alloc(
+                t.unwrap_shared(),
+                &raw as *const RawParameters as *const _,
+                n,
+                p,
+            )
+        };
+
+        if ptr.is_null() {
+            None
+        } else {
+            Some(MultiFitNlinearWorkspace::wrap(ptr))
+        }
+    }
+
+    /// Initializes the workspace to use the system `fdf` with the initial guess `x`.
+    ///
+    /// Returns `Value::BadLength` if the numbers of functions and parameters of `fdf` or the
+    /// length of `x` do not match the workspace.
+    #[doc(alias = "gsl_multifit_nlinear_init")]
+    pub fn init(&mut self, x: &VectorF64, fdf: MultiFitNlinearFdf<'a>) -> Result<(), Value> {
+        self.init_with(x, None, fdf)
+    }
+
+    /// Same as [`init`](Self::init) but performs a weighted fit with the weights `wts`
+    /// (typically `w_i = 1 / σ_i^2`).
+    ///
+    /// Returns `Value::BadLength` as well if the length of `wts` is not the number of functions.
+    #[doc(alias = "gsl_multifit_nlinear_winit")]
+    pub fn winit(
+        &mut self,
+        x: &VectorF64,
+        wts: &VectorF64,
+        fdf: MultiFitNlinearFdf<'a>,
+    ) -> Result<(), Value> {
+        self.init_with(x, Some(wts), fdf)
+    }
+
+    fn init_with(
+        &mut self,
+        x: &VectorF64,
+        wts: Option<&VectorF64>,
+        fdf: MultiFitNlinearFdf<'a>,
+    ) -> Result<(), Value> {
+        let (n, p) = (self.residual().len(), self.position().len());
+        // GSL calls the error handler rather than returning an error for these.
+        if fdf.n != n || fdf.p != p || x.len() != p || wts.is_some_and(|wts| wts.len() != n) {
+            return Err(Value::BadLength);
+        }
+
+        let mut state = FdfState::new(fdf);
+        let ptr = state.as_ptr();
+        let ret = unsafe {
+            match wts {
+                Some(wts) => sys::gsl_multifit_nlinear_winit(
+                    x.unwrap_shared(),
+                    wts.unwrap_shared(),
+                    ptr,
+                    self.unwrap_unique(),
+                ),
+                None => {
+                    sys::gsl_multifit_nlinear_init(x.unwrap_shared(), ptr, self.unwrap_unique())
+                }
+            }
+        };
+        // Once the sizes are checked, GSL keeps `ptr` even if the first evaluation of the
+        // system fails, so the previous state can only be dropped now.
+        self.fdf = Some(state);
+        self.initialized = ret == sys::GSL_SUCCESS;
+        result_handler!(ret, ())
+    }
+
+    // The solver uses the system given to `init`, which must have succeeded.
+    fn check_initialized(&self) -> Result<(), Value> {
+        if self.initialized {
+            Ok(())
+        } else {
+            Err(Value::Invalid)
+        }
+    }
+
+    /// Performs a single iteration of the solver. If the iteration encounters an unexpected
+    /// problem then an error code is returned. `Value::NoProgress` means that the solver was
+    /// unable to find an acceptable step.
+    ///
+    /// Returns `Value::Invalid` if the last call to [`init`](Self::init) or
+    /// [`winit`](Self::winit) did not succeed.
+    #[doc(alias = "gsl_multifit_nlinear_iterate")]
+    pub fn iterate(&mut self) -> Result<(), Value> {
+        self.check_initialized()?;
+        let ret = unsafe { sys::gsl_multifit_nlinear_iterate(self.unwrap_unique()) };
+        result_handler!(ret, ())
+    }
+
+    /// Iterates the solver for at most `maxiter` iterations, stopping as soon as the convergence
+    /// test [`test`](Self::test) with tolerances `xtol`, `gtol` and `ftol` succeeds.
+    ///
+    /// If given, `callback(iter, w)` is called after each iteration (and before the first one,
+    /// with `iter == 0`), which is convenient to print the solver progress.
+    ///
+    /// On success, returns the reason for convergence (see [`test`](Self::test)).
+    /// `Value::MaxIteration` is returned if the convergence was not reached in time and
+    /// `Value::Invalid` if the workspace is not initialized, as for [`iterate`](Self::iterate).
+    #[doc(alias = "gsl_multifit_nlinear_driver")]
+    pub fn driver(
+        &mut self,
+        maxiter: usize,
+        xtol: f64,
+        gtol: f64,
+        ftol: f64,
+        callback: Option<&mut dyn FnMut(usize, &MultiFitNlinearWorkspace<'_>)>,
+    ) -> Result<i32, Value> {
+        unsafe extern "C" fn trampoline(
+            iter: usize,
+            params: *mut c_void,
+            w: *const sys::gsl_multifit_nlinear_workspace,
+        ) {
+            let f = &mut *(params as *mut &mut dyn FnMut(usize, &MultiFitNlinearWorkspace<'_>));
+            let mut w = ManuallyDrop::new(MultiFitNlinearWorkspace::soft_wrap(w as *mut _));
+            w.initialized = true;
+            f(iter, &w);
+        }
+
+        self.check_initialized()?;
+        let mut info = 0;
+        let ret = match callback {
+            Some(mut f) => unsafe {
+                sys::gsl_multifit_nlinear_driver(
+                    maxiter,
+                    xtol,
+                    gtol,
+                    ftol,
+                    Some(trampoline),
+                    &mut f as *mut _ as *mut c_void,
+                    &mut info,
+                    self.unwrap_unique(),
+                )
+            },
+            None => unsafe {
+                sys::gsl_multifit_nlinear_driver(
+                    maxiter,
+                    xtol,
+                    gtol,
+                    ftol,
+                    None,
+                    std::ptr::null_mut(),
+                    &mut info,
+                    self.unwrap_unique(),
+                )
+            },
+        };
+        result_handler!(ret, info)
+    }
+
+    /// Tests for convergence of the minimization. The test succeeds if one of the following
+    /// conditions holds:
+    ///
+    /// * the step is small: `|δ_i| <= xtol (|x_i| + xtol)` for all `i` (returns `Ok(1)`);
+    /// * the gradient is small: `max_i |g_i × max(x_i, 1)| <= gtol × max(Φ(x), 1)` where
+    ///   `g = J^T f` (returns `Ok(2)`);
+    /// * the relative change of the residual is small: `||f(x + δ)|| - ||f(x)|| <= ftol ×
+    ///   max(||f(x)||, 1)` (returns `Ok(3)`).
+    ///
+    /// Otherwise `Err(Value::Continue)` is returned, or `Err(Value::Invalid)` if the workspace is
+    /// not initialized, as for [`iterate`](Self::iterate).
+    #[doc(alias = "gsl_multifit_nlinear_test")]
+    pub fn test(&self, xtol: f64, gtol: f64, ftol: f64) -> Result<i32, Value> {
+        self.check_initialized()?;
+        let mut info = 0;
+        let ret = unsafe {
+            sys::gsl_multifit_nlinear_test(xtol, gtol, ftol, &mut info, self.unwrap_shared())
+        };
+        result_handler!(ret, info)
+    }
+
+    /// Computes the covariance matrix of the best-fit parameters using the Jacobian at the
+    /// current position, `C = (J^T J)^{-1}`. Columns of the Jacobian which satisfy
+    /// `||J_i|| <= epsrel ||J_max||` are considered linearly dependent and excluded.
+    ///
+    /// For an unweighted fit, the covariance must be multiplied by the variance of the residuals
+    /// to obtain the parameter uncertainties.
+    #[doc(alias = "gsl_multifit_nlinear_covar")]
+    pub fn covar(&self, epsrel: f64) -> Result<MatrixF64, Value> {
+        let j = self.jac();
+        let mut covar = MatrixF64::new(j.size2(), j.size2()).ok_or(Value::NoMemory)?;
+        let ret = unsafe {
+            sys::gsl_multifit_nlinear_covar(j.unwrap_shared(), epsrel, covar.unwrap_unique())
+        };
+        result_handler!(ret, covar)
+    }
+
+    /// Returns the current best-fit parameters.
+    #[doc(alias = "gsl_multifit_nlinear_position")]
+    pub fn position(&self) -> View<'_, VectorF64> {
+        unsafe { View::new(sys::gsl_multifit_nlinear_position(self.unwrap_shared())) }
+    }
+
+    /// Returns the residual vector `f(x)` at the current position.
+    #[doc(alias = "gsl_multifit_nlinear_residual")]
+    pub fn residual(&self) -> View<'_, VectorF64> {
+        unsafe { View::new(sys::gsl_multifit_nlinear_residual(self.unwrap_shared())) }
+    }
+
+    /// Returns the Jacobian matrix at the current position.
+    #[doc(alias = "gsl_multifit_nlinear_jac")]
+    pub fn jac(&self) -> View<'_, MatrixF64> {
+        unsafe { View::new(sys::gsl_multifit_nlinear_jac(self.unwrap_shared())) }
+    }
+
+    /// Returns the number of iterations performed so far.
+    #[doc(alias = "gsl_multifit_nlinear_niter")]
+    pub fn niter(&self) -> usize {
+        unsafe { sys::gsl_multifit_nlinear_niter(self.unwrap_shared()) }
+    }
+
+    /// Returns the reciprocal condition number of the Jacobian at the current position.
+    #[doc(alias = "gsl_multifit_nlinear_rcond")]
+    pub fn rcond(&self) -> Result<f64, Value> {
+        let mut rcond = 0.;
+        let ret = unsafe { sys::gsl_multifit_nlinear_rcond(&mut rcond, self.unwrap_shared()) };
+        result_handler!(ret, rcond)
+    }
+
+    /// Returns the ratio `|a| / |v|` of the acceleration to the velocity of the last step. It is
+    /// only meaningful with [`MultiFitNlinearTrs::lm_accel`].
+    #[doc(alias = "gsl_multifit_nlinear_avratio")]
+    pub fn avratio(&self) -> f64 {
+        unsafe { sys::gsl_multifit_nlinear_avratio(self.unwrap_shared()) }
+    }
+
+    /// Returns the name of the solver type.
+    #[doc(alias = "gsl_multifit_nlinear_name")]
+    pub fn name(&self) -> String {
+        unsafe {
+            let tmp = sys::gsl_multifit_nlinear_name(self.unwrap_shared());
+            String::from_utf8_lossy(CStr::from_ptr(tmp).to_bytes()).to_string()
+        }
+    }
+
+    /// Returns the name of the trust region subproblem method.
+    #[doc(alias = "gsl_multifit_nlinear_trs_name")]
+    pub fn trs_name(&self) -> String {
+        unsafe {
+            let tmp = sys::gsl_multifit_nlinear_trs_name(self.unwrap_shared());
+            String::from_utf8_lossy(CStr::from_ptr(tmp).to_bytes()).to_string()
+        }
+    }
+
+    /// Returns the number of evaluations of the residual function, of the Jacobian and of `fvv`
+    /// since the last call to [`init`](Self::init) or [`winit`](Self::winit).
+    pub fn nevals(&self) -> (usize, usize, usize) {
+        match self.fdf {
+            Some(ref s) => (s.raw.nevalf, s.raw.nevaldf, s.raw.nevalfvv),
+            None => (0, 0, 0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Residuals of y = A exp(-lambda t) + b for exact data with A = 5, lambda = 0.1 and b = 1.
+    fn exponential<'a>(n: usize) -> MultiFitNlinearFdf<'a> {
+        MultiFitNlinearFdf::new(n, 3, move |x: &VectorF64, f: &mut VectorF64| {
+            let (a, lambda, b) = (x.get(0), x.get(1), x.get(2));
+            for i in 0..n {
+                let t = i as f64;
+                f.set(
+                    i,
+                    a * (-lambda * t).exp() + b - (5. * (-0.1 * t).exp() + 1.),
+                );
+            }
+            Value::Success
+        })
+    }
+
+    #[test]
+    fn test_nlinear_driver() {
+        let n = 40;
+        let params = MultiFitNlinearParameters::default();
+        let mut w =
+            MultiFitNlinearWorkspace::new(MultiFitNlinearType::trust(), &params, n, 3).unwrap();
+        let x0 = VectorF64::from_slice(&[1., 0., 0.]).unwrap();
+
+        // Nothing can be done before a successful initialization.
+        assert_eq!(w.iterate(), Err(Value::Invalid));
+        assert_eq!(w.test(1e-8, 1e-8, 0.), Err(Value::Invalid));
+        assert_eq!(w.driver(100, 1e-8, 1e-8, 0., None), Err(Value::Invalid));
+        assert_eq!(w.init(&x0, exponential(n + 1)), Err(Value::BadLength));
+        assert_eq!(w.iterate(), Err(Value::Invalid));
+
+        w.init(&x0, exponential(n)).unwrap();
+        let mut calls = 0;
+        let info = w
+            .driver(
+                100,
+                1e-8,
+                1e-8,
+                0.,
+                Some(&mut |iter, w: &MultiFitNlinearWorkspace<'_>| {
+                    assert_eq!(iter, calls);
+                    assert_eq!(w.position().len(), 3);
+                    calls += 1;
+                }),
+            )
+            .unwrap();
+        assert!((1..=3).contains(&info));
+        assert_eq!(w.test(1e-8, 1e-8, 0.), Ok(info));
+        assert_eq!(calls, w.niter() + 1);
+        assert!(w.nevals().0 > 0);
+        let x = w.position();
+        assert!((x.get(0) - 5.).abs() < 1e-6);
+        assert!((x.get(1) - 0.1).abs() < 1e-6);
+        assert!((x.get(2) - 1.).abs() < 1e-6);
+
+        let covar = w.covar(0.).unwrap();
+        assert_eq!((covar.size1(), covar.size2()), (3, 3));
+        assert!(covar.get(0, 0) > 0.);
+
+        // A failed re-initialization keeps the previous system.
+        let short = VectorF64::from_slice(&[1., 0.]).unwrap();
+        assert_eq!(w.init(&short, exponential(n)), Err(Value::BadLength));
+        let wts = VectorF64::new(n - 1).unwrap();
+        assert_eq!(w.winit(&x0, &wts, exponential(n)), Err(Value::BadLength));
+        w.iterate().unwrap();
+
+        // A failing evaluation of the residuals leaves the workspace uninitialized.
+        let failing =
+            MultiFitNlinearFdf::new(n, 3, |_: &VectorF64, _: &mut VectorF64| Value::BadFunction);
+        assert!(w.init(&x0, failing).is_err());
+        assert_eq!(w.iterate(), Err(Value::Invalid));
+
+        let mut wts = VectorF64::new(n).unwrap();
+        wts.set_all(1.);
+        w.winit(&x0, &wts, exponential(n)).unwrap();
+        assert_eq!(w.driver(1, 1e-8, 1e-8, 0., None), Err(Value::MaxIteration));
+    }
+}
@@ -214,7 +214,7 @@ int main(void) {
 */
 
 use crate::ffi::{self, FFI};
-use crate::{Value, VectorF64, View};
+use crate::{MatrixF64, Value, VectorF64, View};
 use std::os::raw::{c_int, c_void};
 
 ffi_wrapper!(MultiFitFSolverType, *mut sys::gsl_multifit_fsolver_type);
@@ -395,6 +395,83 @@ impl MultiFitFdfSolver {
             Ok(())
         }
     }
+
+    /// Returns the current residual vector `f(x)`.
+    #[doc(alias = "gsl_multifit_fdfsolver_residual")]
+    pub fn residual(&self) -> View<'_, VectorF64> {
+        unsafe { View::new(sys::gsl_multifit_fdfsolver_residual(self.unwrap_shared())) }
+    }
+
+    /// Returns the number of iterations performed so far.
+    #[doc(alias = "gsl_multifit_fdfsolver_niter")]
+    pub fn niter(&self) -> usize {
+        unsafe { sys::gsl_multifit_fdfsolver_niter(self.unwrap_shared()) }
+    }
+
+    /// Stores the Jacobian matrix at the current position in `J`, which must be `n`-by-`p`.
+    #[doc(alias = "gsl_multifit_fdfsolver_jac")]
+    pub fn jac(&mut self, J: &mut MatrixF64) -> Result<(), Value> {
+        let ret =
+            unsafe { sys::gsl_multifit_fdfsolver_jac(self.unwrap_unique(), J.unwrap_unique()) };
+        result_handler!(ret, ())
+    }
+
+    /// Tests for convergence of the iteration. The test succeeds when the step is small
+    /// (`|dx_i| <= xtol (|x_i| + xtol)`, returns `Ok(1)`), the gradient is small
+    /// (`max_i |g_i × max(x_i, 1)| <= gtol × max(||f||^2 / 2, 1)`, returns `Ok(2)`) or the
+    /// relative change in the residual norm is small (`ftol`, returns `Ok(3)`).
+    ///
+    /// Otherwise `Err(Value::Continue)` is returned.
+    #[doc(alias = "gsl_multifit_fdfsolver_test")]
+    pub fn test(&self, xtol: f64, gtol: f64, ftol: f64) -> Result<i32, Value> {
+        let mut info = 0;
+        let ret = unsafe {
+            sys::gsl_multifit_fdfsolver_test(self.unwrap_shared(), xtol, gtol, ftol, &mut info)
+        };
+        result_handler!(ret, info)
+    }
+
+    /// Iterates the solver for at most `max_iter` iterations until [`test`](Self::test) with the
+    /// tolerances `xtol`, `gtol` and `ftol` succeeds, calling `callback(iter, x, gnorm, fnorm)`
+    /// after each iteration with the current parameters `x`, the norm of the gradient
+    /// `g = J^T f` and the norm of the residuals `f`.
+    ///
+    /// The iteration is aborted if `callback` returns `false`, in which case `Value::Continue`
+    /// is returned. `Value::MaxIteration` is returned if the convergence was not reached after
+    /// `max_iter` iterations. On success, the reason for convergence is returned (see
+    /// [`test`](Self::test)).
+    pub fn driver_with_callback<F: FnMut(usize, &VectorF64, f64, f64) -> bool>(
+        &mut self,
+        max_iter: usize,
+        xtol: f64,
+        gtol: f64,
+        ftol: f64,
+        mut callback: F,
+    ) -> Result<i32, Value> {
+        let n = self.residual().len();
+        let p = self.position().len();
+        let mut J = MatrixF64::new(n, p).ok_or(Value::NoMemory)?;
+        let mut g = VectorF64::new(p).ok_or(Value::NoMemory)?;
+
+        for iter in 1..=max_iter {
+            self.iterate()?;
+
+            self.jac(&mut J)?;
+            let f = self.residual();
+            crate::multifit::gradient(&J, &f, &mut g)?;
+            let fnorm = crate::blas::level1::dnrm2(&f);
+            let gnorm = crate::blas::level1::dnrm2(&g);
+            if !callback(iter, &self.position(), gnorm, fnorm) {
+                return Err(Value::Continue);
+            }
+
+            match self.test(xtol, gtol, ftol) {
+                Err(Value::Continue) => {}
+                ret => return ret,
+            }
+        }
+        Err(Value::MaxIteration)
+    }
 }
 
 ffi_wrapper!(
@@ -506,3 +583,84 @@ unsafe extern "C" fn fdf(
         Value::Success.into()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const N: usize = 40;
+
+    // Fits y = A exp(-lambda t) + b to exact data with A = 5, lambda = 0.1 and b = 1.
+    fn exponential() -> MultiFitFunctionFdf {
+        let mut fdf = MultiFitFunctionFdf::new(N, 3, 0, 0);
+        fdf.f = Some(Box::new(|x: VectorF64, mut f: VectorF64| {
+            let (a, lambda, b) = (x.get(0), x.get(1), x.get(2));
+            for i in 0..N {
+                let t = i as f64;
+                f.set(
+                    i,
+                    a * (-lambda * t).exp() + b - (5. * (-0.1 * t).exp() + 1.),
+                );
+            }
+            Value::Success
+        }));
+        fdf.df = Some(Box::new(|x: VectorF64, mut J: MatrixF64| {
+            let (a, lambda) = (x.get(0), x.get(1));
+            for i in 0..N {
+                let t = i as f64;
+                let e = (-lambda * t).exp();
+                J.set(i, 0, e);
+                J.set(i, 1, -t * a * e);
+                J.set(i, 2, 1.);
+            }
+            Value::Success
+        }));
+        fdf
+    }
+
+    #[test]
+    fn test_driver_with_callback() {
+        let mut fdf = exponential();
+        let x0 = VectorF64::from_slice(&[1., 0., 0.]).unwrap();
+        let mut s = MultiFitFdfSolver::new(&MultiFitFdfSolverType::lmsder(), N, 3).unwrap();
+
+        s.set(&mut fdf, &x0).unwrap();
+        let mut calls = 0;
+        let info = s
+            .driver_with_callback(100, 1e-8, 1e-8, 0., |iter, x, gnorm, fnorm| {
+                calls += 1;
+                assert_eq!(iter, calls);
+                assert_eq!(x.len(), 3);
+                assert!(gnorm >= 0. && fnorm >= 0.);
+                true
+            })
+            .unwrap();
+        assert!((1..=3).contains(&info));
+        assert_eq!(s.test(1e-8, 1e-8, 0.), Ok(info));
+        assert_eq!(s.niter(), calls);
+        let x = s.position();
+        assert!((x.get(0) - 5.).abs() < 1e-6);
+        assert!((x.get(1) - 0.1).abs() < 1e-6);
+        assert!((x.get(2) - 1.).abs() < 1e-6);
+        assert!(crate::blas::level1::dnrm2(&s.residual()) < 1e-6);
+
+        let mut J = MatrixF64::new(N, 3).unwrap();
+        s.jac(&mut J).unwrap();
+        assert!((J.get(0, 0) - 1.).abs() < 1e-12);
+        assert!((J.get(0, 2) - 1.).abs() < 1e-12);
+
+        // The callback aborts the iterations.
+        s.set(&mut fdf, &x0).unwrap();
+        assert_eq!(
+            s.driver_with_callback(100, 1e-8, 1e-8, 0., |_, _, _, _| false),
+            Err(Value::Continue)
+        );
+        assert_eq!(s.niter(), 1);
+
+        s.set(&mut fdf, &x0).unwrap();
+        assert_eq!(
+            s.driver_with_callback(1, 1e-8, 1e-8, 0., |_, _, _, _| true),
+            Err(Value::MaxIteration)
+        );
+    }
+}
@@ -67,7 +67,9 @@ You must provide a parametric function of n variables for the minimizers to oper
 */
 
 use crate::ffi::FFI;
-use crate::{Value, VectorF64, View};
+use crate::types::fdf::{Callbacks, Fdf};
+use crate::{MinimizerTrace, Value, VectorF64, View};
+use std::cell::RefCell;
 use sys::libc::c_void;
 
 ffi_wrapper!(
@@ -111,13 +113,15 @@ impl<'a> Minimizer<'a> {
             let x_new = VectorF64::soft_wrap(x as *const _ as *mut _);
             f(&x_new)
         }
+        // GSL keeps a pointer to the closure, so it must not move once boxed.
+        let f = Box::new(f);
         self.inner_call = sys::gsl_multimin_function_struct {
             f: Some(inner_f::<F>),
             n: x.len(),
-            params: &f as *const _ as *mut _,
+            params: &*f as *const F as *mut _,
         };
 
-        self.inner_closure = Some(Box::new(f));
+        self.inner_closure = Some(f);
 
         let ret = unsafe {
             sys::gsl_multimin_fminimizer_set(
@@ -172,11 +176,89 @@ impl<'a> Minimizer<'a> {
         let ret = unsafe { sys::gsl_multimin_fminimizer_iterate(self.unwrap_unique()) };
         result_handler!(ret, ())
     }
+
+    /// Appends the current state of the minimizer to `trace`: the best point, the value of the
+    /// function there and, as step size, the size of the simplex. The gradient norm is not
+    /// available and is recorded as NaN.
+    ///
+    /// It is typically called from the `callback` of [`minimize`](Self::minimize).
+    pub fn record(&self, trace: &mut MinimizerTrace) {
+        let x = self.x();
+        trace.push(
+            (0..x.len()).map(|i| x.get(i)),
+            self.minimum(),
+            f64::NAN,
+            self.size(),
+        );
+    }
+
+    /// Minimizes the black-box function `f` starting from `x`, with initial trial steps
+    /// `step_size` (see [`set`](Self::set)).
+    ///
+    /// After each iteration, `callback` is called with the iteration number (starting at 1) and
+    /// the minimizer, from which the simplex [`size`](Self::size), the best point
+    /// [`x`](Self::x) and its value [`minimum`](Self::minimum) can be read. The iterations stop
+    /// as soon as `converged` returns `true`. The usual test is that the size of the simplex is
+    /// smaller than a tolerance, as checked by
+    /// [`multimin::test_size`](crate::multimin::test_size).
+    ///
+    /// Returns the number of iterations performed, [`Value::MaxIteration`] if `converged` did
+    /// not return `true` within `max_iter` iterations or the error of the failed iteration.
+    ///
+    /// ```
+    /// use rgsl::types::multimin::{Minimizer, MinimizerType};
+    /// use rgsl::{multimin, Value, VectorF64};
+    ///
+    /// // f(x, y) = (x - 1)^2 + 10 (y - 2)^2
+    /// let f = |v: &VectorF64| (v.get(0) - 1.).powi(2) + 10. * (v.get(1) - 2.).powi(2);
+    ///
+    /// let mut s = Minimizer::new(MinimizerType::nm_simplex2(), 2).unwrap();
+    /// let x = VectorF64::from_slice(&[5., 7.]).unwrap();
+    /// let step_size = VectorF64::from_slice(&[1., 1.]).unwrap();
+    /// s.minimize(
+    ///     f,
+    ///     &x,
+    ///     &step_size,
+    ///     1000,
+    ///     |s| multimin::test_size(s.size(), 1e-6) == Value::Success,
+    ///     |iter, s| println!("{iter}: size = {:e}, f = {}", s.size(), s.minimum()),
+    /// )
+    /// .unwrap();
+    /// assert!((s.x().get(0) - 1.).abs() < 1e-5);
+    /// ```
+    pub fn minimize<F, T, C>(
+        &mut self,
+        f: F,
+        x: &VectorF64,
+        step_size: &VectorF64,
+        max_iter: usize,
+        mut converged: T,
+        mut callback: C,
+    ) -> Result<usize, Value>
+    where
+        F: Fn(&VectorF64) -> f64 + 'a,
+        T: FnMut(&Self) -> bool,
+        C: FnMut(usize, &Self),
+    {
+        self.set(f, x, step_size)?;
+        for iter in 1..=max_iter {
+            let status = self.iterate();
+            callback(iter, self);
+            // An iteration making no progress at the minimum is not an error.
+            if converged(self) {
+                return Ok(iter);
+            }
+            status?;
+        }
+        Err(Value::MaxIteration)
+    }
 }
 
 ffi_wrapper!(MinimizerType, *const sys::gsl_multimin_fminimizer_type);
 
 impl MinimizerType {
+    /// The Simplex algorithm of Nelder and Mead, keeping track of the size of the simplex at
+    /// each iteration in O(N) operations, which is the most efficient choice in most cases.
     #[doc(alias = "gsl_multimin_fminimizer_nmsimplex2")]
     pub fn nm_simplex2() -> Self {
         ffi_wrap!(gsl_multimin_fminimizer_nmsimplex2)
@@ -187,12 +269,609 @@ impl MinimizerType {
         ffi_wrap!(gsl_multimin_fminimizer_nmsimplex)
     }
 
+    /// A variant of [`nm_simplex2`](Self::nm_simplex2) which initialises the simplex around
+    /// the starting point using a randomly-oriented set of basis vectors instead of the fixed
+    /// coordinate axes, which can help with functions having a poor alignment with the axes.
     #[doc(alias = "gsl_multimin_fminimizer_nmsimplex2rand")]
     pub fn nm_simplex2_rand() -> Self {
         ffi_wrap!(gsl_multimin_fminimizer_nmsimplex2rand)
     }
 }
 
+ffi_wrapper!(
+    FdfMinimizerType,
+    *const sys::gsl_multimin_fdfminimizer_type,
+    "Minimization algorithms which make use of the gradient of the function."
+);
+
+impl FdfMinimizerType {
+    /// The Fletcher-Reeves conjugate gradient algorithm. It proceeds as a succession of line
+    /// minimizations, the sequence of search directions being used to build up an approximation
+    /// to the curvature of the function in the neighborhood of the minimum.
+    #[doc(alias = "gsl_multimin_fdfminimizer_conjugate_fr")]
+    pub fn conjugate_fr() -> Self {
+        ffi_wrap!(gsl_multimin_fdfminimizer_conjugate_fr)
+    }
+
+    /// The Polak-Ribiere conjugate gradient algorithm. It is similar to the Fletcher-Reeves
+    /// method, differing only in the choice of the coefficient used to compute the new search
+    /// direction.
+    #[doc(alias = "gsl_multimin_fdfminimizer_conjugate_pr")]
+    pub fn conjugate_pr() -> Self {
+        ffi_wrap!(gsl_multimin_fdfminimizer_conjugate_pr)
+    }
+
+    /// The vector Broyden-Fletcher-Goldfarb-Shanno (BFGS) algorithm, a quasi-Newton method which
+    /// builds up an approximation to the second derivatives of the function using the
+    /// difference between successive gradient vectors.
+    #[doc(alias = "gsl_multimin_fdfminimizer_vector_bfgs")]
+    pub fn vector_bfgs() -> Self {
+        ffi_wrap!(gsl_multimin_fdfminimizer_vector_bfgs)
+    }
+
+    /// A more efficient version of [`vector_bfgs`](Self::vector_bfgs), using the line
+    /// minimization algorithm of Fletcher. It is the most efficient of the gradient algorithms
+    /// in most cases.
+    #[doc(alias = "gsl_multimin_fdfminimizer_vector_bfgs2")]
+    pub fn vector_bfgs2() -> Self {
+        ffi_wrap!(gsl_multimin_fdfminimizer_vector_bfgs2)
+    }
+
+    /// The steepest descent algorithm, which follows the downhill gradient of the function at
+    /// each step. It is inefficient and is mainly useful for demonstration purposes.
+    #[doc(alias = "gsl_multimin_fdfminimizer_steepest_descent")]
+    pub fn steepest_descent() -> Self {
+        ffi_wrap!(gsl_multimin_fdfminimizer_steepest_descent)
+    }
+}
+
+// `gsl_multimin_function_fdf` is opaque in the generated bindings.
+#[repr(C)]
+struct RawFunctionFdf {
+    f: Option<unsafe extern "C" fn(*const sys::gsl_vector, *mut c_void) -> f64>,
+    df: Option<unsafe extern "C" fn(*const sys::gsl_vector, *mut c_void, *mut sys::gsl_vector)>,
+    fdf: Option<
+        unsafe extern "C" fn(*const sys::gsl_vector, *mut c_void, *mut f64, *mut sys::gsl_vector),
+    >,
+    n: usize,
+    params: *mut c_void,
+}
+
+ffi_wrapper!(
+    FdfMinimizer<'a>,
+    *mut sys::gsl_multimin_fdfminimizer,
+    gsl_multimin_fdfminimizer_free
+    ;inner_call: RawFunctionFdf => RawFunctionFdf { f: None, df: None, fdf: None, n: 0, params: std::ptr::null_mut() };
+    ;inner_closure: Option<Box<dyn Callbacks + 'a>> => None;,
+    "This is a workspace for minimizing functions using their gradient."
+);
+
+impl<'a> FdfMinimizer<'a> {
+    /// Creates a minimizer of type `t` for an n-dimensional function.
+    #[doc(alias = "gsl_multimin_fdfminimizer_alloc")]
+    pub fn new(t: FdfMinimizerType, n: usize) -> Option<FdfMinimizer<'a>> {
+        let ptr = unsafe { sys::gsl_multimin_fdfminimizer_alloc(t.unwrap_shared(), n) };
+
+        if ptr.is_null() {
+            None
+        } else {
+            Some(Self::wrap(ptr))
+        }
+    }
+
+    /// This function initializes the minimizer to minimize the function `f` starting from the
+    /// initial point `x`. `df` stores the gradient of `f` at its first argument into its second
+    /// one, and `fdf` does both at once, returning the value of the function (which is often
+    /// cheaper than calling `f` and `df`).
+    ///
+    /// The size of the first trial step is given by `step_size`. The accuracy of the line
+    /// minimization is specified by `tol`. The precise meaning of this parameter depends on the
+    /// method used. Typically the line minimization is considered successful if the gradient
+    /// of the function g is orthogonal to the current search direction p to a relative accuracy
+    /// of `tol`, where p·g < tol |p| |g|. A `tol` value of 0.1 is suitable for most purposes,
+    /// since line minimization only needs to be carried out approximately.
+    #[doc(alias = "gsl_multimin_fdfminimizer_set")]
+    pub fn set<F, DF, FDF>(
+        &mut self,
+        f: F,
+        df: DF,
+        fdf: FDF,
+        x: &VectorF64,
+        step_size: f64,
+        tol: f64,
+    ) -> Result<(), Value>
+    where
+        F: Fn(&VectorF64) -> f64 + 'a,
+        DF: Fn(&VectorF64, &mut VectorF64) + 'a,
+        FDF: Fn(&VectorF64, &mut VectorF64) -> f64 + 'a,
+    {
+        unsafe extern "C" fn inner_f<F: Fn(&VectorF64) -> f64, DF, FDF>(
+            x: *const sys::gsl_vector,
+            params: *mut c_void,
+        ) -> f64 {
+            let t = Fdf::<F, DF, FDF>::from_params(params);
+            (t.f)(&VectorF64::soft_wrap(x as *mut _))
+        }
+
+        unsafe extern "C" fn inner_df<F, DF: Fn(&VectorF64, &mut VectorF64), FDF>(
+            x: *const sys::gsl_vector,
+            params: *mut c_void,
+            g: *mut sys::gsl_vector,
+        ) {
+            let t = Fdf::<F, DF, FDF>::from_params(params);
+            (t.df)(
+                &VectorF64::soft_wrap(x as *mut _),
+                &mut VectorF64::soft_wrap(g),
+            )
+        }
+
+        unsafe extern "C" fn inner_fdf<F, DF, FDF: Fn(&VectorF64, &mut VectorF64) -> f64>(
+            x: *const sys::gsl_vector,
+            params: *mut c_void,
+            f: *mut f64,
+            g: *mut sys::gsl_vector,
+        ) {
+            let t = Fdf::<F, DF, FDF>::from_params(params);
+            *f = (t.fdf)(
+                &VectorF64::soft_wrap(x as *mut _),
+                &mut VectorF64::soft_wrap(g),
+            );
+        }
+
+        let (callbacks, params) = Fdf::boxed(f, df, fdf);
+        self.inner_call = RawFunctionFdf {
+            f: Some(inner_f::<F, DF, FDF>),
+            df: Some(inner_df::<F, DF, FDF>),
+            fdf: Some(inner_fdf::<F, DF, FDF>),
+            n: x.len(),
+            params,
+        };
+        self.inner_closure = Some(callbacks);
+
+        let ret = unsafe {
+            sys::gsl_multimin_fdfminimizer_set(
+                self.unwrap_unique(),
+                &mut self.inner_call as *mut RawFunctionFdf as *mut _,
+                x.unwrap_shared(),
+                step_size,
+                tol,
+            )
+        };
+        result_handler!(ret, ())
+    }
+
+    /// This function returns the name of the minimizer.
+    #[doc(alias = "gsl_multimin_fdfminimizer_name")]
+    pub fn name(&self) -> String {
+        unsafe {
+            let tmp = sys::gsl_multimin_fdfminimizer_name(self.unwrap_shared());
+
+            String::from_utf8_lossy(std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()
+        }
+    }
+
+    /// This function performs a single iteration of the minimizer. The error code
+    /// `Value::NoProgress` signifies that the minimizer is unable to improve on its current
+    /// estimate, either due to numerical difficulty or because a genuine local minimum has been
+    /// reached.
+    #[doc(alias = "gsl_multimin_fdfminimizer_iterate")]
+    pub fn iterate(&mut self) -> Result<(), Value> {
+        let ret = unsafe { sys::gsl_multimin_fdfminimizer_iterate(self.unwrap_unique()) };
+        result_handler!(ret, ())
+    }
+
+    /// This function resets the minimizer to use the current point as a new starting point.
+    #[doc(alias = "gsl_multimin_fdfminimizer_restart")]
+    pub fn restart(&mut self) -> Result<(), Value> {
+        let ret = unsafe { sys::gsl_multimin_fdfminimizer_restart(self.unwrap_unique()) };
+        result_handler!(ret, ())
+    }
+
+    /// Returns the current best estimate of the location of the minimum.
+    #[doc(alias = "gsl_multimin_fdfminimizer_x")]
+    pub fn x(&self) -> View<'_, VectorF64> {
+        unsafe { View::new(sys::gsl_multimin_fdfminimizer_x(self.unwrap_shared())) }
+    }
+
+    /// Returns the last step taken by the minimizer.
+    #[doc(alias = "gsl_multimin_fdfminimizer_dx")]
+    pub fn dx(&self) -> View<'_, VectorF64> {
+        unsafe { View::new(sys::gsl_multimin_fdfminimizer_dx(self.unwrap_shared())) }
+    }
+
+    /// Returns the gradient of the function at the current best estimate of the minimum.
+    #[doc(alias = "gsl_multimin_fdfminimizer_gradient")]
+    pub fn gradient(&self) -> View<'_, VectorF64> {
+        unsafe {
+            View::new(sys::gsl_multimin_fdfminimizer_gradient(
+                self.unwrap_shared(),
+            ))
+        }
+    }
+
+    /// Returns the value of the function at the current best estimate of the minimum.
+    #[doc(alias = "gsl_multimin_fdfminimizer_minimum")]
+    pub fn minimum(&self) -> f64 {
+        unsafe { sys::gsl_multimin_fdfminimizer_minimum(self.unwrap_shared()) }
+    }
+
+    /// Appends the current state of the minimizer to `trace`: the best estimate of the minimum,
+    /// the value of the function and the norm of the gradient there, and the norm of the last
+    /// step.
+    pub fn record(&self, trace: &mut MinimizerTrace) {
+        let x = self.x();
+        trace.push(
+            (0..x.len()).map(|i| x.get(i)),
+            self.minimum(),
+            crate::blas::level1::dnrm2(&self.gradient()),
+            crate::blas::level1::dnrm2(&self.dx()),
+        );
+    }
+
+    /// Minimizes `f` starting from `x`, see [`set`](Self::set) for the meaning of the
+    /// functions. The minimizer is iterated until the norm of the gradient is smaller than
+    /// `params.epsabs`, as checked by
+    /// [`multimin::test_gradient`](crate::multimin::test_gradient), and the location of the
+    /// minimum is then given by [`x`](Self::x).
+    ///
+    /// Returns the number of iterations performed, [`Value::MaxIteration`] if the minimum was
+    /// not found in `params.max_iter` iterations or the error of the failed iteration.
+    ///
+    /// ```
+    /// use rgsl::types::multimin::{FdfMinimizer, FdfMinimizerParams, FdfMinimizerType};
+    /// use rgsl::VectorF64;
+    ///
+    /// // f(x, y) = (x - 1)^2 + 10 (y - 2)^2
+    /// let f = |v: &VectorF64| (v.get(0) - 1.).powi(2) + 10. * (v.get(1) - 2.).powi(2);
+    /// let df = |v: &VectorF64, g: &mut VectorF64| {
+    ///     g.set(0, 2. * (v.get(0) - 1.));
+    ///     g.set(1, 20. * (v.get(1) - 2.));
+    /// };
+    /// let fdf = |v: &VectorF64, g: &mut VectorF64| {
+    ///     df(v, g);
+    ///     f(v)
+    /// };
+    ///
+    /// let mut s = FdfMinimizer::new(FdfMinimizerType::vector_bfgs2(), 2).unwrap();
+    /// let x = VectorF64::from_slice(&[5., 7.]).unwrap();
+    /// s.minimize(f, df, fdf, &x, &FdfMinimizerParams::default().epsabs(1e-6)).unwrap();
+    /// assert!((s.x().get(0) - 1.).abs() < 1e-5);
+    /// ```
+    pub fn minimize<F, DF, FDF>(
+        &mut self,
+        f: F,
+        df: DF,
+        fdf: FDF,
+        x: &VectorF64,
+        params: &FdfMinimizerParams,
+    ) -> Result<usize, Value>
+    where
+        F: Fn(&VectorF64) -> f64 + 'a,
+        DF: Fn(&VectorF64, &mut VectorF64) + 'a,
+        FDF: Fn(&VectorF64, &mut VectorF64) -> f64 + 'a,
+    {
+        self.minimize_impl(f, df, fdf, x, params, None)
+    }
+
+    /// Same as [`minimize`](Self::minimize), recording the starting point and the state after
+    /// each iteration into `trace`, which is cleared first.
+    pub fn minimize_traced<F, DF, FDF>(
+        &mut self,
+        f: F,
+        df: DF,
+        fdf: FDF,
+        x: &VectorF64,
+        params: &FdfMinimizerParams,
+        trace: &mut MinimizerTrace,
+    ) -> Result<usize, Value>
+    where
+        F: Fn(&VectorF64) -> f64 + 'a,
+        DF: Fn(&VectorF64, &mut VectorF64) + 'a,
+        FDF: Fn(&VectorF64, &mut VectorF64) -> f64 + 'a,
+    {
+        trace.clear();
+        self.minimize_impl(f, df, fdf, x, params, Some(trace))
+    }
+
+    fn minimize_impl<F, DF, FDF>(
+        &mut self,
+        f: F,
+        df: DF,
+        fdf: FDF,
+        x: &VectorF64,
+        params: &FdfMinimizerParams,
+        mut trace: Option<&mut MinimizerTrace>,
+    ) -> Result<usize, Value>
+    where
+        F: Fn(&VectorF64) -> f64 + 'a,
+        DF: Fn(&VectorF64, &mut VectorF64) + 'a,
+        FDF: Fn(&VectorF64, &mut VectorF64) -> f64 + 'a,
+    {
+        self.set(f, df, fdf, x, params.step_size, params.tol)?;
+        if let Some(trace) = trace.as_deref_mut() {
+            self.record(trace);
+        }
+        for iter in 1..=params.max_iter {
+            let status = self.iterate();
+            if let Some(trace) = trace.as_deref_mut() {
+                self.record(trace);
+            }
+            // An iteration making no progress at the minimum is not an error.
+            if crate::multimin::test_gradient(&self.gradient(), params.epsabs) == Value::Success {
+                return Ok(iter);
+            }
+            status?;
+        }
+        Err(Value::MaxIteration)
+    }
+}
+
+/// Parameters of [`FdfMinimizer::minimize`], built from the default values by chaining the
+/// setters.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FdfMinimizerParams {
+    step_size: f64,
+    tol: f64,
+    epsabs: f64,
+    max_iter: usize,
+}
+
+impl Default for FdfMinimizerParams {
+    fn default() -> FdfMinimizerParams {
+        FdfMinimizerParams {
+            step_size: 0.01,
+            tol: 0.1,
+            epsabs: 1e-3,
+            max_iter: 100,
+        }
+    }
+}
+
+impl FdfMinimizerParams {
+    /// Sets the size of the first trial step. The default value is 0.01.
+    pub fn step_size(mut self, step_size: f64) -> Self {
+        self.step_size = step_size;
+        self
+    }
+
+    /// Sets the accuracy of the line minimizations, see [`FdfMinimizer::set`]. The default
+    /// value is 0.1.
+    pub fn tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    /// Sets the norm of the gradient below which the minimum is considered found. The default
+    /// value is 1e-3.
+    pub fn epsabs(mut self, epsabs: f64) -> Self {
+        self.epsabs = epsabs;
+        self
+    }
+
+    /// Sets the maximum number of iterations. The default value is 100.
+    pub fn max_iter(mut self, max_iter: usize) -> Self {
+        self.max_iter = max_iter;
+        self
+    }
+}
+
+/// Constraint on a single parameter, see [`Bounds`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Bound {
+    /// The parameter is unconstrained.
+    Free,
+    /// The parameter must be greater than the given value.
+    Lower(f64),
+    /// The parameter must be less than the given value.
+    Upper(f64),
+    /// The parameter must lie in the open interval `(a, b)`.
+    Interval(f64, f64),
+}
+
+impl Bound {
+    // Maps an internal variable to the constrained parameter.
+    fn to_external(self, u: f64) -> f64 {
+        match self {
+            Bound::Free => u,
+            Bound::Lower(a) => a + u.exp(),
+            Bound::Upper(b) => b - u.exp(),
+            Bound::Interval(a, b) => a + (b - a) / (1. + (-u).exp()),
+        }
+    }
+
+    // Inverse of `to_external`, defined for parameters strictly inside the bounds.
+    fn to_internal(self, x: f64) -> Result<f64, Value> {
+        match self {
+            Bound::Free => Ok(x),
+            Bound::Lower(a) if x > a => Ok((x - a).ln()),
+            Bound::Upper(b) if x < b => Ok((b - x).ln()),
+            Bound::Interval(a, b) if a < x && x < b => Ok(((x - a) / (b - x)).ln()),
+            _ => Err(Value::Domain),
+        }
+    }
+
+    // Derivative of `to_external` at `u`.
+    fn derivative(self, u: f64) -> f64 {
+        match self {
+            Bound::Free => 1.,
+            Bound::Lower(_) => u.exp(),
+            Bound::Upper(_) => -u.exp(),
+            Bound::Interval(a, b) => {
+                let s = 1. / (1. + (-u).exp());
+                (b - a) * s * (1. - s)
+            }
+        }
+    }
+}
+
+/// Box constraints for the multidimensional minimizers.
+///
+/// The minimizers of this module search the whole of R^n and may wander outside of the physical
+/// range of the parameters. Each bounded parameter x is therefore written as a function of an
+/// unbounded internal variable u: x = a + e^u for a lower bound a, x = b - e^u for an upper bound
+/// b and the logistic function x = a + (b - a) / (1 + e^-u) for an interval (a, b). The
+/// minimizers work on u through the functions returned by [`wrap_f`](Self::wrap_f),
+/// [`wrap_df`](Self::wrap_df) and [`wrap_fdf`](Self::wrap_fdf), the gradient being transformed
+/// by the chain rule, and the result is mapped back with [`to_external`](Self::to_external).
+///
+/// Note that a minimum lying on a bound is only reached asymptotically, as u goes to infinity.
+///
+/// ```
+/// use rgsl::types::multimin::{Bound, Bounds, Minimizer, MinimizerType};
+/// use rgsl::{multimin, Value, VectorF64};
+///
+/// // Minimum at (-1, 2), outside of the allowed range for the first parameter.
+/// let f = |v: &VectorF64| (v.get(0) + 1.).powi(2) + (v.get(1) - 2.).powi(2);
+/// let bounds = Bounds::new(&[Bound::Interval(0., 10.), Bound::Free]).unwrap();
+///
+/// let mut s = Minimizer::new(MinimizerType::nm_simplex2(), 2).unwrap();
+/// let u = bounds.to_internal(&VectorF64::from_slice(&[5., 5.]).unwrap()).unwrap();
+/// let step_size = VectorF64::from_slice(&[1., 1.]).unwrap();
+/// s.minimize(
+///     bounds.wrap_f(f).unwrap(),
+///     &u,
+///     &step_size,
+///     1000,
+///     |s| multimin::test_size(s.size(), 1e-6) == Value::Success,
+///     |_, _| {},
+/// )
+/// .unwrap();
+/// let x = bounds.to_external(&s.x()).unwrap();
+/// assert!(x.get(0) > 0. && x.get(0) < 1e-3);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bounds {
+    bounds: Vec<Bound>,
+}
+
+impl Bounds {
+    /// Creates the constraints of the parameters, one [`Bound`] per parameter.
+    ///
+    /// Returns [`Value::Invalid`] if a bound is not finite or an interval is empty. The width
+    /// `b - a` of an interval must be finite as well.
+    pub fn new(bounds: &[Bound]) -> Result<Bounds, Value> {
+        for bound in bounds {
+            let valid = match *bound {
+                Bound::Free => true,
+                Bound::Lower(a) | Bound::Upper(a) => a.is_finite(),
+                Bound::Interval(a, b) => a < b && (b - a).is_finite(),
+            };
+            if !valid {
+                return Err(Value::Invalid);
+            }
+        }
+        Ok(Bounds {
+            bounds: bounds.to_vec(),
+        })
+    }
+
+    /// Returns the constraints of the parameters.
+    pub fn bounds(&self) -> &[Bound] {
+        &self.bounds
+    }
+
+    fn check_len(&self, v: &VectorF64) -> Result<(), Value> {
+        if v.len() == self.bounds.len() {
+            Ok(())
+        } else {
+            Err(Value::BadLength)
+        }
+    }
+
+    // Writes the parameters corresponding to the internal variables `u` into `x`.
+    fn external_into(&self, u: &VectorF64, x: &mut VectorF64) {
+        for (i, bound) in self.bounds.iter().enumerate() {
+            x.set(i, bound.to_external(u.get(i)));
+        }
+    }
+
+    // Multiplies the gradient `g` with respect to the parameters by the derivatives of the
+    // transformations at `u`, giving the gradient with respect to the internal variables.
+    fn chain_gradient(&self, u: &VectorF64, g: &mut VectorF64) {
+        for (i, bound) in self.bounds.iter().enumerate() {
+            g.set(i, g.get(i) * bound.derivative(u.get(i)));
+        }
+    }
+
+    fn buffer(&self) -> Result<RefCell<VectorF64>, Value> {
+        VectorF64::new(self.bounds.len())
+            .map(RefCell::new)
+            .ok_or(Value::NoMemory)
+    }
+
+    /// Returns the internal variables corresponding to the parameters `x`, typically to get the
+    /// starting point of the minimizer.
+    ///
+    /// Returns [`Value::BadLength`] if `x` does not have one element per bound and
+    /// [`Value::Domain`] if a parameter does not lie strictly inside its bounds.
+    pub fn to_internal(&self, x: &VectorF64) -> Result<VectorF64, Value> {
+        self.check_len(x)?;
+        let mut u = VectorF64::new(x.len()).ok_or(Value::NoMemory)?;
+        for (i, bound) in self.bounds.iter().enumerate() {
+            u.set(i, bound.to_internal(x.get(i))?);
+        }
+        Ok(u)
+    }
+
+    /// Returns the parameters corresponding to the internal variables `u`, typically to get the
+    /// location of the minimum found by the minimizer.
+    ///
+    /// Returns [`Value::BadLength`] if `u` does not have one element per bound.
+    pub fn to_external(&self, u: &VectorF64) -> Result<VectorF64, Value> {
+        self.check_len(u)?;
+        let mut x = VectorF64::new(u.len()).ok_or(Value::NoMemory)?;
+        self.external_into(u, &mut x);
+        Ok(x)
+    }
+
+    /// Turns the function `f` of the parameters into a function of the internal variables.
+    pub fn wrap_f<F: Fn(&VectorF64) -> f64>(
+        &self,
+        f: F,
+    ) -> Result<impl Fn(&VectorF64) -> f64, Value> {
+        let bounds = self.clone();
+        let x = self.buffer()?;
+        Ok(move |u: &VectorF64| {
+            let mut x = x.borrow_mut();
+            bounds.external_into(u, &mut x);
+            f(&x)
+        })
+    }
+
+    /// Turns the gradient `df` of a function of the parameters into the gradient with respect to
+    /// the internal variables.
+    pub fn wrap_df<DF: Fn(&VectorF64, &mut VectorF64)>(
+        &self,
+        df: DF,
+    ) -> Result<impl Fn(&VectorF64, &mut VectorF64), Value> {
+        let bounds = self.clone();
+        let x = self.buffer()?;
+        Ok(move |u: &VectorF64, g: &mut VectorF64| {
+            let mut x = x.borrow_mut();
+            bounds.external_into(u, &mut x);
+            df(&x, g);
+            bounds.chain_gradient(u, g);
+        })
+    }
+
+    /// Same as [`wrap_df`](Self::wrap_df) for a function computing both the value and the
+    /// gradient.
+    pub fn wrap_fdf<FDF: Fn(&VectorF64, &mut VectorF64) -> f64>(
+        &self,
+        fdf: FDF,
+    ) -> Result<impl Fn(&VectorF64, &mut VectorF64) -> f64, Value> {
+        let bounds = self.clone();
+        let x = self.buffer()?;
+        Ok(move |u: &VectorF64, g: &mut VectorF64| {
+            let mut x = x.borrow_mut();
+            bounds.external_into(u, &mut x);
+            let value = fdf(&x, g);
+            bounds.chain_gradient(u, g);
+            value
+        })
+    }
+}
+
 #[cfg(any(test, doctest))]
 mod test {
     /// This doc block will be used to ensure that the closure can't be set everywhere!
@@ -292,4 +971,118 @@ mod test {
             iter += 1;
         }
     }
+
+    #[test]
+    fn test_bounds() {
+        let bounds = Bounds::new(&[
+            Bound::Free,
+            Bound::Lower(1.),
+            Bound::Upper(-1.),
+            Bound::Interval(-2., 3.),
+        ])
+        .unwrap();
+        let x = VectorF64::from_slice(&[-4., 2., -3., 0.5]).unwrap();
+        let u = bounds.to_internal(&x).unwrap();
+        let y = bounds.to_external(&u).unwrap();
+        for i in 0..4 {
+            assert!((x.get(i) - y.get(i)).abs() < 1e-12);
+        }
+
+        let out = VectorF64::from_slice(&[0., 0., 0., 3.]).unwrap();
+        assert_eq!(bounds.to_internal(&out).err(), Some(Value::Domain));
+        assert_eq!(
+            Bounds::new(&[Bound::Interval(1., 1.)]).err(),
+            Some(Value::Invalid)
+        );
+        for bound in [
+            Bound::Lower(f64::NEG_INFINITY),
+            Bound::Upper(f64::INFINITY),
+            Bound::Interval(f64::NEG_INFINITY, 1.),
+            Bound::Interval(-f64::MAX, f64::MAX),
+        ] {
+            assert_eq!(Bounds::new(&[bound]).err(), Some(Value::Invalid));
+        }
+
+        // The gradient of the sum of the parameters is the derivative of the transformations.
+        let fdf = bounds
+            .wrap_fdf(|x: &VectorF64, g: &mut VectorF64| {
+                g.set_all(1.);
+                (0..4).map(|i| x.get(i)).sum()
+            })
+            .unwrap();
+        let mut g = VectorF64::new(4).unwrap();
+        fdf(&u, &mut g);
+        let h = 1e-6;
+        for i in 0..4 {
+            let mut v = u.clone().unwrap();
+            v.set(i, u.get(i) + h);
+            let fd = (bounds.to_external(&v).unwrap().get(i) - x.get(i)) / h;
+            assert!((g.get(i) - fd).abs() < 1e-4);
+        }
+    }
+
+    // f(x, y) = (x - 1)^2 + 10 (y - 2)^2
+    fn quadratic(v: &VectorF64) -> f64 {
+        (v.get(0) - 1.).powi(2) + 10. * (v.get(1) - 2.).powi(2)
+    }
+
+    fn quadratic_df(v: &VectorF64, g: &mut VectorF64) {
+        g.set(0, 2. * (v.get(0) - 1.));
+        g.set(1, 20. * (v.get(1) - 2.));
+    }
+
+    #[test]
+    fn test_minimize() {
+        let mut s = Minimizer::new(MinimizerType::nm_simplex2(), 2).unwrap();
+        let x = VectorF64::from_slice(&[5., 7.]).unwrap();
+        let step_size = VectorF64::from_slice(&[1., 1.]).unwrap();
+        let mut calls = 0;
+        let iter = s
+            .minimize(
+                quadratic,
+                &x,
+                &step_size,
+                1000,
+                |s| test_size(s.size(), 1e-8) == Value::Success,
+                |_, _| calls += 1,
+            )
+            .unwrap();
+        assert_eq!(calls, iter);
+        assert!((s.x().get(0) - 1.).abs() < 1e-6);
+        assert!((s.x().get(1) - 2.).abs() < 1e-6);
+
+        assert_eq!(
+            s.minimize(quadratic, &x, &step_size, 2, |_| false, |_, _| {}),
+            Err(Value::MaxIteration)
+        );
+    }
+
+    #[test]
+    fn test_fdf_minimize() {
+        let fdf = |v: &VectorF64, g: &mut VectorF64| {
+            quadratic_df(v, g);
+            quadratic(v)
+        };
+        let x = VectorF64::from_slice(&[5., 7.]).unwrap();
+        let params = FdfMinimizerParams::default().epsabs(1e-8);
+
+        let mut s = FdfMinimizer::new(FdfMinimizerType::vector_bfgs2(), 2).unwrap();
+        s.minimize(quadratic, quadratic_df, fdf, &x, &params)
+            .unwrap();
+        assert!((s.x().get(0) - 1.).abs() < 1e-6);
+        assert!((s.x().get(1) - 2.).abs() < 1e-6);
+
+        let mut trace = MinimizerTrace::new();
+        let iter = s
+            .minimize_traced(quadratic, quadratic_df, fdf, &x, &params, &mut trace)
+            .unwrap();
+        // The starting point and one state per iteration.
+        assert_eq!(trace.len(), iter + 1);
+
+        let mut s = FdfMinimizer::new(FdfMinimizerType::steepest_descent(), 2).unwrap();
+        assert_eq!(
+            s.minimize(quadratic, quadratic_df, fdf, &x, &params.max_iter(1)),
+            Err(Value::MaxIteration)
+        );
+    }
 }
@@ -57,7 +57,9 @@ The algorithms estimate the matrix J or J^{-1} by approximate methods.
 !*/
 
 use crate::ffi::FFI;
-use crate::{Value, VectorF64, View};
+use crate::types::fdf::{Callbacks, Fdf};
+use crate::{MatrixF64, Value, VectorF64, View};
+use std::rc::Rc;
 use sys::libc::{c_int, c_void};
 
 ffi_wrapper!(
@@ -179,12 +181,14 @@ impl<'a> MultiRootFSolver<'a> {
             Value::into(g(&x_new, &mut VectorF64::soft_wrap(f)))
         }
 
+        // GSL keeps a pointer to the closure, so it must not move once boxed.
+        let f = Box::new(f);
         self.inner_call = sys::gsl_multiroot_function_struct {
             f: Some(inner_f::<F>),
             n,
-            params: &f as *const _ as *mut _,
+            params: &*f as *const F as *mut _,
         };
-        self.inner_closure = Some(Box::new(f));
+        self.inner_closure = Some(f);
 
         let ret = unsafe {
             sys::gsl_multiroot_fsolver_set(
@@ -234,6 +238,451 @@ impl<'a> MultiRootFSolver<'a> {
     }
 }
 
+ffi_wrapper!(
+    MultiRootFdfSolverType,
+    *const sys::gsl_multiroot_fdfsolver_type,
+    "The multiroot algorithms described in this section make use of both the function and its
+    derivative. They require an initial guess for the location of the root, but there is no
+    absolute guarantee of convergence—the equation must be suitable for this technique and the
+    initial guess must be sufficiently close to the root for it to work. When the conditions are
+    satisfied then convergence is quadratic."
+);
+
+impl MultiRootFdfSolverType {
+    /// This is a modified version of Powell’s Hybrid method as implemented in the HYBRJ
+    /// algorithm in MINPACK. The Hybrid algorithm retains the fast convergence of Newton’s method
+    /// but will also reduce the residual when Newton’s method is unreliable.
+    ///
+    /// The algorithm uses a generalized trust region to keep each step under control, and the
+    /// elements of the diagonal scaling matrix are computed from the column norms of the Jacobian.
+    #[doc(alias = "gsl_multiroot_fdfsolver_hybridsj")]
+    pub fn hybridsj() -> MultiRootFdfSolverType {
+        ffi_wrap!(gsl_multiroot_fdfsolver_hybridsj)
+    }
+
+    /// This algorithm is an unscaled version of [`hybridsj`](Self::hybridsj). The steps are
+    /// controlled by a spherical trust region instead of a generalized region.
+    #[doc(alias = "gsl_multiroot_fdfsolver_hybridj")]
+    pub fn hybridj() -> MultiRootFdfSolverType {
+        ffi_wrap!(gsl_multiroot_fdfsolver_hybridj)
+    }
+
+    /// Newton’s Method is the standard root-polishing algorithm. The algorithm begins with an
+    /// initial guess for the location of the solution. On each iteration a linear approximation
+    /// to the function F is used to estimate the step which will zero all the components of the
+    /// residual. The iteration is defined by the following sequence,
+    ///
+    ///```text
+    ///x \to x' = x - J^{-1} f(x)
+    ///```
+    ///
+    /// where the Jacobian matrix J is computed from the derivative functions provided by f.
+    #[doc(alias = "gsl_multiroot_fdfsolver_newton")]
+    pub fn newton() -> MultiRootFdfSolverType {
+        ffi_wrap!(gsl_multiroot_fdfsolver_newton)
+    }
+
+    /// This is a modified version of Newton’s method which attempts to improve global
+    /// convergence by requiring every step to reduce the Euclidean norm of the residual,
+    /// |f(x)|. If the Newton step leads to an increase in the norm then a reduced step of
+    /// relative size t is proposed, with t chosen by a quadratic line search.
+    #[doc(alias = "gsl_multiroot_fdfsolver_gnewton")]
+    pub fn gnewton() -> MultiRootFdfSolverType {
+        ffi_wrap!(gsl_multiroot_fdfsolver_gnewton)
+    }
+}
+
+// `gsl_multiroot_function_fdf` is opaque in the generated bindings.
+#[repr(C)]
+struct RawFunctionFdf {
+    f: Option<
+        unsafe extern "C" fn(*const sys::gsl_vector, *mut c_void, *mut sys::gsl_vector) -> c_int,
+    >,
+    df: Option<
+        unsafe extern "C" fn(*const sys::gsl_vector, *mut c_void, *mut sys::gsl_matrix) -> c_int,
+    >,
+    fdf: Option<
+        unsafe extern "C" fn(
+            *const sys::gsl_vector,
+            *mut c_void,
+            *mut sys::gsl_vector,
+            *mut sys::gsl_matrix,
+        ) -> c_int,
+    >,
+    n: usize,
+    params: *mut c_void,
+}
+
+ffi_wrapper!(
+    MultiRootFdfSolver<'a>,
+    *mut sys::gsl_multiroot_fdfsolver,
+    gsl_multiroot_fdfsolver_free
+    ;inner_call: RawFunctionFdf => RawFunctionFdf { f: None, df: None, fdf: None, n: 0, params: std::ptr::null_mut() };
+    ;inner_closure: Option<Box<dyn Callbacks + 'a>> => None;,
+    "This is a workspace for multidimensional root-finding with derivatives."
+);
+
+impl<'a> MultiRootFdfSolver<'a> {
+    /// This function returns a pointer to a newly allocated instance of a solver of type `T` with
+    /// `n` unknowns.
+    ///
+    /// If there is insufficient memory to create the solver then the function returns a null
+    /// pointer and the error handler is invoked with an error code of `Value::NoMemory`.
+    #[doc(alias = "gsl_multiroot_fdfsolver_alloc")]
+    pub fn new(t: &MultiRootFdfSolverType, n: usize) -> Option<MultiRootFdfSolver<'a>> {
+        let ptr = unsafe { sys::gsl_multiroot_fdfsolver_alloc(t.unwrap_shared(), n) };
+
+        if ptr.is_null() {
+            None
+        } else {
+            Some(MultiRootFdfSolver::wrap(ptr))
+        }
+    }
+
+    /// This function initializes, or reinitializes, an existing solver to find the root of the
+    /// function `f` starting from the initial guess `x`.
+    ///
+    /// `f` stores the value of the function at its first argument into its second one, `df`
+    /// stores the Jacobian matrix J_ij = d f_i / d x_j into its second argument and `fdf` does
+    /// both at once (which is often cheaper than calling `f` and `df`).
+    #[doc(alias = "gsl_multiroot_fdfsolver_set")]
+    pub fn set<F, DF, FDF>(&mut self, f: F, df: DF, fdf: FDF, x: &VectorF64) -> Result<(), Value>
+    where
+        F: Fn(&VectorF64, &mut VectorF64) -> Value + 'a,
+        DF: Fn(&VectorF64, &mut MatrixF64) -> Value + 'a,
+        FDF: Fn(&VectorF64, &mut VectorF64, &mut MatrixF64) -> Value + 'a,
+    {
+        unsafe extern "C" fn inner_f<F: Fn(&VectorF64, &mut VectorF64) -> Value, DF, FDF>(
+            x: *const sys::gsl_vector,
+            params: *mut c_void,
+            f: *mut sys::gsl_vector,
+        ) -> c_int {
+            let t = Fdf::<F, DF, FDF>::from_params(params);
+            Value::into((t.f)(
+                &VectorF64::soft_wrap(x as *mut _),
+                &mut VectorF64::soft_wrap(f),
+            ))
+        }
+
+        unsafe extern "C" fn inner_df<F, DF: Fn(&VectorF64, &mut MatrixF64) -> Value, FDF>(
+            x: *const sys::gsl_vector,
+            params: *mut c_void,
+            j: *mut sys::gsl_matrix,
+        ) -> c_int {
+            let t = Fdf::<F, DF, FDF>::from_params(params);
+            Value::into((t.df)(
+                &VectorF64::soft_wrap(x as *mut _),
+                &mut MatrixF64::soft_wrap(j),
+            ))
+        }
+
+        unsafe extern "C" fn inner_fdf<
+            F,
+            DF,
+            FDF: Fn(&VectorF64, &mut VectorF64, &mut MatrixF64) -> Value,
+        >(
+            x: *const sys::gsl_vector,
+            params: *mut c_void,
+            f: *mut sys::gsl_vector,
+            j: *mut sys::gsl_matrix,
+        ) -> c_int {
+            let t = Fdf::<F, DF, FDF>::from_params(params);
+            Value::into((t.fdf)(
+                &VectorF64::soft_wrap(x as *mut _),
+                &mut VectorF64::soft_wrap(f),
+                &mut MatrixF64::soft_wrap(j),
+            ))
+        }
+
+        let (callbacks, params) = Fdf::boxed(f, df, fdf);
+        self.inner_call = RawFunctionFdf {
+            f: Some(inner_f::<F, DF, FDF>),
+            df: Some(inner_df::<F, DF, FDF>),
+            fdf: Some(inner_fdf::<F, DF, FDF>),
+            n: x.len(),
+            params,
+        };
+        self.inner_closure = Some(callbacks);
+
+        let ret = unsafe {
+            sys::gsl_multiroot_fdfsolver_set(
+                self.unwrap_unique(),
+                &mut self.inner_call as *mut RawFunctionFdf as *mut _,
+                x.unwrap_shared(),
+            )
+        };
+        result_handler!(ret, ())
+    }
+
+    /// This function returns the name of the solver.
+    #[doc(alias = "gsl_multiroot_fdfsolver_name")]
+    pub fn name(&self) -> String {
+        unsafe {
+            let tmp = sys::gsl_multiroot_fdfsolver_name(self.unwrap_shared());
+
+            String::from_utf8_lossy(std::ffi::CStr::from_ptr(tmp).to_bytes()).to_string()
+        }
+    }
+
+    /// This function performs a single iteration of the solver. If the iteration encounters an
+    /// unexpected problem then an error code will be returned,
+    ///
+    /// `Value::BadFunction`
+    /// the iteration encountered a singular point where the function or its derivative
+    /// evaluated to Inf or NaN.
+    ///
+    /// `Value::NoProgress`
+    /// the iteration is not making any progress, preventing the algorithm from continuing.
+    #[doc(alias = "gsl_multiroot_fdfsolver_iterate")]
+    pub fn iterate(&mut self) -> Result<(), Value> {
+        let ret = unsafe { sys::gsl_multiroot_fdfsolver_iterate(self.unwrap_unique()) };
+        result_handler!(ret, ())
+    }
+
+    /// This function returns the current estimate of the root for the solver `s`, given by `s->x`.
+    #[doc(alias = "gsl_multiroot_fdfsolver_root")]
+    pub fn root(&self) -> View<'_, VectorF64> {
+        unsafe { View::new(sys::gsl_multiroot_fdfsolver_root(self.unwrap_shared())) }
+    }
+
+    /// This function returns the last step `dx` taken by the solver `s`, given by `s->dx`.
+    #[doc(alias = "gsl_multiroot_fdfsolver_dx")]
+    pub fn dx(&self) -> View<'_, VectorF64> {
+        unsafe { View::new(sys::gsl_multiroot_fdfsolver_dx(self.unwrap_shared())) }
+    }
+
+    /// This function returns the function value `f(x)` at the current estimate of the root for
+    /// the solver `s`, given by `s->f`.
+    #[doc(alias = "gsl_multiroot_fdfsolver_f")]
+    pub fn f(&self) -> View<'_, VectorF64> {
+        unsafe { View::new(sys::gsl_multiroot_fdfsolver_f(self.unwrap_shared())) }
+    }
+
+    /// Finds a root starting from `x`, see [`set`](Self::set) for the meaning of the functions.
+    ///
+    /// The solver is iterated until the residual is small, as checked by
+    /// [`multiroot::test_residual`](crate::multiroot::test_residual), or the last step is small,
+    /// as checked by [`multiroot::test_delta`](crate::multiroot::test_delta), with the
+    /// tolerances of `params`. The root is then given by [`root`](Self::root).
+    ///
+    /// Returns the number of iterations performed, [`Value::MaxIteration`] if no root was found
+    /// within `params.max_iter` iterations or the error of the failed iteration.
+    ///
+    /// ```
+    /// use rgsl::types::multiroot::{MultiRootFdfSolver, MultiRootFdfSolverType, MultiRootParams};
+    /// use rgsl::{MatrixF64, Value, VectorF64};
+    ///
+    /// // Rosenbrock system: f = (1 - x, 10 (y - x^2)).
+    /// let f = |x: &VectorF64, f: &mut VectorF64| {
+    ///     f.set(0, 1. - x.get(0));
+    ///     f.set(1, 10. * (x.get(1) - x.get(0).powi(2)));
+    ///     Value::Success
+    /// };
+    /// let df = |x: &VectorF64, j: &mut MatrixF64| {
+    ///     j.set(0, 0, -1.);
+    ///     j.set(0, 1, 0.);
+    ///     j.set(1, 0, -20. * x.get(0));
+    ///     j.set(1, 1, 10.);
+    ///     Value::Success
+    /// };
+    /// let fdf = |x: &VectorF64, v: &mut VectorF64, j: &mut MatrixF64| {
+    ///     f(x, v);
+    ///     df(x, j)
+    /// };
+    ///
+    /// let mut s = MultiRootFdfSolver::new(&MultiRootFdfSolverType::hybridsj(), 2).unwrap();
+    /// let x = VectorF64::from_slice(&[-10., -5.]).unwrap();
+    /// s.solve(f, df, fdf, &x, &MultiRootParams::default()).unwrap();
+    /// assert!((s.root().get(1) - 1.).abs() < 1e-6);
+    /// ```
+    pub fn solve<F, DF, FDF>(
+        &mut self,
+        f: F,
+        df: DF,
+        fdf: FDF,
+        x: &VectorF64,
+        params: &MultiRootParams,
+    ) -> Result<usize, Value>
+    where
+        F: Fn(&VectorF64, &mut VectorF64) -> Value + 'a,
+        DF: Fn(&VectorF64, &mut MatrixF64) -> Value + 'a,
+        FDF: Fn(&VectorF64, &mut VectorF64, &mut MatrixF64) -> Value + 'a,
+    {
+        use crate::multiroot::{test_delta, test_residual};
+
+        self.set(f, df, fdf, x)?;
+        for iter in 1..=params.max_iter {
+            self.iterate()?;
+            if test_residual(&self.f(), params.epsabs) == Value::Success
+                || test_delta(
+                    &self.dx(),
+                    &self.root(),
+                    params.delta_epsabs,
+                    params.delta_epsrel,
+                ) == Value::Success
+            {
+                return Ok(iter);
+            }
+        }
+        Err(Value::MaxIteration)
+    }
+}
+
+/// Adapter supplying a finite-difference Jacobian for a function given without derivatives,
+/// so that it can be solved with a [`MultiRootFdfSolver`].
+///
+/// The Jacobian is computed by [`jacobian`](Self::jacobian), using forward differences with a
+/// relative step size set by [`epsrel`](Self::epsrel).
+///
+/// ```
+/// use rgsl::types::multiroot::{
+///     FdJacobian, MultiRootFdfSolver, MultiRootFdfSolverType, MultiRootParams,
+/// };
+/// use rgsl::{Value, VectorF64};
+///
+/// let (f, df, fdf) = FdJacobian::new(|x: &VectorF64, f: &mut VectorF64| {
+///     f.set(0, 1. - x.get(0));
+///     f.set(1, 10. * (x.get(1) - x.get(0).powi(2)));
+///     Value::Success
+/// })
+/// .into_fdf();
+///
+/// let mut s = MultiRootFdfSolver::new(&MultiRootFdfSolverType::hybridsj(), 2).unwrap();
+/// let x = VectorF64::from_slice(&[-10., -5.]).unwrap();
+/// s.solve(f, df, fdf, &x, &MultiRootParams::default()).unwrap();
+/// assert!((s.root().get(1) - 1.).abs() < 1e-6);
+/// ```
+pub struct FdJacobian<F> {
+    f: F,
+    epsrel: f64,
+}
+
+impl<F: Fn(&VectorF64, &mut VectorF64) -> Value> FdJacobian<F> {
+    /// Wraps the function `f`, which stores its value at its first argument into its second one.
+    /// The relative step size is sqrt(ε) where ε is the machine precision.
+    pub fn new(f: F) -> FdJacobian<F> {
+        FdJacobian {
+            f,
+            epsrel: f64::EPSILON.sqrt(),
+        }
+    }
+
+    /// Sets the relative step size of the finite differences. The step used for the j-th
+    /// variable is `epsrel * |x_j|`, or `epsrel` if x_j is zero.
+    pub fn epsrel(mut self, epsrel: f64) -> Self {
+        self.epsrel = epsrel;
+        self
+    }
+
+    /// This function computes a forward-difference approximation of the Jacobian of the
+    /// function at `x`, storing it into `jacobian`, where `fx` is the value of the function at
+    /// `x`.
+    #[doc(alias = "gsl_multiroot_fdjacobian")]
+    pub fn jacobian(&self, x: &VectorF64, fx: &VectorF64, jacobian: &mut MatrixF64) -> Value {
+        unsafe extern "C" fn inner_f<A: Fn(&VectorF64, &mut VectorF64) -> Value>(
+            x: *const sys::gsl_vector,
+            params: *mut c_void,
+            f: *mut sys::gsl_vector,
+        ) -> c_int {
+            let g: &A = &*(params as *const A);
+            let x_new = VectorF64::soft_wrap(x as *const _ as *mut _);
+            Value::into(g(&x_new, &mut VectorF64::soft_wrap(f)))
+        }
+
+        let mut function = sys::gsl_multiroot_function_struct {
+            f: Some(inner_f::<F>),
+            n: x.len(),
+            params: &self.f as *const F as *mut _,
+        };
+        Value::from(unsafe {
+            sys::gsl_multiroot_fdjacobian(
+                &mut function,
+                x.unwrap_shared(),
+                fx.unwrap_shared(),
+                self.epsrel,
+                jacobian.unwrap_unique(),
+            )
+        })
+    }
+
+    /// Returns the function, its Jacobian and both of them at once, in the form expected by
+    /// [`MultiRootFdfSolver::set`] and [`MultiRootFdfSolver::solve`].
+    pub fn into_fdf(
+        self,
+    ) -> (
+        impl Fn(&VectorF64, &mut VectorF64) -> Value,
+        impl Fn(&VectorF64, &mut MatrixF64) -> Value,
+        impl Fn(&VectorF64, &mut VectorF64, &mut MatrixF64) -> Value,
+    ) {
+        let this = Rc::new(self);
+        let (df_this, fdf_this) = (Rc::clone(&this), Rc::clone(&this));
+        let f = move |x: &VectorF64, f: &mut VectorF64| (this.f)(x, f);
+        let df = move |x: &VectorF64, jacobian: &mut MatrixF64| {
+            let mut fx = match VectorF64::new(jacobian.size1()) {
+                Some(fx) => fx,
+                None => return Value::NoMemory,
+            };
+            let status = (df_this.f)(x, &mut fx);
+            if status != Value::Success {
+                return status;
+            }
+            df_this.jacobian(x, &fx, jacobian)
+        };
+        let fdf = move |x: &VectorF64, f: &mut VectorF64, jacobian: &mut MatrixF64| {
+            let status = (fdf_this.f)(x, f);
+            if status != Value::Success {
+                return status;
+            }
+            fdf_this.jacobian(x, f, jacobian)
+        };
+        (f, df, fdf)
+    }
+}
+
+/// Stopping criteria of [`MultiRootFdfSolver::solve`], built from the default values by chaining
+/// the setters.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MultiRootParams {
+    epsabs: f64,
+    delta_epsabs: f64,
+    delta_epsrel: f64,
+    max_iter: usize,
+}
+
+impl Default for MultiRootParams {
+    fn default() -> MultiRootParams {
+        MultiRootParams {
+            epsabs: 1e-7,
+            delta_epsabs: 0.,
+            delta_epsrel: 0.,
+            max_iter: 100,
+        }
+    }
+}
+
+impl MultiRootParams {
+    /// Sets the tolerance on the residual: the iterations stop when the sum of the absolute
+    /// values of the components of f(x) is less than `epsabs`. The default value is 1e-7.
+    pub fn epsabs(mut self, epsabs: f64) -> Self {
+        self.epsabs = epsabs;
+        self
+    }
+
+    /// Sets the tolerances on the last step dx: the iterations stop when |dx_i| < epsabs +
+    /// epsrel |x_i| for all i. By default both are 0, which disables this test.
+    pub fn delta(mut self, epsabs: f64, epsrel: f64) -> Self {
+        self.delta_epsabs = epsabs;
+        self.delta_epsrel = epsrel;
+        self
+    }
+
+    /// Sets the maximum number of iterations. The default value is 100.
+    pub fn max_iter(mut self, max_iter: usize) -> Self {
+        self.max_iter = max_iter;
+        self
+    }
+}
+
 #[cfg(any(test, doctest))]
 mod tests {
     /// This doc block will be used to ensure that the closure can't be set everywhere!
@@ -338,4 +787,61 @@ mod tests {
         }
         assert!(matches!(status, crate::Value::Success))
     }
+
+    fn rosenbrock_df(x: &VectorF64, j: &mut MatrixF64) -> Value {
+        j.set(0, 0, -1.0);
+        j.set(0, 1, 0.0);
+        j.set(1, 0, 1.0);
+        j.set(1, 1, -2.0 * x.get(1));
+        Value::Success
+    }
+
+    fn rosenbrock_fdf(x: &VectorF64, f: &mut VectorF64, j: &mut MatrixF64) -> Value {
+        rosenbrock_f(x, f);
+        rosenbrock_df(x, j)
+    }
+
+    #[test]
+    fn test_multiroot_fdfsolver_solve() {
+        let mut solver = MultiRootFdfSolver::new(&MultiRootFdfSolverType::hybridsj(), 2).unwrap();
+        let guess_value = VectorF64::from_slice(&[-10.0, -5.0]).unwrap();
+        let params = MultiRootParams::default().epsabs(1e-10);
+
+        let iter = solver
+            .solve(
+                rosenbrock_f,
+                rosenbrock_df,
+                rosenbrock_fdf,
+                &guess_value,
+                &params,
+            )
+            .unwrap();
+        assert!((1..=100).contains(&iter));
+        assert_eq!(test_residual(&solver.f(), 1e-10), Value::Success);
+        assert!((solver.root().get(0) - 1.0).abs() < 1e-8);
+        assert!((solver.root().get(1).abs() - 1.0).abs() < 1e-8);
+
+        // The finite-difference Jacobian finds the same root.
+        let (f, df, fdf) = FdJacobian::new(rosenbrock_f).into_fdf();
+        solver.solve(f, df, fdf, &guess_value, &params).unwrap();
+        assert!((solver.root().get(0) - 1.0).abs() < 1e-6);
+
+        assert_eq!(
+            solver.solve(
+                rosenbrock_f,
+                rosenbrock_df,
+                rosenbrock_fdf,
+                &guess_value,
+                &params.max_iter(1)
+            ),
+            Err(Value::MaxIteration)
+        );
+
+        // The errors of the function stop the iterations.
+        let failing = |_: &VectorF64, _: &mut VectorF64| Value::BadFunction;
+        let failing_fdf = |_: &VectorF64, _: &mut VectorF64, _: &mut MatrixF64| Value::BadFunction;
+        assert!(solver
+            .solve(failing, rosenbrock_df, failing_fdf, &guess_value, &params)
+            .is_err());
+    }
 }
@@ -0,0 +1,156 @@
+//
+// A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
+//
+
+/*!
+The multivariate Gaussian distribution with mean vector \mu and positive-definite variance-covariance
+matrix \Sigma is
+
+p(x_1,...,x_k) dx_1...dx_k = 1 / \sqrt{(2\pi)^k |\Sigma|} \exp(-1/2 (x - \mu)^T \Sigma^{-1} (x - \mu)) dx_1...dx_k
+
+GSL works with the Cholesky factor L of \Sigma = L L^T. [`MultivariateGaussian`] computes and stores
+this factor once, so that it can be reused for sampling and for evaluating the density.
+
+```
+use rgsl::{MatrixF64, MultivariateGaussian, Rng, RngType, VectorF64};
+
+let mu = VectorF64::from_slice(&[1., 2.]).unwrap();
+let mut sigma = MatrixF64::new(2, 2).unwrap();
+sigma.set(0, 0, 2.);
+sigma.set(1, 1, 1.);
+sigma.set(0, 1, 0.5);
+sigma.set(1, 0, 0.5);
+
+let mut gaussian = MultivariateGaussian::new(&mu, &sigma).unwrap();
+let mut rng = Rng::new(RngType::default()).unwrap();
+let mut x = VectorF64::new(2).unwrap();
+gaussian.sample(&mut rng, &mut x).unwrap();
+assert!(gaussian.pdf(&x).unwrap() > 0.);
+```
+!*/
+
+use crate::ffi::FFI;
+use crate::types::{MatrixF64, Rng, VectorF64};
+use crate::Value;
+
+/// A multivariate Gaussian distribution, holding its mean and the Cholesky factor of its
+/// variance-covariance matrix.
+pub struct MultivariateGaussian {
+    mu: VectorF64,
+    L: MatrixF64,
+    work: VectorF64,
+}
+
+impl MultivariateGaussian {
+    /// Creates the distribution with mean `mu` and variance-covariance matrix `sigma`. `sigma`
+    /// is copied and factorized with [`cholesky_decomp`](crate::linear_algebra::cholesky_decomp),
+    /// so it must be symmetric positive-definite, otherwise [`Value::Domain`] is returned.
+    pub fn new(mu: &VectorF64, sigma: &MatrixF64) -> Result<MultivariateGaussian, Value> {
+        let n = mu.len();
+        if sigma.size1() != n || sigma.size2() != n {
+            return Err(Value::BadLength);
+        }
+        let mut L = sigma.clone().ok_or(Value::NoMemory)?;
+        crate::linear_algebra::cholesky_decomp(&mut L)?;
+
+        Ok(MultivariateGaussian {
+            mu: mu.clone().ok_or(Value::NoMemory)?,
+            L,
+            work: VectorF64::new(n).ok_or(Value::NoMemory)?,
+        })
+    }
+
+    /// Estimates the mean and variance-covariance matrix from the samples `X`, stored one per
+    /// row, and creates the corresponding distribution.
+    pub fn fit(X: &MatrixF64) -> Result<MultivariateGaussian, Value> {
+        let mu = Self::sample_mean(X)?;
+        let sigma = Self::sample_vcov(X)?;
+        Self::new(&mu, &sigma)
+    }
+
+    /// Returns the dimension k of the distribution.
+    pub fn dimension(&self) -> usize {
+        self.mu.len()
+    }
+
+    /// Returns the mean vector \mu.
+    pub fn mean(&self) -> &VectorF64 {
+        &self.mu
+    }
+
+    /// Returns the Cholesky factor L of the variance-covariance matrix, stored in its lower
+    /// triangular part.
+    pub fn cholesky(&self) -> &MatrixF64 {
+        &self.L
+    }
+
+    /// This function generates a random vector satisfying the k-dimensional multivariate Gaussian
+    /// distribution and stores it in `result`, which must have length k.
+    #[doc(alias = "gsl_ran_multivariate_gaussian")]
+    pub fn sample(&self, rng: &mut Rng, result: &mut VectorF64) -> Result<(), Value> {
+        let ret = unsafe {
+            sys::gsl_ran_multivariate_gaussian(
+                rng.unwrap_unique(),
+                self.mu.unwrap_shared(),
+                self.L.unwrap_shared(),
+                result.unwrap_unique(),
+            )
+        };
+        result_handler!(ret, ())
+    }
+
+    /// This function computes the probability density p(x_1,...,x_k) at the point `x`.
+    #[doc(alias = "gsl_ran_multivariate_gaussian_pdf")]
+    pub fn pdf(&mut self, x: &VectorF64) -> Result<f64, Value> {
+        let mut result = 0.;
+        let ret = unsafe {
+            sys::gsl_ran_multivariate_gaussian_pdf(
+                x.unwrap_shared(),
+                self.mu.unwrap_shared(),
+                self.L.unwrap_shared(),
+                &mut result,
+                self.work.unwrap_unique(),
+            )
+        };
+        result_handler!(ret, result)
+    }
+
+    /// This function computes the logarithm of the probability density p(x_1,...,x_k) at the
+    /// point `x`.
+    #[doc(alias = "gsl_ran_multivariate_gaussian_log_pdf")]
+    pub fn log_pdf(&mut self, x: &VectorF64) -> Result<f64, Value> {
+        let mut result = 0.;
+        let ret = unsafe {
+            sys::gsl_ran_multivariate_gaussian_log_pdf(
+                x.unwrap_shared(),
+                self.mu.unwrap_shared(),
+                self.L.unwrap_shared(),
+                &mut result,
+                self.work.unwrap_unique(),
+            )
+        };
+        result_handler!(ret, result)
+    }
+
+    /// This function computes the maximum-likelihood estimate of the mean of a multivariate
+    /// Gaussian distribution from the samples `X`, stored one per row.
+    #[doc(alias = "gsl_ran_multivariate_gaussian_mean")]
+    pub fn sample_mean(X: &MatrixF64) -> Result<VectorF64, Value> {
+        let mut mu_hat = VectorF64::new(X.size2()).ok_or(Value::NoMemory)?;
+        let ret = unsafe {
+            sys::gsl_ran_multivariate_gaussian_mean(X.unwrap_shared(), mu_hat.unwrap_unique())
+        };
+        result_handler!(ret, mu_hat)
+    }
+
+    /// This function computes the maximum-likelihood estimate of the variance-covariance matrix of
+    /// a multivariate Gaussian distribution from the samples `X`, stored one per row.
+    #[doc(alias = "gsl_ran_multivariate_gaussian_vcov")]
+    pub fn sample_vcov(X: &MatrixF64) -> Result<MatrixF64, Value> {
+        let mut sigma_hat = MatrixF64::new(X.size2(), X.size2()).ok_or(Value::NoMemory)?;
+        let ret = unsafe {
+            sys::gsl_ran_multivariate_gaussian_vcov(X.unwrap_shared(), sigma_hat.unwrap_unique())
+        };
+        result_handler!(ret, sigma_hat)
+    }
+}
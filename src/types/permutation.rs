@@ -284,3 +284,36 @@ impl Debug for Permutation {
         }
     }
 }
+
+/// The permutation is serialized as the sequence `p[0], ..., p[n-1]` of its linear form.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Permutation {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.as_slice())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Permutation {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Permutation, D::Error> {
+        use serde::de::Error;
+
+        let v = <Vec<usize> as serde::Deserialize>::deserialize(deserializer)?;
+        if v.is_empty() {
+            return Err(D::Error::invalid_length(0, &"a non-empty permutation"));
+        }
+        // `gsl_permutation_valid` reports invalid permutations through the error handler, so
+        // they are checked here.
+        let mut seen = vec![false; v.len()];
+        for &i in &v {
+            match seen.get_mut(i) {
+                Some(s) if !*s => *s = true,
+                _ => return Err(D::Error::custom("invalid permutation")),
+            }
+        }
+        let mut p = Permutation::new(v.len())
+            .ok_or_else(|| D::Error::custom("cannot allocate the permutation"))?;
+        p.as_mut_slice().copy_from_slice(&v);
+        Ok(p)
+    }
+}
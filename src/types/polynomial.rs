@@ -11,6 +11,8 @@ described in this section uses an iterative method to find the approximate locat
 
 use crate::ffi::FFI;
 use crate::Value;
+#[cfg(feature = "complex")]
+use num_complex::Complex;
 
 ffi_wrapper!(
     PolyComplexWorkspace,
@@ -57,3 +59,260 @@ impl PolyComplexWorkspace {
         result_handler!(ret, ())
     }
 }
+
+/// A polynomial with real coefficients, P(x) = c_0 + c_1 x + c_2 x^2 + ... + c_n x^n.
+///
+/// The coefficients are stored in increasing order of the powers of x, without trailing zeros,
+/// so that the zero polynomial has no coefficients.
+///
+/// Polynomials can be added, subtracted and multiplied with the usual operators, either by value
+/// or by reference.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Poly {
+    coeffs: Vec<f64>,
+}
+
+impl Poly {
+    /// Creates the polynomial with coefficients `coeffs`, `coeffs[i]` being the coefficient of
+    /// x^i. Trailing zero coefficients are removed.
+    pub fn new(mut coeffs: Vec<f64>) -> Poly {
+        while coeffs.last() == Some(&0.) {
+            coeffs.pop();
+        }
+        Poly { coeffs }
+    }
+
+    /// Returns the zero polynomial.
+    pub fn zero() -> Poly {
+        Poly { coeffs: Vec::new() }
+    }
+
+    /// Returns the constant polynomial P(x) = c.
+    pub fn constant(c: f64) -> Poly {
+        Poly::new(vec![c])
+    }
+
+    /// Returns the monomial P(x) = c x^n.
+    pub fn monomial(c: f64, n: usize) -> Poly {
+        let mut coeffs = vec![0.; n + 1];
+        coeffs[n] = c;
+        Poly::new(coeffs)
+    }
+
+    /// Returns the monic polynomial whose roots are `roots`, P(x) = (x - r_1) ... (x - r_n).
+    pub fn from_roots(roots: &[f64]) -> Poly {
+        roots
+            .iter()
+            .fold(Poly::constant(1.), |p, &r| &p * &Poly::new(vec![-r, 1.]))
+    }
+
+    /// Returns the coefficients, in increasing order of the powers of x.
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coeffs
+    }
+
+    /// Returns the degree of the polynomial, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    /// Evaluates the polynomial at `x` using Horner's method.
+    #[doc(alias = "gsl_poly_eval")]
+    pub fn eval(&self, x: f64) -> f64 {
+        crate::polynomials::evaluation::poly_eval(&self.coeffs, x)
+    }
+
+    /// Returns the derivative P'(x) of the polynomial.
+    pub fn derivative(&self) -> Poly {
+        Poly::new(
+            self.coeffs
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, &c)| i as f64 * c)
+                .collect(),
+        )
+    }
+
+    /// Returns the antiderivative of the polynomial which takes the value `c` at 0.
+    pub fn antiderivative(&self, c: f64) -> Poly {
+        let mut coeffs = Vec::with_capacity(self.coeffs.len() + 1);
+        coeffs.push(c);
+        coeffs.extend(
+            self.coeffs
+                .iter()
+                .enumerate()
+                .map(|(i, &c)| c / (i + 1) as f64),
+        );
+        Poly::new(coeffs)
+    }
+
+    /// Returns the composition P(Q(x)) of the polynomial with `q`.
+    pub fn compose(&self, q: &Poly) -> Poly {
+        // Horner's method with polynomial operations.
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(Poly::zero(), |p, &c| &(&p * q) + &Poly::constant(c))
+    }
+
+    /// Returns the roots of the polynomial, with multiplicity, computed by
+    /// [`PolyComplexWorkspace::solve`]. A constant polynomial has no roots.
+    ///
+    /// Returns [`Value::Invalid`] for the zero polynomial. GSL reports a QR reduction which does
+    /// not converge through the error handler, which aborts the program by default: the error
+    /// is only returned as [`Value::Failed`] if the handler has been turned off, for instance
+    /// with [`set_error_handler_off`](crate::error::set_error_handler_off).
+    ///
+    /// ```
+    /// use rgsl::Poly;
+    ///
+    /// // P(x) = x^2 + 1
+    /// let roots = Poly::new(vec![1., 0., 1.]).roots().unwrap();
+    /// assert_eq!(roots.len(), 2);
+    /// for z in roots {
+    ///     assert!(z.re.abs() < 1e-12 && (z.im.abs() - 1.).abs() < 1e-12);
+    /// }
+    /// ```
+    #[cfg(feature = "complex")]
+    #[doc(alias = "gsl_poly_complex_solve")]
+    pub fn roots(&self) -> Result<Vec<Complex<f64>>, Value> {
+        let n = match self.coeffs.len() {
+            0 => return Err(Value::Invalid),
+            1 => return Ok(Vec::new()),
+            n => n,
+        };
+        let mut w = PolyComplexWorkspace::new(n).ok_or(Value::NoMemory)?;
+        let mut z = vec![0.; 2 * (n - 1)];
+        w.solve(&self.coeffs, &mut z)?;
+        Ok(z.chunks_exact(2)
+            .map(|z| Complex::new(z[0], z[1]))
+            .collect())
+    }
+}
+
+impl From<Vec<f64>> for Poly {
+    fn from(coeffs: Vec<f64>) -> Poly {
+        Poly::new(coeffs)
+    }
+}
+
+impl From<&[f64]> for Poly {
+    fn from(coeffs: &[f64]) -> Poly {
+        Poly::new(coeffs.to_vec())
+    }
+}
+
+impl std::ops::Add for &Poly {
+    type Output = Poly;
+
+    fn add(self, other: &Poly) -> Poly {
+        let (long, short) = if self.coeffs.len() >= other.coeffs.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let mut coeffs = long.coeffs.clone();
+        for (c, &d) in coeffs.iter_mut().zip(&short.coeffs) {
+            *c += d;
+        }
+        Poly::new(coeffs)
+    }
+}
+
+impl std::ops::Neg for &Poly {
+    type Output = Poly;
+
+    fn neg(self) -> Poly {
+        Poly {
+            coeffs: self.coeffs.iter().map(|c| -c).collect(),
+        }
+    }
+}
+
+impl std::ops::Sub for &Poly {
+    type Output = Poly;
+
+    fn sub(self, other: &Poly) -> Poly {
+        self + &-other
+    }
+}
+
+impl std::ops::Mul for &Poly {
+    type Output = Poly;
+
+    fn mul(self, other: &Poly) -> Poly {
+        if self.coeffs.is_empty() || other.coeffs.is_empty() {
+            return Poly::zero();
+        }
+        let mut coeffs = vec![0.; self.coeffs.len() + other.coeffs.len() - 1];
+        for (i, &c) in self.coeffs.iter().enumerate() {
+            for (j, &d) in other.coeffs.iter().enumerate() {
+                coeffs[i + j] += c * d;
+            }
+        }
+        Poly::new(coeffs)
+    }
+}
+
+impl std::ops::Mul<f64> for &Poly {
+    type Output = Poly;
+
+    fn mul(self, x: f64) -> Poly {
+        Poly::new(self.coeffs.iter().map(|c| c * x).collect())
+    }
+}
+
+// The operations on owned polynomials forward to the ones on references.
+macro_rules! forward_owned_op {
+    ($($tr:ident $f:ident),*) => {$(
+        impl std::ops::$tr for Poly {
+            type Output = Poly;
+
+            fn $f(self, other: Poly) -> Poly {
+                std::ops::$tr::$f(&self, &other)
+            }
+        }
+    )*};
+}
+
+forward_owned_op!(Add add, Sub sub, Mul mul);
+
+impl std::ops::Neg for Poly {
+    type Output = Poly;
+
+    fn neg(self) -> Poly {
+        -&self
+    }
+}
+
+impl std::ops::Mul<f64> for Poly {
+    type Output = Poly;
+
+    fn mul(self, x: f64) -> Poly {
+        &self * x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_poly_arithmetic() {
+        // p = 1 + 2x, q = -1 + x^2
+        let p = Poly::new(vec![1., 2.]);
+        let q = Poly::new(vec![-1., 0., 1.]);
+
+        assert_eq!((&p + &q).coefficients(), &[0., 2., 1.]);
+        assert_eq!(&q - &q, Poly::zero());
+        assert_eq!((&p * &q).coefficients(), &[-1., -2., 1., 2.]);
+        assert_eq!(Poly::from_roots(&[1., -1.]), q);
+        assert_eq!(q.derivative().coefficients(), &[0., 2.]);
+        assert_eq!(q.derivative().antiderivative(-1.), q);
+        // p(q(x)) = 1 + 2 (x^2 - 1) = -1 + 2x^2
+        assert_eq!(p.compose(&q).coefficients(), &[-1., 0., 2.]);
+        assert_eq!(Poly::monomial(3., 2).degree(), Some(2));
+        assert_eq!(Poly::from(&[0., 0.][..]).degree(), None);
+    }
+}
@@ -21,7 +21,7 @@ Transactions on Mathematical Software, Vol. 20, No. 4, December, 1994, p. 494–
 use crate::ffi::FFI;
 use crate::Value;
 
-ffi_wrapper!(QRng, *mut sys::gsl_qrng, gsl_qrng_free);
+ffi_wrapper!(QRng, *mut sys::gsl_qrng, gsl_qrng_free; dimension: usize => 0;);
 
 impl QRng {
     /// This function returns a pointer to a newly-created instance of a quasi-random sequence
@@ -35,7 +35,9 @@ impl QRng {
         if tmp.is_null() {
             None
         } else {
-            Some(Self::wrap(tmp))
+            let mut q = Self::wrap(tmp);
+            q.dimension = d as usize;
+            Some(q)
         }
     }
 
@@ -49,12 +51,58 @@ impl QRng {
     /// This function stores the next point from the sequence generator self in the array x. The
     /// space available for x must match the dimension of the generator. The point x will lie in the
     /// range 0 < x_i < 1 for each x_i.
+    ///
+    /// Returns `Err(Value::BadLength)` if the length of `x` is not the dimension of the generator.
     #[doc(alias = "gsl_qrng_get")]
     pub fn get(&self, x: &mut [f64]) -> Result<(), Value> {
+        if x.len() != self.dimension() {
+            return Err(Value::BadLength);
+        }
         let ret = unsafe { sys::gsl_qrng_get(self.unwrap_shared(), x.as_mut_ptr()) };
         result_handler!(ret, ())
     }
 
+    /// Returns the dimension of the points of the sequence.
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// Stores the next point of the sequence in `x`, see [`QRng::get`].
+    pub fn sample_into(&mut self, x: &mut [f64]) -> Result<(), Value> {
+        self.get(x)
+    }
+
+    /// Returns the next point of the sequence.
+    ///
+    /// ```Rust
+    /// let mut q = QRng::new(QRngType::sobol(), 2).unwrap();
+    /// let p = q.sample().unwrap();
+    /// assert_eq!(p.len(), 2);
+    /// ```
+    pub fn sample(&mut self) -> Result<Vec<f64>, Value> {
+        let mut x = vec![0.; self.dimension()];
+        self.get(&mut x)?;
+        Ok(x)
+    }
+
+    /// Returns an iterator over the next points of the sequence.
+    ///
+    /// When the Sobol or Niederreiter sequences are exhausted, GSL calls the error handler,
+    /// which aborts the program by default. The iterator only stops there if the handler has
+    /// been turned off, see [`with_handler_off`](crate::error::with_handler_off).
+    pub fn iter(&mut self) -> impl Iterator<Item = Vec<f64>> + '_ {
+        std::iter::from_fn(move || self.sample().ok())
+    }
+
+    /// Discards the next `n` points of the sequence.
+    pub fn skip(&mut self, n: usize) -> Result<(), Value> {
+        let mut x = vec![0.; self.dimension()];
+        for _ in 0..n {
+            self.get(&mut x)?;
+        }
+        Ok(())
+    }
+
     /// This function returns a pointer to the name of the generator.
     #[doc(alias = "gsl_qrng_name")]
     pub fn name(&self) -> Option<String> {
@@ -107,7 +155,9 @@ impl QRng {
     #[doc(alias = "gsl_qrng_memcpy")]
     pub fn copy(&self, dest: &mut QRng) -> Result<(), Value> {
         let ret = unsafe { sys::gsl_qrng_memcpy(dest.unwrap_unique(), self.unwrap_shared()) };
-        result_handler!(ret, ())
+        result_handler!(ret, ())?;
+        dest.dimension = self.dimension;
+        Ok(())
     }
 }
 
@@ -116,7 +166,9 @@ impl Clone for QRng {
     /// generator `self`.
     #[doc(alias = "gsl_qrng_clone")]
     fn clone(&self) -> Self {
-        unsafe { Self::wrap(sys::gsl_qrng_clone(self.unwrap_shared())) }
+        let mut q = unsafe { Self::wrap(sys::gsl_qrng_clone(self.unwrap_shared())) };
+        q.dimension = self.dimension;
+        q
     }
 }
 
@@ -45,6 +45,11 @@ impl RanDiscrete {
         }
     }
 
+    /// Same as [`RanDiscrete::new`] but takes the weights from an iterator.
+    pub fn from_weights<I: IntoIterator<Item = f64>>(P: I) -> Option<RanDiscrete> {
+        RanDiscrete::new(&P.into_iter().collect::<Vec<_>>())
+    }
+
     /// After the new, above, has been called, you use this function to get the discrete random numbers.
     #[doc(alias = "gsl_ran_discrete")]
     pub fn discrete(&self, r: &mut Rng) -> usize {
@@ -56,7 +61,27 @@ impl RanDiscrete {
     /// so if K is large and you care about the original array `P[k]` used to create the lookup
     /// table, then you should just keep this original array `P[k]` around.
     #[doc(alias = "gsl_ran_discrete_pdf")]
-    pub fn discrete_pdf(&self, k: usize) -> f64 {
+    pub fn pdf(&self, k: usize) -> f64 {
         unsafe { sys::gsl_ran_discrete_pdf(k, self.unwrap_shared()) }
     }
+
+    #[deprecated(since = "8.0.0", note = "Please use `pdf` instead")]
+    pub fn discrete_pdf(&self, k: usize) -> f64 {
+        self.pdf(k)
+    }
+
+    /// Fills `result` with discrete random numbers, see [`RanDiscrete::discrete`]. This is
+    /// convenient to build empirical distributions:
+    ///
+    /// ```Rust
+    /// let mut samples = vec![0; 10_000];
+    /// g.sample_into(&mut r, &mut samples);
+    /// ```
+    pub fn sample_into(&self, r: &mut Rng, result: &mut [usize]) {
+        let g = self.unwrap_shared();
+        let r = r.unwrap_unique();
+        for x in result {
+            *x = unsafe { sys::gsl_ran_discrete(r, g) };
+        }
+    }
 }
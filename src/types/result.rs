@@ -2,9 +2,11 @@
 // A rust binding for the GSL library by Guillaume Gomez (guillaume1.gomez@gmail.com)
 //
 
+use std::mem::MaybeUninit;
+
 /// The error handling form of the special functions always calculate an error estimate along with the value of the result.
 /// Therefore, structures are provided for amalgamating a value and error estimate.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Result {
     /// Contains the value.
     pub val: f64,
@@ -27,6 +29,39 @@ impl Result {
     }
 }
 
+/// Only the values `val` are compared, the error estimates `err` are ignored. This allows to
+/// check the outcome of a special function against a reference value:
+///
+/// ```
+/// use approx::assert_relative_eq;
+///
+/// let expected = rgsl::types::Result { val: 0.7651976865579666, err: 0. };
+/// assert_relative_eq!(rgsl::bessel::J0_e(1.).unwrap(), expected);
+/// ```
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Result {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        f64::EPSILON
+    }
+
+    fn abs_diff_eq(&self, other: &Result, epsilon: f64) -> bool {
+        self.val.abs_diff_eq(&other.val, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Result {
+    fn default_max_relative() -> f64 {
+        f64::EPSILON
+    }
+
+    fn relative_eq(&self, other: &Result, epsilon: f64, max_relative: f64) -> bool {
+        self.val.relative_eq(&other.val, epsilon, max_relative)
+    }
+}
+
 impl From<::sys::gsl_sf_result> for Result {
     fn from(v: sys::gsl_sf_result) -> Self {
         Self {
@@ -62,6 +97,20 @@ impl ResultE10 {
             e10: 0i32,
         }
     }
+
+    /// Converts this extended range result into an ordinary [`Result`] by multiplying `val` and
+    /// `err` by `10^e10`.
+    ///
+    /// Returns [`Value::OverFlow`][crate::Value::OverFlow] (resp.
+    /// [`Value::UnderFlow`][crate::Value::UnderFlow]) if the value does not fit in a `f64`.
+    #[doc(alias = "gsl_sf_result_smash_e")]
+    pub fn smash(&self) -> std::result::Result<Result, crate::Value> {
+        let re = sys::gsl_sf_result_e10::from(*self);
+        let mut result = MaybeUninit::<sys::gsl_sf_result>::uninit();
+        let ret = unsafe { sys::gsl_sf_result_smash_e(&re, result.as_mut_ptr()) };
+
+        result_handler!(ret, unsafe { result.assume_init() }.into())
+    }
 }
 
 impl From<::sys::gsl_sf_result_e10> for ResultE10 {
@@ -73,3 +122,13 @@ impl From<::sys::gsl_sf_result_e10> for ResultE10 {
         }
     }
 }
+
+impl From<ResultE10> for ::sys::gsl_sf_result_e10 {
+    fn from(v: ResultE10) -> Self {
+        Self {
+            val: v.val,
+            err: v.err,
+            e10: v.e10,
+        }
+    }
+}
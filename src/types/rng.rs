@@ -71,7 +71,7 @@ Thanks to Makoto Matsumoto, Takuji Nishimura and Yoshiharu Kurita for making the
 !*/
 
 use crate::ffi::FFI;
-use crate::Value;
+use crate::{GaussianMethod, Value};
 use std::os::raw::c_ulong;
 
 ffi_wrapper!(Rng, *mut sys::gsl_rng, gsl_rng_free);
@@ -146,6 +146,65 @@ impl Rng {
         unsafe { sys::gsl_rng_uniform_int(self.unwrap_unique(), n as c_ulong) as _ }
     }
 
+    /// Returns an infinite iterator whose items are obtained by calling `f` on the generator. It
+    /// allows to use any sampling function with the standard iterator adaptors:
+    ///
+    /// ```Rust
+    /// let v: Vec<f64> = r.iter_with(|r| r.gamma(2., 1.)).take(100).collect();
+    /// ```
+    pub fn iter_with<'a, T, F>(&'a mut self, mut f: F) -> impl Iterator<Item = T> + 'a
+    where
+        F: FnMut(&mut Rng) -> T + 'a,
+    {
+        std::iter::repeat_with(move || f(self))
+    }
+
+    /// Returns an infinite iterator of numbers uniformly distributed in [0,1), see
+    /// [`Rng::uniform`].
+    pub fn iter_uniform(&mut self) -> impl Iterator<Item = f64> + '_ {
+        std::iter::repeat_with(move || self.uniform())
+    }
+
+    /// Returns an infinite iterator of numbers uniformly distributed in (0,1), see
+    /// [`Rng::uniform_pos`].
+    pub fn iter_uniform_pos(&mut self) -> impl Iterator<Item = f64> + '_ {
+        std::iter::repeat_with(move || self.uniform_pos())
+    }
+
+    /// Returns an infinite iterator of integers uniformly distributed in [0,n-1], see
+    /// [`Rng::uniform_int`].
+    pub fn iter_uniform_int(&mut self, n: usize) -> impl Iterator<Item = usize> + '_ {
+        std::iter::repeat_with(move || self.uniform_int(n))
+    }
+
+    /// Returns an infinite iterator of Gaussian random variates with mean zero and standard
+    /// deviation `sigma`, see [`Rng::gaussian`].
+    ///
+    /// ```Rust
+    /// let mean = r.iter_gaussian(2.).take(1000).sum::<f64>() / 1000.;
+    /// ```
+    pub fn iter_gaussian(&mut self, sigma: f64) -> impl Iterator<Item = f64> + '_ {
+        std::iter::repeat_with(move || self.gaussian(sigma))
+    }
+
+    /// Returns an infinite iterator of Gaussian random variates with mean zero and unit standard
+    /// deviation, see [`Rng::ugaussian`].
+    pub fn iter_ugaussian(&mut self) -> impl Iterator<Item = f64> + '_ {
+        std::iter::repeat_with(move || self.ugaussian())
+    }
+
+    /// Returns an infinite iterator of exponential random variates with mean `mu`, see
+    /// [`Rng::exponential`].
+    pub fn iter_exponential(&mut self, mu: f64) -> impl Iterator<Item = f64> + '_ {
+        std::iter::repeat_with(move || self.exponential(mu))
+    }
+
+    /// Returns an infinite iterator of Poisson random variates with mean `mu`, see
+    /// [`Rng::poisson`].
+    pub fn iter_poisson(&mut self, mu: f64) -> impl Iterator<Item = u32> + '_ {
+        std::iter::repeat_with(move || self.poisson(mu))
+    }
+
     /// This function returns a pointer to the name of the generator. For example,
     ///
     /// ```Rust
@@ -223,6 +282,104 @@ impl Rng {
         unsafe { sys::gsl_rng_size(self.unwrap_shared()) }
     }
 
+    /// This function writes the random number state of the random number generator r to the
+    /// stream stream in binary format. The return value is 0 for success and GSL_EFAILED if
+    /// there was a problem writing to the file. Since the data is written in the native binary
+    /// format it may not be portable between different architectures.
+    ///
+    /// Returns `Err(Value::Invalid)` if `stream` was not opened in write mode.
+    #[doc(alias = "gsl_rng_fwrite")]
+    pub fn fwrite(&self, stream: &mut crate::IOStream) -> Result<(), Value> {
+        if !stream.write_mode() {
+            return Err(Value::Invalid);
+        }
+        let ret = unsafe { sys::gsl_rng_fwrite(stream.as_raw(), self.unwrap_shared()) };
+        result_handler!(ret, ())
+    }
+
+    /// This function reads the random number state into the random number generator r from the
+    /// open stream stream in binary format. The random number generator r must be preinitialized
+    /// with the correct random number generator type since type information is not saved. The
+    /// return value is 0 for success and GSL_EFAILED if there was a problem reading from the
+    /// file. The data is assumed to have been written in the native binary format on the same
+    /// architecture.
+    ///
+    /// Returns `Err(Value::Invalid)` if `stream` was not opened in read mode.
+    #[doc(alias = "gsl_rng_fread")]
+    pub fn fread(&mut self, stream: &mut crate::IOStream) -> Result<(), Value> {
+        if stream.write_mode() {
+            return Err(Value::Invalid);
+        }
+        let ret = unsafe { sys::gsl_rng_fread(stream.as_raw(), self.unwrap_unique()) };
+        result_handler!(ret, ())
+    }
+
+    /// Returns a copy of the state of the generator, in the same native binary format as
+    /// [`Rng::fwrite`]. Restoring it with [`Rng::from_bytes`] on a generator of the same type
+    /// continues the stream of random numbers from this point.
+    ///
+    /// ```Rust
+    /// let checkpoint = r.to_bytes();
+    /// let x = r.uniform();
+    /// r.from_bytes(&checkpoint).unwrap();
+    /// assert_eq!(x, r.uniform());
+    /// ```
+    ///
+    /// Panics if the state cannot be copied through a temporary file.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        use sys::libc;
+
+        // The padding bytes of the state may be uninitialized, so they cannot be read from
+        // Rust: the state is written by `gsl_rng_fwrite` to a temporary file and read back.
+        let mut bytes = vec![0u8; self.size()];
+        unsafe {
+            let f = libc::tmpfile();
+            assert!(
+                !f.is_null(),
+                "rgsl::Rng::to_bytes: cannot create a temporary file"
+            );
+            let ok = sys::gsl_rng_fwrite(f, self.unwrap_shared()) == sys::GSL_SUCCESS && {
+                libc::rewind(f);
+                libc::fread(bytes.as_mut_ptr() as *mut libc::c_void, 1, bytes.len(), f)
+                    == bytes.len()
+            };
+            libc::fclose(f);
+            assert!(ok, "rgsl::Rng::to_bytes: cannot copy the state");
+        }
+        bytes
+    }
+
+    /// Restores a state obtained with [`Rng::to_bytes`]. The generator must be of the same type
+    /// as the one which produced `bytes` since the type information is not saved.
+    ///
+    /// Returns `Err(Value::BadLength)` if the length of `bytes` is not the size of the state of
+    /// the generator.
+    pub fn from_bytes(&mut self, bytes: &[u8]) -> Result<(), Value> {
+        if bytes.len() != self.size() {
+            return Err(Value::BadLength);
+        }
+        unsafe {
+            let state = sys::gsl_rng_state(self.unwrap_shared()) as *mut u8;
+            std::ptr::copy_nonoverlapping(bytes.as_ptr(), state, bytes.len());
+        }
+        Ok(())
+    }
+
+    /// Writes the state of the generator to `w`, see [`Rng::to_bytes`].
+    pub fn write_state<W: std::io::Write>(&self, mut w: W) -> std::io::Result<()> {
+        w.write_all(&self.to_bytes())
+    }
+
+    /// Reads the state of the generator from `r`, see [`Rng::from_bytes`]. Exactly
+    /// [`Rng::size`] bytes are read.
+    pub fn read_state<R: std::io::Read>(&mut self, mut r: R) -> std::io::Result<()> {
+        let mut bytes = vec![0; self.size()];
+        r.read_exact(&mut bytes)?;
+        self.from_bytes(&bytes).map_err(|_| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, "invalid generator state")
+        })
+    }
+
     /// Equivalent to DefaultRngSeed
     pub fn default_seed() -> usize {
         unsafe { sys::gsl_rng_default_seed as _ }
@@ -243,6 +400,8 @@ impl Rng {
     ///
     /// gsl_ran_shuffle (r, a, 52, sizeof (int));
     /// ```
+    ///
+    /// The elements of `base` are moved around, so it can hold values of any type.
     #[doc(alias = "gsl_ran_shuffle")]
     pub fn shuffle<T>(&mut self, base: &mut [T]) {
         unsafe {
@@ -273,9 +432,17 @@ impl Rng {
     ///
     /// gsl_ran_choose (r, a, 3, b, 100, sizeof (double));
     /// ```
+    ///
+    /// The number of objects k is the length of `dest` and n is the length of `src`. The objects
+    /// are copied bitwise, hence the `Copy` bound.
+    ///
+    /// Panics if `dest` is longer than `src`.
     #[doc(alias = "gsl_ran_choose")]
-    pub fn choose<T>(&mut self, src: &[T], dest: &mut [T]) -> Result<(), Value> {
-        assert!(src.len() <= dest.len());
+    pub fn choose<T: Copy>(&mut self, src: &[T], dest: &mut [T]) -> Result<(), Value> {
+        assert!(
+            dest.len() <= src.len(),
+            "rgsl::Rng::choose: `dest` cannot be longer than `src`"
+        );
         let ret = unsafe {
             sys::gsl_ran_choose(
                 self.unwrap_unique(),
@@ -291,9 +458,14 @@ impl Rng {
 
     /// This function is like gsl_ran_choose but samples k items from the original array of n items src with replacement, so the same object can appear more
     /// than once in the output sequence dest. There is no requirement that k be less than n in this case.
+    ///
+    /// Panics if `src` is empty while `dest` is not.
     #[doc(alias = "gsl_ran_sample")]
-    pub fn sample<T>(&mut self, src: &[T], dest: &mut [T]) {
-        assert!(src.len() <= dest.len());
+    pub fn sample<T: Copy>(&mut self, src: &[T], dest: &mut [T]) {
+        assert!(
+            !src.is_empty() || dest.is_empty(),
+            "rgsl::Rng::sample: cannot sample from an empty `src`"
+        );
         unsafe {
             sys::gsl_ran_sample(
                 self.unwrap_unique(),
@@ -333,6 +505,15 @@ impl Rng {
         }
     }
 
+    /// Same as [`multinomial`](Self::multinomial) but returns the counts `n[K]` in a newly
+    /// allocated vector.
+    #[doc(alias = "gsl_ran_multinomial")]
+    pub fn multinomial_vec(&mut self, N: u32, p: &[f64]) -> Vec<u32> {
+        let mut n = vec![0; p.len()];
+        self.multinomial(N, p, &mut n);
+        n
+    }
+
     /// This function returns an array of K random variates from a Dirichlet distribution of order K-1. The distribution function is
     ///
     /// p(\theta_1, ..., \theta_K) d\theta_1 ... d\theta_K =
@@ -386,6 +567,20 @@ impl Rng {
         unsafe { sys::gsl_ran_binomial(self.unwrap_unique(), p, n) }
     }
 
+    /// This function returns a binomial variate using the algorithm from Knuth (vol 2), based on
+    /// the beta distribution.
+    #[doc(alias = "gsl_ran_binomial_knuth")]
+    pub fn binomial_knuth(&mut self, p: f64, n: u32) -> u32 {
+        unsafe { sys::gsl_ran_binomial_knuth(self.unwrap_unique(), p, n) }
+    }
+
+    /// This function returns a binomial variate using the TPE algorithm of Kachitvichyanukul and
+    /// Schmeiser. It is the algorithm used by [`binomial`](Self::binomial).
+    #[doc(alias = "gsl_ran_binomial_tpe")]
+    pub fn binomial_tpe(&mut self, p: f64, n: u32) -> u32 {
+        unsafe { sys::gsl_ran_binomial_tpe(self.unwrap_unique(), p, n) }
+    }
+
     /// This function generates a pair of correlated Gaussian variates, with mean zero, correlation coefficient rho and standard deviations sigma_x and sigma_y in the x and y directions.
     /// The probability distribution for bivariate Gaussian random variates is,
     ///
@@ -410,6 +605,36 @@ impl Rng {
         (x, y)
     }
 
+    /// Fills `x` and `y` with pairs of correlated Gaussian variates, see
+    /// [`Rng::bivariate_gaussian`]. This is a quick way to generate correlated noise:
+    ///
+    /// ```Rust
+    /// let mut x = vec![0.; 1000];
+    /// let mut y = vec![0.; 1000];
+    /// r.bivariate_gaussian_into(1., 2., 0.9, &mut x, &mut y);
+    /// ```
+    ///
+    /// Panics if `x` and `y` don't have the same length.
+    #[doc(alias = "gsl_ran_bivariate_gaussian")]
+    pub fn bivariate_gaussian_into(
+        &mut self,
+        sigma_x: f64,
+        sigma_y: f64,
+        rho: f64,
+        x: &mut [f64],
+        y: &mut [f64],
+    ) {
+        assert_eq!(
+            x.len(),
+            y.len(),
+            "rgsl::Rng::bivariate_gaussian_into: `x` and `y` must have the same length"
+        );
+        let r = self.unwrap_unique();
+        for (x, y) in x.iter_mut().zip(y.iter_mut()) {
+            unsafe { sys::gsl_ran_bivariate_gaussian(r, sigma_x, sigma_y, rho, x, y) };
+        }
+    }
+
     /// This function returns a random variate from the Cauchy distribution with scale parameter a. The probability distribution for Cauchy random variates is,
     ///
     /// p(x) dx = {1 \over a\pi (1 + (x/a)^2) } dx
@@ -479,6 +704,17 @@ impl Rng {
         unsafe { sys::gsl_ran_flat(self.unwrap_unique(), a, b) }
     }
 
+    /// This function returns a random variate from the Erlang distribution with scale parameter
+    /// a and order n. The distribution function is,
+    ///
+    /// p(x) dx = {x^{n-1} \over \Gamma(n) a^n} \exp(-x/a) dx
+    ///
+    /// for x >= 0. It is the gamma distribution with an integer shape parameter.
+    #[doc(alias = "gsl_ran_erlang")]
+    pub fn erlang(&mut self, a: f64, n: f64) -> f64 {
+        unsafe { sys::gsl_ran_erlang(self.unwrap_unique(), a, n) }
+    }
+
     /// This function returns a random variate from the gamma distribution. The distribution function is,
     ///
     /// p(x) dx = {1 over Gamma(a) b^a} x^{a-1} e^{-x/b} dx
@@ -499,6 +735,13 @@ impl Rng {
         unsafe { sys::gsl_ran_gamma_knuth(self.unwrap_unique(), a, b) }
     }
 
+    /// This function returns a gamma variate with an integer shape parameter a and unit scale,
+    /// computed as a sum of exponential variates for small a.
+    #[doc(alias = "gsl_ran_gamma_int")]
+    pub fn gamma_int(&mut self, a: u32) -> f64 {
+        unsafe { sys::gsl_ran_gamma_int(self.unwrap_unique(), a) }
+    }
+
     /// This function returns a Gaussian random variate, with mean zero and standard deviation sigma.
     /// The probability distribution for Gaussian random variates is,
     ///
@@ -510,18 +753,33 @@ impl Rng {
         unsafe { sys::gsl_ran_gaussian(self.unwrap_unique(), sigma) }
     }
 
+    /// This function computes a Gaussian random variate using the alternative Marsaglia-Tsang ziggurat method.
+    /// The Ziggurat algorithm is the fastest available algorithm in most cases.
     #[doc(alias = "gsl_ran_gaussian_ziggurat")]
     pub fn gaussian_ziggurat(&mut self, sigma: f64) -> f64 {
         unsafe { sys::gsl_ran_gaussian_ziggurat(self.unwrap_unique(), sigma) }
     }
 
-    /// This function computes a Gaussian random variate using the alternative Marsaglia-Tsang ziggurat and Kinderman-Monahan-Leva ratio methods.
-    /// The Ziggurat algorithm is the fastest available algorithm in most cases.
+    /// This function computes a Gaussian random variate using the alternative Kinderman-Monahan-Leva ratio method.
     #[doc(alias = "gsl_ran_gaussian_ratio_method")]
     pub fn gaussian_ratio_method(&mut self, sigma: f64) -> f64 {
         unsafe { sys::gsl_ran_gaussian_ratio_method(self.unwrap_unique(), sigma) }
     }
 
+    /// Returns a Gaussian random variate with standard deviation `sigma`, computed with the
+    /// algorithm `method`. This allows to select the algorithm at runtime:
+    ///
+    /// ```Rust
+    /// let x = r.gaussian_with(GaussianMethod::Ziggurat, 2.);
+    /// ```
+    pub fn gaussian_with(&mut self, method: GaussianMethod, sigma: f64) -> f64 {
+        match method {
+            GaussianMethod::BoxMuller => self.gaussian(sigma),
+            GaussianMethod::Ziggurat => self.gaussian_ziggurat(sigma),
+            GaussianMethod::RatioMethod => self.gaussian_ratio_method(sigma),
+        }
+    }
+
     /// This function computes results for the unit Gaussian distribution.
     /// They are equivalent to the functions above with a standard deviation of one, sigma = 1.
     #[doc(alias = "gsl_ran_ugaussian")]
@@ -723,6 +981,20 @@ impl Rng {
         unsafe { sys::gsl_ran_poisson(self.unwrap_unique(), mu) }
     }
 
+    /// This function fills `array` with independent random integers from the Poisson
+    /// distribution with mean mu.
+    #[doc(alias = "gsl_ran_poisson_array")]
+    pub fn poisson_array(&mut self, mu: f64, array: &mut [u32]) {
+        unsafe {
+            sys::gsl_ran_poisson_array(
+                self.unwrap_unique(),
+                array.len() as _,
+                array.as_mut_ptr(),
+                mu,
+            )
+        }
+    }
+
     /// This function returns a random variate from the Rayleigh distribution with scale parameter sigma. The distribution is,
     ///
     /// p(x) dx = {x \over \sigma^2} \exp(- x^2/(2 \sigma^2)) dx
@@ -761,14 +1033,10 @@ impl Rng {
         (x, y)
     }
 
-    /// This function returns a random direction vector v = (x,y) in two dimensions. The vector is normalized such that |v|^2 = x^2 + y^2 = 1.
-    /// The obvious way to do this is to take a uniform random number between 0 and 2\pi and let x and y be the sine and cosine respectively.
-    /// Two trig functions would have been expensive in the old days, but with modern hardware implementations, this is sometimes the fastest way to go.
-    /// This is the case for the Pentium (but not the case for the Sun Sparcstation).
-    /// One can avoid the trig evaluations by choosing x and y in the interior of a unit circle (choose them at random from the interior of the enclosing square,
-    /// and then reject those that are outside the unit circle), and then dividing by \sqrt{x^2 + y^2}. A much cleverer approach, attributed to von Neumann
-    /// (See Knuth, v2, 3rd ed, p140, exercise 23), requires neither trig nor a square root. In this approach, u and v are chosen at random from the interior of
-    /// a unit circle, and then x=(u^2-v^2)/(u^2+v^2) and y=2uv/(u^2+v^2).
+    /// This function returns a random direction vector v = (x,y) in two dimensions, like
+    /// [`Rng::dir_2d`], but computed with the obvious trigonometric method: a uniform random
+    /// angle between 0 and 2\pi is chosen and x and y are its cosine and sine. Depending on the
+    /// hardware, this may be faster than the rejection method of [`Rng::dir_2d`].
     ///
     /// Returns `(x, y)`.
     #[doc(alias = "gsl_ran_dir_2d_trig_method")]
@@ -796,6 +1064,8 @@ impl Rng {
     /// This function returns a random direction vector v = (x_1,x_2,...,x_n) in n dimensions. The vector is normalized such that |v|^2 = x_1^2 + x_2^2 + ... + x_n^2 = 1.
     /// The method uses the fact that a multivariate Gaussian distribution is spherically symmetric. Each component is generated to have a Gaussian distribution, and then
     /// the components are normalized. The method is described by Knuth, v2, 3rd ed, p135–136, and attributed to G. W. Brown, Modern Mathematics for the Engineer (1956).
+    ///
+    /// The dimension n is the length of `x`.
     #[doc(alias = "gsl_ran_dir_nd")]
     pub fn dir_nd(&mut self, x: &mut [f64]) {
         unsafe { sys::gsl_ran_dir_nd(self.unwrap_unique(), x.len() as _, x.as_mut_ptr()) }
@@ -832,6 +1102,257 @@ impl Clone for Rng {
     }
 }
 
+#[cfg(feature = "rand")]
+impl Rng {
+    /// Returns `n <= 64` uniformly distributed random bits in the low bits of the result. The
+    /// generator is called as many times as needed, each call contributing the largest number of
+    /// bits its range `[min, max]` can provide without bias.
+    fn random_bits(&mut self, n: u32) -> u64 {
+        let (min, max) = (self.min() as u64, self.max() as u64);
+        let mut value = 0u64;
+        let mut filled = 0;
+        while filled < n {
+            let (v, bits) = if min == 0 && max & max.wrapping_add(1) == 0 {
+                // The range is [0, 2^k - 1], all outputs are usable as is.
+                (self.get() as u64, 64 - max.leading_zeros())
+            } else {
+                let bits = 63 - (max - min).leading_zeros();
+                (self.uniform_int(1 << bits) as u64, bits)
+            };
+            value |= v << filled;
+            filled += bits;
+        }
+        if n < 64 {
+            value & ((1 << n) - 1)
+        } else {
+            value
+        }
+    }
+
+    /// Runs `f` with a GSL generator whose uniform variates are drawn from the `rand` generator
+    /// `rng`. This allows to sample any GSL distribution using a source of randomness coming
+    /// from the `rand` ecosystem:
+    ///
+    /// ```
+    /// use rgsl::{Rng, RngType};
+    ///
+    /// // Any `rand_core::RngCore` works, including a GSL generator.
+    /// let mut source = Rng::new(RngType::default()).unwrap();
+    /// let x = Rng::with_rand_core(&mut source, |r| r.gaussian(1.));
+    /// ```
+    ///
+    /// The GSL generator returns integers in `[0, 2^32 - 1]` (from `next_u32`) and doubles with
+    /// 53 random bits (from `next_u64`). Seeding it with [`Rng::set`] has no effect.
+    ///
+    /// The generator draws from `rng` only: nested calls each get their own source. If `rng`
+    /// panics, the GSL generator returns zeros until `f` returns and the panic is then resumed.
+    /// The generator must not be used once `f` returns: its clones are only valid inside `f` and
+    /// the program aborts if they are used afterwards.
+    pub fn with_rand_core<R, T, F>(rng: &mut R, f: F) -> T
+    where
+        R: rand_core::RngCore,
+        F: FnOnce(&mut Rng) -> T,
+    {
+        let mut call = rand_compat::Call::new(rng);
+        let ptr = unsafe { sys::gsl_rng_alloc(&rand_compat::RAND_CORE_TYPE.0) };
+        if ptr.is_null() {
+            panic!("rgsl::Rng::with_rand_core: cannot allocate the generator");
+        }
+        let mut r = Rng::wrap(ptr);
+        let live = call.attach(&mut r);
+        let ret = f(&mut r);
+        drop(live);
+        call.resume_panic();
+        ret
+    }
+}
+
+/// `rand_core` generator built on the GSL one, so that the GSL generators can be used wherever a
+/// `rand` generator is expected.
+///
+/// Full 32 and 64-bit words are built from as many calls to the GSL generator as needed, so
+/// generators whose range is smaller than `[0, 2^32 - 1]` can be used too.
+#[cfg(feature = "rand")]
+impl rand_core::RngCore for Rng {
+    fn next_u32(&mut self) -> u32 {
+        self.random_bits(32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.random_bits(64)
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        rand_core::impls::fill_bytes_via_next(self, dst)
+    }
+}
+
+/// Creates a MT19937 generator (see [`algorithms::mt19937`]). The seed is passed to
+/// [`Rng::set`], so `Rng::seed_from_u64(s)` produces the same stream as a generator seeded with
+/// `gsl_rng_set(r, s)`.
+#[cfg(feature = "rand")]
+impl rand_core::SeedableRng for Rng {
+    type Seed = [u8; 4];
+
+    fn from_seed(seed: Self::Seed) -> Rng {
+        Rng::seed_from_u64(u32::from_le_bytes(seed) as u64)
+    }
+
+    fn seed_from_u64(state: u64) -> Rng {
+        let mut r = Rng::new(algorithms::mt19937())
+            .expect("rgsl::Rng::seed_from_u64: cannot allocate the generator");
+        r.set(state as usize);
+        r
+    }
+}
+
+#[cfg(feature = "rand")]
+mod rand_compat {
+    use super::Rng;
+    use crate::ffi::FFI;
+    use std::any::Any;
+    use std::cell::RefCell;
+    use std::os::raw::{c_ulong, c_void};
+    use std::panic::{self, AssertUnwindSafe};
+
+    pub(super) struct RngTypeStatic(pub(super) sys::gsl_rng_type);
+
+    // The type description is never modified.
+    unsafe impl Sync for RngTypeStatic {}
+
+    /// The state of a generator is the address of the `Call` it draws from.
+    type State = *mut Call<'static>;
+
+    pub(super) static RAND_CORE_TYPE: RngTypeStatic = RngTypeStatic(sys::gsl_rng_type {
+        name: c"rand_core".as_ptr(),
+        max: u32::MAX as c_ulong,
+        min: 0,
+        size: std::mem::size_of::<State>(),
+        set: Some(set),
+        get: Some(get),
+        get_double: Some(get_double),
+    });
+
+    thread_local! {
+        // Calls of `with_rand_core` still running on this thread. `Rng` is not `Send`, so the
+        // generators (and their clones) of a call never leave its thread.
+        static LIVE: RefCell<Vec<State>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// The `rand` generator of one `with_rand_core` call.
+    pub(super) struct Call<'a> {
+        rng: &'a mut dyn rand_core::RngCore,
+        panicked: Option<Box<dyn Any + Send>>,
+    }
+
+    /// Keeps a `Call` reachable from its generators until it is dropped.
+    pub(super) struct Live(State);
+
+    impl<'a> Call<'a> {
+        pub(super) fn new(rng: &'a mut dyn rand_core::RngCore) -> Call<'a> {
+            Call {
+                rng,
+                panicked: None,
+            }
+        }
+
+        /// Makes `r` (and its future clones) draw from `self` until `Live` is dropped.
+        pub(super) fn attach(&mut self, r: &mut Rng) -> Live {
+            // The lifetime is erased but the pointer is only dereferenced while it is listed in
+            // `LIVE`, and `with_rand_core` drops the returned `Live` before `self`.
+            let call = self as *mut Call<'a> as State;
+            unsafe { *(sys::gsl_rng_state(r.unwrap_shared()) as *mut State) = call };
+            LIVE.with(|l| l.borrow_mut().push(call));
+            Live(call)
+        }
+
+        /// Resumes the panic of the `rand` generator, if any.
+        pub(super) fn resume_panic(&mut self) {
+            if let Some(e) = self.panicked.take() {
+                panic::resume_unwind(e);
+            }
+        }
+    }
+
+    impl Drop for Live {
+        fn drop(&mut self) {
+            LIVE.with(|l| l.borrow_mut().retain(|&c| c != self.0));
+        }
+    }
+
+    /// Draws from the `Call` of `state`. Panics cannot cross the C frames of GSL, so they are
+    /// recorded to be resumed by `with_rand_core` and `0` is returned meanwhile.
+    fn draw<T: Default>(state: *mut c_void, f: impl FnOnce(&mut dyn rand_core::RngCore) -> T) -> T {
+        let call = unsafe { *(state as *const State) };
+        if !LIVE.with(|l| l.borrow().contains(&call)) {
+            eprintln!(
+                "rgsl::Rng::with_rand_core: the generator cannot be used outside of its closure"
+            );
+            std::process::abort();
+        }
+        let call = unsafe { &mut *call };
+        if call.panicked.is_some() {
+            return T::default();
+        }
+        match panic::catch_unwind(AssertUnwindSafe(|| f(&mut *call.rng))) {
+            Ok(v) => v,
+            Err(e) => {
+                call.panicked = Some(e);
+                T::default()
+            }
+        }
+    }
+
+    unsafe extern "C" fn set(_state: *mut c_void, _seed: c_ulong) {}
+
+    unsafe extern "C" fn get(state: *mut c_void) -> c_ulong {
+        draw(state, |rng| rng.next_u32() as c_ulong)
+    }
+
+    unsafe extern "C" fn get_double(state: *mut c_void) -> f64 {
+        draw(state, |rng| {
+            (rng.next_u64() >> 11) as f64 * (1. / (1u64 << 53) as f64)
+        })
+    }
+}
+
+/// The generator is serialized as the name of its type together with its state (see
+/// [`Rng::to_bytes`]), so it can be restored without knowing its type beforehand.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Rng {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut s = serializer.serialize_struct("Rng", 2)?;
+        s.serialize_field("name", &self.name())?;
+        s.serialize_field("state", &self.to_bytes())?;
+        s.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Rng {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Rng, D::Error> {
+        use serde::de::Error;
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Rng")]
+        struct RngState {
+            name: String,
+            state: Vec<u8>,
+        }
+
+        let RngState { name, state } = RngState::deserialize(deserializer)?;
+        let t = RngType::from_name(&name)
+            .ok_or_else(|| D::Error::custom(format!("unknown generator type `{name}`")))?;
+        let mut r = Rng::new(t).ok_or_else(|| D::Error::custom("cannot allocate the generator"))?;
+        r.from_bytes(&state).map_err(|_| {
+            D::Error::invalid_length(state.len(), &"the state size of the generator")
+        })?;
+        Ok(r)
+    }
+}
+
 ffi_wrapper!(RngType, *const sys::gsl_rng_type);
 
 impl RngType {
@@ -896,24 +1417,37 @@ impl RngType {
     /// ```
     #[doc(alias = "gsl_rng_types_setup")]
     pub fn types_setup() -> Vec<RngType> {
-        let ptr = unsafe { sys::gsl_rng_types_setup() };
-        let mut ret = Vec::new();
+        RngType::iter().collect()
+    }
 
-        if !ptr.is_null() {
-            unsafe {
-                let mut it = 0;
-                loop {
-                    let tmp = ptr.offset(it);
-
-                    if (*tmp).is_null() {
-                        break;
-                    }
-                    ret.push(RngType::wrap(*tmp as *mut sys::gsl_rng_type));
-                    it += 1;
-                }
+    /// Returns an iterator over all the available generator types. Their ranges are given by
+    /// [`RngType::min`] and [`RngType::max`]:
+    ///
+    /// ```Rust
+    /// for t in RngType::iter() {
+    ///     println!("{}: [{}, {}]", t.name(), t.min(), t.max());
+    /// }
+    /// ```
+    #[doc(alias = "gsl_rng_types_setup")]
+    pub fn iter() -> impl Iterator<Item = RngType> {
+        let mut ptr = unsafe { sys::gsl_rng_types_setup() };
+
+        std::iter::from_fn(move || unsafe {
+            if ptr.is_null() || (*ptr).is_null() {
+                None
+            } else {
+                let t = RngType::wrap(*ptr as *mut sys::gsl_rng_type);
+                ptr = ptr.offset(1);
+                Some(t)
             }
-        }
-        ret
+        })
+    }
+
+    /// Returns the generator type called `name` (as returned by [`RngType::name`]), such as
+    /// `"taus2"` or `"mt19937"`, or `None` if there is no such generator. This allows to select
+    /// the generator at runtime, from a configuration file for example.
+    pub fn from_name(name: &str) -> Option<RngType> {
+        RngType::iter().find(|t| t.name() == name)
     }
 
     /// This function reads the environment variables GSL_RNG_TYPE and GSL_RNG_SEED and uses their values to set the corresponding library variables gsl_rng_default and gsl_rng_default_seed. These global variables are defined as follows,
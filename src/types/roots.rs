@@ -42,6 +42,7 @@ its derivative (hence the name fdf) to be supplied by the user.
 !*/
 
 use crate::ffi::FFI;
+use crate::types::fdf::{Callbacks, Fdf};
 use crate::Value;
 use sys::libc::{c_double, c_void};
 
@@ -143,8 +144,10 @@ impl<'a> RootFSolver<'a> {
         x_lower: f64,
         x_upper: f64,
     ) -> Result<(), Value> {
-        self.inner_call = wrap_callback!(f, F + 'a);
-        self.inner_closure = Some(Box::new(f));
+        // GSL keeps a pointer to the closure, so it must not move once boxed.
+        let f = Box::new(f);
+        self.inner_call = wrap_callback!(*f, F + 'a);
+        self.inner_closure = Some(f);
 
         let ret = unsafe {
             sys::gsl_root_fsolver_set(self.unwrap_unique(), &mut self.inner_call, x_lower, x_upper)
@@ -237,9 +240,7 @@ ffi_wrapper!(
     *mut sys::gsl_root_fdfsolver,
     gsl_root_fdfsolver_free
     ;inner_call: sys::gsl_function_fdf_struct => sys::gsl_function_fdf_struct{f: None, df: None, fdf: None, params: std::ptr::null_mut()};
-    ;inner_f_closure: Option<Box<dyn Fn(f64) -> f64 + 'a>> => None;
-    ;inner_df_closure: Option<Box<dyn Fn(f64) -> f64 + 'a>> => None;
-    ;inner_fdf_closure: Option<Box<dyn Fn(f64, &mut f64, &mut f64) + 'a>> => None;,
+    ;inner_closure: Option<Box<dyn Callbacks + 'a>> => None;,
     "This is a workspace for finding roots using methods which do require derivatives."
 );
 
@@ -275,41 +276,37 @@ impl<'a> RootFdfSolver<'a> {
         root: f64,
     ) -> Result<(), Value> {
         // convert rust functions to C
-        unsafe extern "C" fn inner_f<'a, F: Fn(f64) -> f64 + 'a>(
+        unsafe extern "C" fn inner_f<F: Fn(f64) -> f64, DF, FDF>(
             x: c_double,
             params: *mut c_void,
         ) -> f64 {
-            let f: &F = &*(params as *const F);
-            f(x)
+            (Fdf::<F, DF, FDF>::from_params(params).f)(x)
         }
 
-        unsafe extern "C" fn inner_df<'a, DF: Fn(f64) -> f64 + 'a>(
+        unsafe extern "C" fn inner_df<F, DF: Fn(f64) -> f64, FDF>(
             x: c_double,
             params: *mut c_void,
         ) -> f64 {
-            let df: &DF = &*(params as *const DF);
-            df(x)
+            (Fdf::<F, DF, FDF>::from_params(params).df)(x)
         }
 
-        unsafe extern "C" fn inner_fdf<'a, FDF: Fn(f64, &mut f64, &mut f64) + 'a>(
+        unsafe extern "C" fn inner_fdf<F, DF, FDF: Fn(f64, &mut f64, &mut f64)>(
             x: c_double,
             params: *mut c_void,
             y: *mut c_double,
             dy: *mut c_double,
         ) {
-            let fdf: &FDF = &*(params as *const FDF);
-            fdf(x, &mut *y, &mut *dy);
+            (Fdf::<F, DF, FDF>::from_params(params).fdf)(x, &mut *y, &mut *dy);
         }
 
+        let (callbacks, params) = Fdf::boxed(f, df, fdf);
         self.inner_call = sys::gsl_function_fdf {
-            f: Some(inner_f::<F>),
-            df: Some(inner_df::<DF>),
-            fdf: Some(inner_fdf::<FDF>),
-            params: &(&f, &df, &fdf) as *const _ as *mut _,
+            f: Some(inner_f::<F, DF, FDF>),
+            df: Some(inner_df::<F, DF, FDF>),
+            fdf: Some(inner_fdf::<F, DF, FDF>),
+            params,
         };
-        self.inner_f_closure = Some(Box::new(f));
-        self.inner_df_closure = Some(Box::new(df));
-        self.inner_fdf_closure = Some(Box::new(fdf));
+        self.inner_closure = Some(callbacks);
 
         let ret = unsafe {
             sys::gsl_root_fdfsolver_set(self.unwrap_unique(), &mut self.inner_call, root)
@@ -506,4 +503,61 @@ mod test {
         }
         assert!(matches!(status, Value::Success))
     }
+
+    #[test]
+    fn test_find() {
+        use crate::roots::{find, find_with, RootParams};
+
+        // The interval is enlarged until it brackets the root.
+        let (x, err) = find(quadratic_test_fn, 0.0, 1.0).unwrap();
+        assert!((x - 5.0_f64.sqrt()).abs() <= err);
+        assert!(err < 1e-9);
+
+        assert_eq!(find(|x| x, 0.0, 1.0), Ok((0.0, 0.0)));
+        assert_eq!(find(quadratic_test_fn, 1.0, 1.0), Err(Value::Invalid));
+        assert_eq!(find(|x| 1.0 / x, 0.0, 1.0), Err(Value::BadFunction));
+
+        let params = RootParams::default().max_expansions(3);
+        assert_eq!(
+            find_with(
+                |x| x * x + 1.0,
+                -1.0,
+                1.0,
+                RootFSolverType::brent(),
+                &params
+            ),
+            Err(Value::Invalid)
+        );
+        let params = RootParams::default().max_iter(1);
+        assert_eq!(
+            find_with(
+                quadratic_test_fn,
+                0.0,
+                5.0,
+                RootFSolverType::bisection(),
+                &params
+            ),
+            Err(Value::MaxIteration)
+        );
+    }
+
+    #[test]
+    fn test_newton() {
+        use crate::roots::{newton, RootParams};
+
+        let params = RootParams::default();
+        let (x, err) = newton(quadratic_test_fn, quadratic_test_fn_df, 5.0, &params).unwrap();
+        assert!((x - 5.0_f64.sqrt()).abs() < 1e-10);
+        assert!(err < 1e-6);
+
+        assert_eq!(
+            newton(
+                quadratic_test_fn,
+                quadratic_test_fn_df,
+                5.0,
+                &params.max_iter(1)
+            ),
+            Err(Value::MaxIteration)
+        );
+    }
 }
@@ -36,12 +36,83 @@ impl RStatQuantileWorkspace {
         result_handler!(ret, ())
     }
 
+    /// Adds all the values of `xs`, see [`RStatQuantileWorkspace::add`].
+    #[doc(alias = "gsl_rstat_quantile_add")]
+    pub fn add_slice(&mut self, xs: &[f64]) -> Result<(), Value> {
+        let w = self.unwrap_unique();
+        for &x in xs {
+            let ret = unsafe { sys::gsl_rstat_quantile_add(x, w) };
+            result_handler!(ret, ())?;
+        }
+        Ok(())
+    }
+
     #[doc(alias = "gsl_rstat_quantile_get")]
     pub fn get(&mut self) -> f64 {
         unsafe { sys::gsl_rstat_quantile_get(self.unwrap_unique()) }
     }
 }
 
+/// Running estimates of several quantiles of the same data, using one
+/// [`RStatQuantileWorkspace`] per probability.
+///
+/// ```
+/// use rgsl::RStatMultiQuantileWorkspace;
+///
+/// let mut q = RStatMultiQuantileWorkspace::new(&[0.25, 0.5, 0.75]).unwrap();
+/// q.add_slice(&[1., 2., 3., 4., 5.]).unwrap();
+/// assert_eq!(q.get()[1], 3.);
+/// ```
+pub struct RStatMultiQuantileWorkspace {
+    p: Vec<f64>,
+    workspaces: Vec<RStatQuantileWorkspace>,
+}
+
+impl RStatMultiQuantileWorkspace {
+    /// Creates the workspaces estimating the quantiles of each of the probabilities `ps`.
+    #[doc(alias = "gsl_rstat_quantile_alloc")]
+    pub fn new(ps: &[f64]) -> Option<Self> {
+        let workspaces = ps
+            .iter()
+            .map(|&p| RStatQuantileWorkspace::new(p))
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self {
+            p: ps.to_vec(),
+            workspaces,
+        })
+    }
+
+    /// Returns the probabilities of the estimated quantiles.
+    pub fn probabilities(&self) -> &[f64] {
+        &self.p
+    }
+
+    #[cfg(feature = "v2_2")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v2_2")))]
+    #[doc(alias = "gsl_rstat_quantile_reset")]
+    pub fn reset(&mut self) -> Result<(), Value> {
+        self.workspaces.iter_mut().try_for_each(|w| w.reset())
+    }
+
+    #[doc(alias = "gsl_rstat_quantile_add")]
+    pub fn add(&mut self, x: f64) -> Result<(), Value> {
+        self.workspaces.iter_mut().try_for_each(|w| w.add(x))
+    }
+
+    /// Adds all the values of `xs` to the estimates of all the quantiles.
+    #[doc(alias = "gsl_rstat_quantile_add")]
+    pub fn add_slice(&mut self, xs: &[f64]) -> Result<(), Value> {
+        self.workspaces.iter_mut().try_for_each(|w| w.add_slice(xs))
+    }
+
+    /// Returns the current estimates of the quantiles, in the same order as
+    /// [`RStatMultiQuantileWorkspace::probabilities`].
+    #[doc(alias = "gsl_rstat_quantile_get")]
+    pub fn get(&mut self) -> Vec<f64> {
+        self.workspaces.iter_mut().map(|w| w.get()).collect()
+    }
+}
+
 ffi_wrapper!(
     RStatWorkspace,
     *mut sys::gsl_rstat_workspace,
@@ -128,3 +199,108 @@ impl RStatWorkspace {
         result_handler!(ret, ())
     }
 }
+
+/// Running covariance and correlation of two variables.
+///
+/// GSL only provides running statistics of a single variable, this accumulator extends
+/// [`RStatWorkspace`] to pairs of samples (x, y). The means and co-moments are updated with
+/// Welford's algorithm, so that the data does not need to be stored and the results are
+/// numerically stable.
+///
+/// ```
+/// use rgsl::RStatCovariance;
+///
+/// let mut r = RStatCovariance::new();
+/// for (x, y) in [(1., 2.), (2., 4.), (3., 6.)] {
+///     r.add(x, y);
+/// }
+/// assert_eq!(r.covariance(), 2.);
+/// assert!((r.correlation() - 1.).abs() < 1e-12);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RStatCovariance {
+    n: usize,
+    mean_x: f64,
+    mean_y: f64,
+    m2_x: f64,
+    m2_y: f64,
+    c: f64,
+}
+
+impl RStatCovariance {
+    /// Creates running statistics with no pair added yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of pairs added so far.
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Adds the pair `(x, y)` to the running statistics.
+    pub fn add(&mut self, x: f64, y: f64) {
+        self.n += 1;
+        let n = self.n as f64;
+        let dx = x - self.mean_x;
+        let dy = y - self.mean_y;
+        self.mean_x += dx / n;
+        self.mean_y += dy / n;
+        self.m2_x += dx * (x - self.mean_x);
+        self.m2_y += dy * (y - self.mean_y);
+        self.c += dx * (y - self.mean_y);
+    }
+
+    /// Returns the mean of x, or 0 if no pair was added.
+    pub fn mean_x(&self) -> f64 {
+        self.mean_x
+    }
+
+    /// Returns the mean of y, or 0 if no pair was added.
+    pub fn mean_y(&self) -> f64 {
+        self.mean_y
+    }
+
+    /// Returns the sample variance of x, or 0 if less than two pairs were added.
+    pub fn variance_x(&self) -> f64 {
+        if self.n < 2 {
+            0.
+        } else {
+            self.m2_x / (self.n - 1) as f64
+        }
+    }
+
+    /// Returns the sample variance of y, or 0 if less than two pairs were added.
+    pub fn variance_y(&self) -> f64 {
+        if self.n < 2 {
+            0.
+        } else {
+            self.m2_y / (self.n - 1) as f64
+        }
+    }
+
+    /// Returns the sample covariance of x and y, or 0 if less than two pairs were added. It
+    /// is equal to [`covariance`](crate::stats::covariance) computed on all the pairs.
+    pub fn covariance(&self) -> f64 {
+        if self.n < 2 {
+            0.
+        } else {
+            self.c / (self.n - 1) as f64
+        }
+    }
+
+    /// Returns the Pearson correlation coefficient of x and y. It is NaN if less than two pairs
+    /// were added or if one of the variables is constant.
+    pub fn correlation(&self) -> f64 {
+        if self.n < 2 {
+            f64::NAN
+        } else {
+            self.c / (self.m2_x * self.m2_y).sqrt()
+        }
+    }
+
+    /// Removes all the pairs added so far.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
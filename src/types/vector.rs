@@ -34,11 +34,13 @@ use crate::ffi::FFI;
 use crate::Value;
 use std::{
     fmt::{self, Debug, Formatter},
+    io,
     marker::PhantomData,
     ops::Range,
 };
 
 use paste::paste;
+use std::os::raw::{c_char, c_long, c_ulong};
 
 #[cfg(feature = "complex")]
 extern crate num_complex;
@@ -190,6 +192,34 @@ pub(crate) fn as_mut_ptr<F, T: VectorMut<F> + ?Sized>(x: &mut T) -> *mut F {
     T::as_mut_slice(x).as_mut_ptr()
 }
 
+/// Return a `gsl_vector` viewing the elements of `x` (to pass slices
+/// to GSL functions expecting vectors).  The data is not copied, so
+/// the returned value must not be used after `x` is dropped.
+#[inline]
+#[cfg_attr(not(feature = "v2_5"), allow(dead_code))]
+pub(crate) fn as_gsl_vector<T: Vector<f64> + ?Sized>(x: &T) -> sys::gsl_vector {
+    sys::gsl_vector {
+        size: T::len(x),
+        stride: T::stride(x),
+        data: as_ptr(x) as *mut f64,
+        block: std::ptr::null_mut(),
+        owner: 0,
+    }
+}
+
+/// Same as [`as_gsl_vector`] for vectors which GSL will modify.
+#[inline]
+#[cfg_attr(not(feature = "v2_5"), allow(dead_code))]
+pub(crate) fn as_gsl_vector_mut<T: VectorMut<f64> + ?Sized>(x: &mut T) -> sys::gsl_vector {
+    sys::gsl_vector {
+        size: T::len(x),
+        stride: T::stride(x),
+        data: as_mut_ptr(x),
+        block: std::ptr::null_mut(),
+        owner: 0,
+    }
+}
+
 /// Return the stride of `x` as a `i32` value (to use in CBLAS calls).
 #[inline]
 pub(crate) fn stride<F, T: Vector<F> + ?Sized>(x: &T) -> i32 {
@@ -203,11 +233,103 @@ where
     T2: Vector<F> + ?Sized,
 {
     if T1::len(x) != T2::len(y) {
-        return Err(Value::Invalid);
+        return Err(Value::BadLength);
     }
     Ok(())
 }
 
+/// Dimensions longer than this are truncated when displayed (unless the alternate flag `{:#}` is
+/// used), showing only the first and last [`DISPLAY_EDGE_ITEMS`] elements.
+pub(crate) const DISPLAY_THRESHOLD: usize = 10;
+pub(crate) const DISPLAY_EDGE_ITEMS: usize = 3;
+
+/// Indices of the elements to display for a dimension of length `len`, `None` standing for the
+/// elided part.
+pub(crate) fn display_indices(len: usize, truncate: bool) -> impl Iterator<Item = Option<usize>> {
+    let elide = truncate && len > DISPLAY_THRESHOLD;
+    let head = if elide { DISPLAY_EDGE_ITEMS } else { len };
+    let tail = if elide { len - DISPLAY_EDGE_ITEMS } else { len };
+    (0..head)
+        .map(Some)
+        .chain(elide.then_some(None))
+        .chain((tail..len).map(Some))
+}
+
+/// Writes `[e_0, e_1, ...]` where each element is written by `elem`.
+pub(crate) fn fmt_elements(
+    f: &mut Formatter,
+    len: usize,
+    truncate: bool,
+    mut elem: impl FnMut(&mut Formatter, usize) -> fmt::Result,
+) -> fmt::Result {
+    f.write_str("[")?;
+    for (n, i) in display_indices(len, truncate).enumerate() {
+        if n > 0 {
+            f.write_str(", ")?;
+        }
+        match i {
+            Some(i) => elem(f, i)?,
+            None => f.write_str("...")?,
+        }
+    }
+    f.write_str("]")
+}
+
+/// Writes the matrix row by row, one row per line.
+pub(crate) fn fmt_matrix(
+    f: &mut Formatter,
+    size1: usize,
+    size2: usize,
+    truncate: bool,
+    mut elem: impl FnMut(&mut Formatter, usize, usize) -> fmt::Result,
+) -> fmt::Result {
+    f.write_str("[")?;
+    for (n, i) in display_indices(size1, truncate).enumerate() {
+        if n > 0 {
+            f.write_str(",\n ")?;
+        }
+        match i {
+            Some(i) => fmt_elements(f, size2, truncate, |f, j| elem(f, i, j))?,
+            None => f.write_str("...")?,
+        }
+    }
+    f.write_str("]")
+}
+
+/// Reads the next whitespace separated token of `r` and parses it, leaving the rest of the input
+/// untouched (like `fscanf` does).
+pub(crate) fn read_value<T: std::str::FromStr, R: io::BufRead>(r: &mut R) -> io::Result<T> {
+    let mut token = Vec::new();
+    loop {
+        let buf = r.fill_buf()?;
+        if buf.is_empty() {
+            break;
+        }
+        let skip = if token.is_empty() {
+            buf.iter().take_while(|b| b.is_ascii_whitespace()).count()
+        } else {
+            0
+        };
+        let len = buf[skip..]
+            .iter()
+            .take_while(|b| !b.is_ascii_whitespace())
+            .count();
+        token.extend_from_slice(&buf[skip..skip + len]);
+        let done = skip + len < buf.len() && !token.is_empty();
+        r.consume(skip + len);
+        if done {
+            break;
+        }
+    }
+    if token.is_empty() {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+    std::str::from_utf8(&token)
+        .ok()
+        .and_then(|t| t.parse().ok())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "cannot parse the value"))
+}
+
 macro_rules! gsl_vec {
     ($rust_name:ident, $name:ident, $rust_ty:ident) => (
 paste! {
@@ -233,7 +355,21 @@ impl Debug for $rust_name {
         if ptr.is_null() {
             write!(f, "<null>")
         } else {
-            write!(f, "{:?}", self.as_slice().expect("conversion to slice failed"))
+            write!(f, "{} {{ len: {}, data: ", stringify!($rust_name), self.len())?;
+            fmt_elements(f, self.len(), true, |f, i| Debug::fmt(&self.get(i), f))?;
+            write!(f, " }}")
+        }
+    }
+}
+
+/// The elements are displayed with the width and precision given to the formatter. Long vectors
+/// are truncated, except with the alternate flag `{:#}`.
+impl fmt::Display for $rust_name {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.unwrap_shared().is_null() {
+            write!(f, "<null>")
+        } else {
+            fmt_elements(f, self.len(), !f.alternate(), |f, i| fmt::Display::fmt(&self.get(i), f))
         }
     }
 }
@@ -291,6 +427,22 @@ impl $rust_name {
         }
     }
 
+    /// Creates a new vector of length `n` whose `i`-th element is `f(i)`.
+    ///
+    /// ```
+    #[doc = "use rgsl::" $rust_name ";"]
+    ///
+    #[doc = "let v = " $rust_name "::from_fn(4, |i| i as _).unwrap();"]
+    /// assert_eq!(v.len(), 4);
+    /// ```
+    pub fn from_fn<F: FnMut(usize) -> $rust_ty>(n: usize, mut f: F) -> Option<$rust_name> {
+        let mut v = $rust_name::new(n)?;
+        for i in 0..n {
+            v.set(i, f(i));
+        }
+        Some(v)
+    }
+
     pub fn len(&self) -> usize {
         let ptr = self.unwrap_shared();
         if ptr.is_null() {
@@ -304,21 +456,38 @@ impl $rust_name {
         self.len() == 0
     }
 
+    // GSL reads the first element unconditionally in the min/max functions.
+    fn check_not_empty(&self, f: &str) {
+        assert!(!self.is_empty(), "rgsl::{}::{}: empty vector", stringify!($rust_name), f);
+    }
+
+    // Views whose parameters overrun the underlying memory have a null data pointer.
+    fn is_empty_view(&self) -> bool {
+        unsafe { (*self.unwrap_shared()).data.is_null() }
+    }
+
+    /// Returns the elements of the vector as a slice, or `None` if the vector is an empty view or
+    /// if its stride is not 1 (a column of a matrix for example). Use
+    #[doc = concat!("[`", stringify!($rust_name), "::iter`]")]
+    /// to go through the elements of a strided vector.
     pub fn as_slice(&self) -> Option<&[$rust_ty]> {
-        let ptr = unsafe { (*self.unwrap_shared()).data };
-        if ptr.is_null() {
+        let v = unsafe { &*self.unwrap_shared() };
+        if v.data.is_null() || v.stride != 1 {
             None
         } else {
-            Some(unsafe { std::slice::from_raw_parts(ptr, self.len()) })
+            Some(unsafe { std::slice::from_raw_parts(v.data, v.size) })
         }
     }
 
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "::as_slice`]")]
+    /// but allows to modify the elements.
     pub fn as_slice_mut(&mut self) -> Option<&mut [$rust_ty]> {
-        let ptr = unsafe { (*self.unwrap_shared()).data };
-        if ptr.is_null() {
+        let v = unsafe { &*self.unwrap_unique() };
+        if v.data.is_null() || v.stride != 1 {
             None
         } else {
-            Some(unsafe { std::slice::from_raw_parts_mut(ptr, self.len()) })
+            Some(unsafe { std::slice::from_raw_parts_mut(v.data, v.size) })
         }
     }
 
@@ -452,20 +621,29 @@ impl $rust_name {
     }
 
     /// This function returns the maximum value in the self vector.
+    ///
+    /// Panics if the vector is empty.
     #[doc(alias = $name _max)]
     pub fn max(&self) -> $rust_ty {
+        self.check_not_empty("max");
         unsafe { sys::[<$name _max>](self.unwrap_shared()) }
     }
 
     /// This function returns the minimum value in the self vector.
+    ///
+    /// Panics if the vector is empty.
     #[doc(alias = $name _min)]
     pub fn min(&self) -> $rust_ty {
+        self.check_not_empty("min");
         unsafe { sys::[<$name _min>](self.unwrap_shared()) }
     }
 
     /// This function returns the minimum and maximum values in the self vector.
+    ///
+    /// Panics if the vector is empty.
     #[doc(alias = $name _minmax)]
     pub fn minmax(&self) -> ($rust_ty, $rust_ty) {
+        self.check_not_empty("minmax");
         let mut min_out = 0 as _;
         let mut max_out = 0 as _;
 
@@ -477,23 +655,32 @@ impl $rust_name {
 
     /// This function returns the index of the maximum value in the self vector.
     /// When there are several equal maximum elements then the lowest index is returned.
+    ///
+    /// Panics if the vector is empty.
     #[doc(alias = $name _max_index)]
     pub fn max_index(&self) -> usize {
+        self.check_not_empty("max_index");
         unsafe { sys::[<$name _max_index>](self.unwrap_shared()) }
     }
 
     /// This function returns the index of the minimum value in the self vector.
     /// When there are several equal minimum elements then the lowest index is returned.
+    ///
+    /// Panics if the vector is empty.
     #[doc(alias = $name _min_index)]
     pub fn min_index(&self) -> usize {
+        self.check_not_empty("min_index");
         unsafe { sys::[<$name _min_index>](self.unwrap_shared()) }
     }
 
     /// This function returns the indices of the minimum and maximum values in the self vector.
     /// When there are several equal minimum or maximum elements then the lowest indices are
     /// returned.
+    ///
+    /// Panics if the vector is empty.
     #[doc(alias = $name _minmax_index)]
     pub fn minmax_index(&self) -> (usize, usize) {
+        self.check_not_empty("minmax_index");
         let mut imin = 0;
         let mut imax = 0;
 
@@ -501,33 +688,241 @@ impl $rust_name {
         (imin, imax)
     }
 
-    /// This function returns true if all the elements of the self vector are equal to 0.
+    /// Returns `true` if all the elements of the vector are zero.
     #[doc(alias = $name _isnull)]
-    pub fn is_null(&self) -> bool {
+    pub fn is_zero(&self) -> bool {
         unsafe { sys::[<$name _isnull>](self.unwrap_shared()) == 1 }
     }
 
-    /// This function returns true if all the elements of the self vector are stricly positive.
+    /// Returns `true` if all the elements of the vector are strictly positive.
     #[doc(alias = $name _ispos)]
-    pub fn is_pos(&self) -> bool {
+    pub fn all_positive(&self) -> bool {
         unsafe { sys::[<$name _ispos>](self.unwrap_shared()) == 1 }
     }
 
-    /// This function returns true if all the elements of the self vector are stricly negative.
+    /// Returns `true` if all the elements of the vector are strictly negative.
     #[doc(alias = $name _isneg)]
-    pub fn is_neg(&self) -> bool {
+    pub fn all_negative(&self) -> bool {
         unsafe { sys::[<$name _isneg>](self.unwrap_shared()) == 1 }
     }
 
-    /// This function returns true if all the elements of the self vector are stricly non-negative.
+    /// Returns `true` if all the elements of the vector are non-negative.
     #[doc(alias = $name _isnonneg)]
-    pub fn is_non_neg(&self) -> bool {
+    pub fn all_non_negative(&self) -> bool {
         unsafe { sys::[<$name _isnonneg>](self.unwrap_shared()) == 1 }
     }
 
+    #[deprecated(since = "8.0.0", note = "Please use `is_zero` instead")]
+    pub fn is_null(&self) -> bool {
+        self.is_zero()
+    }
+
+    #[deprecated(since = "8.0.0", note = "Please use `all_positive` instead")]
+    pub fn is_pos(&self) -> bool {
+        self.all_positive()
+    }
+
+    #[deprecated(since = "8.0.0", note = "Please use `all_negative` instead")]
+    pub fn is_neg(&self) -> bool {
+        self.all_negative()
+    }
+
+    #[deprecated(since = "8.0.0", note = "Please use `all_non_negative` instead")]
+    pub fn is_non_neg(&self) -> bool {
+        self.all_non_negative()
+    }
+
+    /// Returns `true` if the two vectors have the same dimensions and all their elements are equal.
+    /// This is the same as `==`.
     #[doc(alias = $name _equal)]
     pub fn equal(&self, other: &$rust_name) -> bool {
-        unsafe { sys::[<$name _equal>](self.unwrap_shared(), other.unwrap_shared()) == 1 }
+        // GSL calls the error handler when the dimensions differ.
+        self.len() == other.len()
+            && unsafe { sys::[<$name _equal>](self.unwrap_shared(), other.unwrap_shared()) == 1 }
+    }
+
+    /// Copies the elements of the vector (taking the stride into account) into a `Vec`.
+    pub fn into_vec(self) -> Vec<$rust_ty> {
+        self.iter().copied().collect()
+    }
+
+    /// Returns an iterator over the elements of the vector, taking the stride into account.
+    pub fn iter(&self) -> std::iter::StepBy<std::slice::Iter<'_, $rust_ty>> {
+        let stride = Vector::stride(self);
+        self.strided_slice().iter().step_by(stride)
+    }
+
+    /// Returns an iterator allowing to modify the elements of the vector, taking the stride into
+    /// account.
+    pub fn iter_mut(&mut self) -> std::iter::StepBy<std::slice::IterMut<'_, $rust_ty>> {
+        let stride = Vector::stride(self);
+        self.strided_slice_mut().iter_mut().step_by(stride)
+    }
+
+    /// Returns a new vector whose elements are the images of the elements of `self` by `f`.
+    ///
+    /// Returns `None` if the allocation of the new vector fails.
+    pub fn map<F: FnMut($rust_ty) -> $rust_ty>(&self, mut f: F) -> Option<$rust_name> {
+        let mut v = $rust_name::new(self.len())?;
+        for (y, &x) in v.iter_mut().zip(self.iter()) {
+            *y = f(x);
+        }
+        Some(v)
+    }
+
+    /// Replaces each element `x` of the vector by `f(x)`.
+    pub fn map_inplace<F: FnMut($rust_ty) -> $rust_ty>(&mut self, mut f: F) {
+        for x in self.iter_mut() {
+            *x = f(*x);
+        }
+    }
+
+    /// Returns a new vector whose elements are `f(x, y)` where `x` and `y` are the elements of
+    /// `self` and `other` at the same position.
+    ///
+    /// Returns [`Value::BadLength`] if the vectors do not have the same length.
+    pub fn zip_map<F: FnMut($rust_ty, $rust_ty) -> $rust_ty>(
+        &self,
+        other: &$rust_name,
+        mut f: F,
+    ) -> Result<$rust_name, Value> {
+        if self.len() != other.len() {
+            return Err(Value::BadLength);
+        }
+        let mut v = $rust_name::new(self.len()).ok_or(Value::NoMemory)?;
+        for ((z, &x), &y) in v.iter_mut().zip(self.iter()).zip(other.iter()) {
+            *z = f(x, y);
+        }
+        Ok(v)
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "::map`]")]
+    /// but the function is applied in parallel.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "rayon")))]
+    pub fn par_map<F: Fn($rust_ty) -> $rust_ty + Sync + Send>(&self, f: F) -> Option<$rust_name> {
+        use rayon::prelude::*;
+
+        let stride = Vector::stride(self);
+        let mut v = $rust_name::new(self.len())?;
+        v.strided_slice_mut()
+            .par_iter_mut()
+            .zip(self.strided_slice().par_chunks(stride))
+            .for_each(|(y, x)| *y = f(x[0]));
+        Some(v)
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "::map_inplace`]")]
+    /// but the function is applied in parallel.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "rayon")))]
+    pub fn par_map_inplace<F: Fn($rust_ty) -> $rust_ty + Sync + Send>(&mut self, f: F) {
+        use rayon::prelude::*;
+
+        let stride = Vector::stride(self);
+        self.strided_slice_mut()
+            .par_chunks_mut(stride)
+            .for_each(|x| x[0] = f(x[0]));
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "::zip_map`]")]
+    /// but the function is applied in parallel.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "rayon")))]
+    pub fn par_zip_map<F: Fn($rust_ty, $rust_ty) -> $rust_ty + Sync + Send>(
+        &self,
+        other: &$rust_name,
+        f: F,
+    ) -> Result<$rust_name, Value> {
+        use rayon::prelude::*;
+
+        if self.len() != other.len() {
+            return Err(Value::BadLength);
+        }
+        let (stride1, stride2) = (Vector::stride(self), Vector::stride(other));
+        let mut v = $rust_name::new(self.len()).ok_or(Value::NoMemory)?;
+        v.strided_slice_mut()
+            .par_iter_mut()
+            .zip(self.strided_slice().par_chunks(stride1))
+            .zip(other.strided_slice().par_chunks(stride2))
+            .for_each(|((z, x), y)| *z = f(x[0], y[0]));
+        Ok(v)
+    }
+
+    /// This function writes the elements of the vector to `w` in binary format, using the native
+    /// representation of the numbers (the format of `gsl_vector_fwrite`). Since the data is written
+    /// in the native binary format it may not be portable between different architectures.
+    pub fn write_binary<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        for x in self.iter() {
+            w.write_all(&x.to_ne_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// This function reads into the vector the binary data written by
+    #[doc = concat!("[`", stringify!($rust_name), "::write_binary`].")]
+    /// The vector must be preallocated with the correct length since the format contains no size
+    /// information.
+    pub fn read_binary<R: io::Read>(&mut self, r: &mut R) -> io::Result<()> {
+        for x in self.iter_mut() {
+            let mut buf = [0; std::mem::size_of::<$rust_ty>()];
+            r.read_exact(&mut buf)?;
+            *x = <$rust_ty>::from_ne_bytes(buf);
+        }
+        Ok(())
+    }
+
+    /// This function writes the elements of the vector to `w`, one per line (the format of
+    /// `gsl_vector_fprintf`).
+    pub fn to_writer<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        for x in self.iter() {
+            writeln!(w, "{}", x)?;
+        }
+        Ok(())
+    }
+
+    /// This function reads whitespace separated values from `r` into the vector (the format of
+    /// `gsl_vector_fscanf`). The vector must be preallocated with the correct length since the
+    /// format contains no size information.
+    pub fn from_reader<R: io::BufRead>(&mut self, r: &mut R) -> io::Result<()> {
+        for x in self.iter_mut() {
+            *x = read_value(r)?;
+        }
+        Ok(())
+    }
+
+    // The memory spanned by the vector elements, `(len - 1) * stride + 1` values.
+    fn strided_slice(&self) -> &[$rust_ty] {
+        let len = self.len();
+        if len == 0 {
+            return &[];
+        }
+        unsafe {
+            let v = &*self.unwrap_shared();
+            if v.data.is_null() {
+                &[]
+            } else {
+                std::slice::from_raw_parts(v.data, (len - 1) * v.stride + 1)
+            }
+        }
+    }
+
+    fn strided_slice_mut(&mut self) -> &mut [$rust_ty] {
+        let len = self.len();
+        if len == 0 {
+            return &mut [];
+        }
+        unsafe {
+            let v = &*self.unwrap_unique();
+            if v.data.is_null() {
+                &mut []
+            } else {
+                std::slice::from_raw_parts_mut(v.data, (len - 1) * v.stride + 1)
+            }
+        }
     }
 
     pub fn clone(&self) -> Option<$rust_name> {
@@ -546,31 +941,109 @@ impl $rust_name {
         }
     }
 
+    /// Builds a new vector by concatenating the vectors `vs`.
+    ///
+    /// Returns [`Value::Invalid`] if `vs` is empty or if all the vectors are empty.
+    pub fn concat(vs: &[&$rust_name]) -> Result<$rust_name, Value> {
+        let len = vs.iter().map(|v| v.len()).sum();
+        if len == 0 {
+            return Err(Value::Invalid);
+        }
+        let mut out = $rust_name::new(len).ok_or(Value::NoMemory)?;
+        let mut offset = 0;
+        for v in vs.iter().filter(|v| !v.is_empty()) {
+            out.subvector(offset, v.len()).copy_from(v)?;
+            offset += v.len();
+        }
+        Ok(out)
+    }
+
+    /// Returns a view of the `n` elements of the vector starting at `offset`, borrowing it
+    /// mutably. See
+    #[doc = concat!("[`", stringify!($rust_name), "View::from_vector`].")]
     #[doc(alias = $name _subvector)]
     pub fn subvector(&mut self, offset: usize, n: usize) -> [<$rust_name View>]<'_> {
         [<$rust_name View>]::from_vector(self, offset, n)
     }
+
+    /// Returns a view of `n` elements of the vector, starting at `offset` with a step-size of
+    /// `stride`, borrowing it mutably. See
+    #[doc = concat!("[`", stringify!($rust_name), "View::from_vector_with_stride`].")]
+    #[doc(alias = $name _subvector_with_stride)]
+    pub fn subvector_with_stride(
+        &mut self,
+        offset: usize,
+        stride: usize,
+        n: usize,
+    ) -> [<$rust_name View>]<'_> {
+        [<$rust_name View>]::from_vector_with_stride(self, offset, stride, n)
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "::subvector`]")]
+    /// but only borrows the vector immutably.
+    #[doc(alias = $name _const_subvector)]
+    pub fn const_subvector(&self, offset: usize, n: usize) -> [<$rust_name ConstView>]<'_> {
+        [<$rust_name ConstView>]::from_vector(self, offset, n)
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "::subvector_with_stride`]")]
+    /// but only borrows the vector immutably.
+    #[doc(alias = $name _const_subvector_with_stride)]
+    pub fn const_subvector_with_stride(
+        &self,
+        offset: usize,
+        stride: usize,
+        n: usize,
+    ) -> [<$rust_name ConstView>]<'_> {
+        [<$rust_name ConstView>]::from_vector_with_stride(self, offset, stride, n)
+    }
 }
 
+/// A mutable view of (a part of) a vector, an array or a matrix, borrowing it for `'a`.
+///
+/// The view dereferences to an immutable
+#[doc = concat!("[`", stringify!($rust_name), "`]")]
+/// and provides the methods modifying the elements itself. It implements [`VectorMut`] so it can
+/// be passed to the CBLAS routines for example. Writing into the view writes into the memory it
+/// borrows.
+///
+/// If the parameters used to create the view overrun the underlying memory, GSL reports an error
+/// and the view is empty.
 pub struct [<$rust_name View>]<'a> {
-    v: sys::[<$name _view>],
-    #[allow(dead_code)]
-    phantom: PhantomData<&'a ()>,
+    // Soft wrapper around a heap copy of the `gsl_vector` struct returned by GSL (so that the
+    // view can be moved around), freed on drop.
+    inner: $rust_name,
+    phantom: PhantomData<&'a mut [$rust_ty]>,
+}
+
+impl<'a> Drop for [<$rust_name View>]<'a> {
+    fn drop(&mut self) {
+        unsafe { drop(Box::from_raw(self.inner.vec)) };
+    }
+}
+
+impl<'a> std::ops::Deref for [<$rust_name View>]<'a> {
+    type Target = $rust_name;
+
+    fn deref(&self) -> &$rust_name {
+        &self.inner
+    }
 }
 
 impl<'a> [<$rust_name View>]<'a> {
+    pub(crate) fn new(v: sys::$name) -> Self {
+        Self {
+            inner: $rust_name::soft_wrap(Box::into_raw(Box::new(v))),
+            phantom: PhantomData,
+        }
+    }
+
     #[doc(hidden)]
     pub(crate) fn wrap<F: FnOnce(Option<Self>)>(v: sys::[<$name _view>], f: F) {
-        let tmp = Self {
-            v,
-            phantom: PhantomData,
-        };
-        let is_none = {
-            let v = &tmp.v.vector;
-            let tmp = $rust_name::soft_wrap(v as *const _ as usize as *mut _);
-            tmp.as_slice().is_none()
-        };
-        if is_none {
+        let tmp = Self::new(v.vector);
+        if tmp.is_empty_view() {
             f(None)
         } else {
             f(Some(tmp))
@@ -585,25 +1058,14 @@ impl<'a> [<$rust_name View>]<'a> {
     ///
     /// where the index i runs from 0 to n-1.
     ///
-    /// The data pointer of the returned vector struct is set to null if the combined parameters
-    /// (offset,n) overrun the end of the original vector.
-    ///
-    /// The new vector is only a view of the block underlying the original vector, v. The block
-    /// containing the elements of v is not owned by the new vector. When the view goes out of scope
-    /// the original vector v and its block will continue to exist. The original memory can only be
-    /// deallocated by freeing the original vector. Of course, the original vector should not be
-    /// deallocated while the view is still in use.
+    /// The view is empty if the combined parameters (offset,n) overrun the end of the original
+    /// vector.
     ///
-    /// The function gsl_vector_const_subvector is equivalent to gsl_vector_subvector but can be
-    /// used for vectors which are declared const.
+    /// The new vector is only a view of the block underlying the original vector, v, which stays
+    /// mutably borrowed as long as the view is alive.
     #[doc(alias = $name _subvector)]
     pub fn from_vector(v: &'a mut $rust_name, offset: usize, n: usize) -> Self {
-        unsafe {
-            Self {
-                v: sys::[<$name _subvector>](v.unwrap_unique(), offset, n),
-                phantom: PhantomData,
-            }
-        }
+        Self::new(unsafe { sys::[<$name _subvector>](v.unwrap_unique(), offset, n) }.vector)
     }
 
     /// These functions return a vector view of a subvector of another vector v with an additional
@@ -618,23 +1080,23 @@ impl<'a> [<$rust_name View>]<'a> {
     /// vector. For example, the following code will zero the even elements of the vector v of
     /// length n, while leaving the odd elements untouched,
     ///
-    /// ```C
-    /// gsl_vector_view v_even
-    ///   = gsl_vector_subvector_with_stride (v, 0, 2, n/2);
-    /// gsl_vector_set_zero (&v_even.vector);
     /// ```
-    /// A vector view can be passed to any subroutine which takes a vector argument just as a
-    /// directly allocated vector would be, using &view.vector.
-    /// For example, the following code computes the norm of the odd elements of v using the BLAS
-    /// routine DNRM2,
+    /// # let mut v = rgsl::VectorF64::new(10).unwrap();
+    /// # let n = v.len();
+    /// let mut v_even = v.subvector_with_stride(0, 2, n / 2);
+    /// v_even.set_zero();
+    /// ```
+    ///
+    /// A vector view can be passed to any function which takes a vector argument just as a
+    /// directly allocated vector would be. For example, the following code computes the norm of
+    /// the odd elements of v using the BLAS routine DNRM2,
     ///
-    /// ```C
-    /// gsl_vector_view v_odd
-    ///   = gsl_vector_subvector_with_stride (v, 1, 2, n/2);
-    /// double r = gsl_blas_dnrm2 (&v_odd.vector);
     /// ```
-    /// The function gsl_vector_const_subvector_with_stride is equivalent to
-    /// gsl_vector_subvector_with_stride but can be used for vectors which are declared const.
+    /// # let mut v = rgsl::VectorF64::new(10).unwrap();
+    /// # let n = v.len();
+    /// let v_odd = v.subvector_with_stride(1, 2, n / 2);
+    /// let r = rgsl::blas::level1::dnrm2(&v_odd);
+    /// ```
     #[doc(alias = $name _subvector_with_stride)]
     pub fn from_vector_with_stride(
         v: &'a mut $rust_name,
@@ -642,12 +1104,10 @@ impl<'a> [<$rust_name View>]<'a> {
         stride: usize,
         n: usize,
     ) -> Self {
-        unsafe {
-            Self {
-                v: sys::[<$name _subvector_with_stride>](v.vec, offset, stride, n),
-                phantom: PhantomData,
-            }
-        }
+        Self::new(
+            unsafe { sys::[<$name _subvector_with_stride>](v.unwrap_unique(), offset, stride, n) }
+                .vector,
+        )
     }
 
     /// These functions return a vector view of an array. The start of the new vector is given by
@@ -659,21 +1119,10 @@ impl<'a> [<$rust_name View>]<'a> {
     ///
     /// where the index i runs from 0 to n-1.
     ///
-    /// The array containing the elements of v is not owned by the new vector view. When the view
-    /// goes out of scope the original array will continue to exist. The original memory can only be
-    /// deallocated by freeing the original pointer base. Of course, the original array should not
-    /// be deallocated while the view is still in use.
-    ///
-    /// The function gsl_vector_const_view_array is equivalent to gsl_vector_view_array but can be
-    /// used for arrays which are declared const.
+    /// The array stays mutably borrowed as long as the view is alive.
     #[doc(alias = $name _view_array)]
-    pub fn from_array(base: &'a mut [f64]) -> Self {
-        unsafe {
-            Self {
-                v: sys::[<$name _view_array>](base.as_mut_ptr() as _, base.len() as _),
-                phantom: PhantomData,
-            }
-        }
+    pub fn from_array(base: &'a mut [$rust_ty]) -> Self {
+        Self::new(unsafe { sys::[<$name _view_array>](base.as_mut_ptr(), base.len()) }.vector)
     }
 
     /// These functions return a vector view of an array base with an additional stride argument.
@@ -683,42 +1132,49 @@ impl<'a> [<$rust_name View>]<'a> {
     ///
     /// v'(i) = base[i*stride]
     ///
-    /// where the index i runs from 0 to n-1.
+    /// where the index i runs from 0 to n-1, n being the number of such elements fitting in base.
     ///
-    /// Note that the view gives direct access to the underlying elements of the original array. A
-    /// vector view can be passed to any subroutine which takes a vector argument just as a directly
-    /// allocated vector would be, using &view.vector.
+    /// Note that the view gives direct access to the underlying elements of the original array,
+    /// which stays mutably borrowed as long as the view is alive.
     ///
-    /// The function gsl_vector_const_view_array_with_stride is equivalent to
-    /// gsl_vector_view_array_with_stride but can be used for arrays which are declared const.
+    /// Panics if `stride` is zero.
     #[doc(alias = $name _view_array_with_stride)]
     pub fn from_array_with_stride(base: &'a mut [$rust_ty], stride: usize) -> Self {
-        unsafe {
-            Self {
-                v: sys::[<$name _view_array_with_stride>](
-                    base.as_mut_ptr(),
-                    stride,
-                    base.len() as _,
-                ),
-                phantom: PhantomData,
-            }
-        }
+        assert!(stride > 0, "stride must be positive");
+        let n = base.len().div_ceil(stride);
+        Self::new(
+            unsafe { sys::[<$name _view_array_with_stride>](base.as_mut_ptr(), stride, n) }
+                .vector,
+        )
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "View::from_array`]")]
+    /// so that an existing Rust buffer can be modified by GSL routines in place, without any copy.
+    pub fn from_mut_slice(s: &'a mut [$rust_ty]) -> Self {
+        Self::from_array(s)
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "ConstView::from_array`]")]
+    /// so that an existing Rust buffer can be passed to GSL routines without any copy.
+    pub fn from_slice(s: &'a [$rust_ty]) -> [<$rust_name ConstView>]<'a> {
+        [<$rust_name ConstView>]::from_array(s)
     }
 
     pub fn vector<F: FnOnce(Option<&$rust_name>)>(&self, f: F) {
-        let v = &self.v.vector;
-        let tmp = $rust_name::soft_wrap(v as *const _ as usize as *mut _);
-        if tmp.as_slice().is_none() {
+        if self.inner.is_empty_view() {
             f(None)
         } else {
-            f(Some(&tmp))
+            f(Some(&self.inner))
         }
     }
 
     pub fn vector_mut<F: FnOnce(Option<&mut $rust_name>)>(&mut self, f: F) {
-        let v = &mut self.v.vector;
-        let mut tmp = $rust_name::soft_wrap(v as *mut _);
-        if tmp.as_slice().is_none() {
+        // A temporary wrapper is handed out so that the view keeps its own one whatever `f` does
+        // with the reference.
+        let mut tmp = $rust_name::soft_wrap(self.inner.vec);
+        if tmp.is_empty_view() {
             f(None)
         } else {
             f(Some(&mut tmp))
@@ -726,6 +1182,230 @@ impl<'a> [<$rust_name View>]<'a> {
     }
 } // end of impl block
 
+// The view does not implement `DerefMut`: a `&mut` to the wrapped vector would allow to replace
+// it (with `std::mem::swap` for example), freeing it with the wrong deallocator or letting the
+// borrowed memory outlive `'a`. The methods modifying the elements are forwarded instead.
+impl<'a> [<$rust_name View>]<'a> {
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::set`].")]
+    pub fn set(&mut self, i: usize, x: $rust_ty) -> &mut Self {
+        self.inner.set(i, x);
+        self
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::set_all`].")]
+    pub fn set_all(&mut self, x: $rust_ty) -> &mut Self {
+        self.inner.set_all(x);
+        self
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::set_zero`].")]
+    pub fn set_zero(&mut self) -> &mut Self {
+        self.inner.set_zero();
+        self
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::set_basis`].")]
+    pub fn set_basis(&mut self, i: usize) -> &mut Self {
+        self.inner.set_basis(i);
+        self
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::copy_from`].")]
+    pub fn copy_from(&mut self, other: &$rust_name) -> Result<(), Value> {
+        self.inner.copy_from(other)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::swap`].")]
+    pub fn swap(&mut self, other: &mut $rust_name) -> Result<(), Value> {
+        self.inner.swap(other)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::swap_elements`].")]
+    pub fn swap_elements(&mut self, i: usize, j: usize) -> Result<(), Value> {
+        self.inner.swap_elements(i, j)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::reverse`].")]
+    pub fn reverse(&mut self) -> Result<(), Value> {
+        self.inner.reverse()
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::add`].")]
+    pub fn add(&mut self, other: &$rust_name) -> Result<(), Value> {
+        self.inner.add(other)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::sub`].")]
+    pub fn sub(&mut self, other: &$rust_name) -> Result<(), Value> {
+        self.inner.sub(other)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::mul`].")]
+    pub fn mul(&mut self, other: &$rust_name) -> Result<(), Value> {
+        self.inner.mul(other)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::div`].")]
+    pub fn div(&mut self, other: &$rust_name) -> Result<(), Value> {
+        self.inner.div(other)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::scale`].")]
+    pub fn scale(&mut self, x: $rust_ty) -> Result<(), Value> {
+        self.inner.scale(x)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::add_constant`].")]
+    pub fn add_constant(&mut self, x: $rust_ty) -> Result<(), Value> {
+        self.inner.add_constant(x)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::as_slice_mut`].")]
+    pub fn as_slice_mut(&mut self) -> Option<&mut [$rust_ty]> {
+        self.inner.as_slice_mut()
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::iter_mut`].")]
+    pub fn iter_mut(&mut self) -> std::iter::StepBy<std::slice::IterMut<'_, $rust_ty>> {
+        self.inner.iter_mut()
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::map_inplace`].")]
+    pub fn map_inplace<F: FnMut($rust_ty) -> $rust_ty>(&mut self, f: F) {
+        self.inner.map_inplace(f)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::par_map_inplace`].")]
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "rayon")))]
+    pub fn par_map_inplace<F: Fn($rust_ty) -> $rust_ty + Sync + Send>(&mut self, f: F) {
+        self.inner.par_map_inplace(f)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::read_binary`].")]
+    pub fn read_binary<R: io::Read>(&mut self, r: &mut R) -> io::Result<()> {
+        self.inner.read_binary(r)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::from_reader`].")]
+    pub fn from_reader<R: io::BufRead>(&mut self, r: &mut R) -> io::Result<()> {
+        self.inner.from_reader(r)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::subvector`].")]
+    pub fn subvector(&mut self, offset: usize, n: usize) -> [<$rust_name View>]<'_> {
+        self.inner.subvector(offset, n)
+    }
+
+    #[doc = concat!("Same as [`", stringify!($rust_name), "::subvector_with_stride`].")]
+    pub fn subvector_with_stride(
+        &mut self,
+        offset: usize,
+        stride: usize,
+        n: usize,
+    ) -> [<$rust_name View>]<'_> {
+        self.inner.subvector_with_stride(offset, stride, n)
+    }
+}
+
+/// A read-only view of (a part of) a vector, an array or a matrix, borrowing it for `'a`.
+///
+/// The view dereferences to a
+#[doc = concat!("[`", stringify!($rust_name), "`]")]
+/// so it can be used like any (immutable) vector.
+///
+/// If the parameters used to create the view overrun the underlying memory, GSL reports an error
+/// and the view is empty.
+pub struct [<$rust_name ConstView>]<'a> {
+    // Same as for the mutable view. The wrapped vector is never handed out mutably.
+    inner: $rust_name,
+    phantom: PhantomData<&'a [$rust_ty]>,
+}
+
+impl<'a> Drop for [<$rust_name ConstView>]<'a> {
+    fn drop(&mut self) {
+        unsafe { drop(Box::from_raw(self.inner.vec)) };
+    }
+}
+
+impl<'a> std::ops::Deref for [<$rust_name ConstView>]<'a> {
+    type Target = $rust_name;
+
+    fn deref(&self) -> &$rust_name {
+        &self.inner
+    }
+}
+
+impl<'a> [<$rust_name ConstView>]<'a> {
+    pub(crate) fn new(v: sys::$name) -> Self {
+        Self {
+            inner: $rust_name::soft_wrap(Box::into_raw(Box::new(v))),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "View::from_vector`]")]
+    /// for a vector which is only borrowed immutably.
+    #[doc(alias = $name _const_subvector)]
+    pub fn from_vector(v: &'a $rust_name, offset: usize, n: usize) -> Self {
+        Self::new(unsafe { sys::[<$name _const_subvector>](v.unwrap_shared(), offset, n) }.vector)
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "View::from_vector_with_stride`]")]
+    /// for a vector which is only borrowed immutably.
+    #[doc(alias = $name _const_subvector_with_stride)]
+    pub fn from_vector_with_stride(
+        v: &'a $rust_name,
+        offset: usize,
+        stride: usize,
+        n: usize,
+    ) -> Self {
+        Self::new(
+            unsafe {
+                sys::[<$name _const_subvector_with_stride>](v.unwrap_shared(), offset, stride, n)
+            }
+            .vector,
+        )
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "View::from_array`]")]
+    /// for an array which is only borrowed immutably.
+    #[doc(alias = $name _const_view_array)]
+    pub fn from_array(base: &'a [$rust_ty]) -> Self {
+        Self::new(unsafe { sys::[<$name _const_view_array>](base.as_ptr(), base.len()) }.vector)
+    }
+
+    /// Same as
+    #[doc = concat!("[`", stringify!($rust_name), "View::from_array_with_stride`]")]
+    /// for an array which is only borrowed immutably.
+    ///
+    /// Panics if `stride` is zero.
+    #[doc(alias = $name _const_view_array_with_stride)]
+    pub fn from_array_with_stride(base: &'a [$rust_ty], stride: usize) -> Self {
+        assert!(stride > 0, "stride must be positive");
+        let n = base.len().div_ceil(stride);
+        Self::new(
+            unsafe { sys::[<$name _const_view_array_with_stride>](base.as_ptr(), stride, n) }
+                .vector,
+        )
+    }
+} // end of impl block
+
+impl<'a> From<&'a mut [$rust_ty]> for [<$rust_name View>]<'a> {
+    fn from(s: &'a mut [$rust_ty]) -> Self {
+        Self::from_array(s)
+    }
+}
+
+impl<'a> From<&'a [$rust_ty]> for [<$rust_name ConstView>]<'a> {
+    fn from(s: &'a [$rust_ty]) -> Self {
+        Self::from_array(s)
+    }
+}
+
     impl Vector<$rust_ty> for $rust_name {
         #[inline]
         fn len(x: &Self) -> usize {
@@ -742,13 +1422,134 @@ impl<'a> [<$rust_name View>]<'a> {
         }
         #[inline]
         fn as_slice(x: &Self) -> &[$rust_ty] {
-            $rust_name::as_slice(x).unwrap_or(&[])
+            x.strided_slice()
         }
     }
     impl VectorMut<$rust_ty> for $rust_name {
         #[inline]
         fn as_mut_slice(x: &mut Self) -> &mut [$rust_ty] {
-            $rust_name::as_slice_mut(x).unwrap_or(&mut [])
+            x.strided_slice_mut()
+        }
+    }
+
+    // Views (such as matrix rows and columns) can be fed to the functions taking `Vector`s.
+    impl<'a> Vector<$rust_ty> for [<$rust_name View>]<'a> {
+        #[inline]
+        fn len(x: &Self) -> usize {
+            $rust_name::len(x)
+        }
+        #[inline]
+        fn stride(x: &Self) -> usize {
+            <$rust_name as Vector<$rust_ty>>::stride(x)
+        }
+        #[inline]
+        fn as_slice(x: &Self) -> &[$rust_ty] {
+            x.strided_slice()
+        }
+    }
+    impl<'a> VectorMut<$rust_ty> for [<$rust_name View>]<'a> {
+        #[inline]
+        fn as_mut_slice(x: &mut Self) -> &mut [$rust_ty] {
+            x.inner.strided_slice_mut()
+        }
+    }
+    impl<'a> Vector<$rust_ty> for [<$rust_name ConstView>]<'a> {
+        #[inline]
+        fn len(x: &Self) -> usize {
+            $rust_name::len(x)
+        }
+        #[inline]
+        fn stride(x: &Self) -> usize {
+            <$rust_name as Vector<$rust_ty>>::stride(x)
+        }
+        #[inline]
+        fn as_slice(x: &Self) -> &[$rust_ty] {
+            x.strided_slice()
+        }
+    }
+
+    impl std::ops::Index<usize> for $rust_name {
+        type Output = $rust_ty;
+
+        fn index(&self, i: usize) -> &$rust_ty {
+            let len = self.len();
+            assert!(i < len, "index out of bounds: the len is {} but the index is {}", len, i);
+            unsafe {
+                let v = &*self.unwrap_shared();
+                &*v.data.add(i * v.stride)
+            }
+        }
+    }
+
+    impl std::ops::IndexMut<usize> for $rust_name {
+        fn index_mut(&mut self, i: usize) -> &mut $rust_ty {
+            let len = self.len();
+            assert!(i < len, "index out of bounds: the len is {} but the index is {}", len, i);
+            unsafe {
+                let v = &*self.unwrap_unique();
+                &mut *v.data.add(i * v.stride)
+            }
+        }
+    }
+
+    impl From<&[$rust_ty]> for $rust_name {
+        /// Panics if the allocation fails.
+        fn from(v: &[$rust_ty]) -> $rust_name {
+            $rust_name::from_slice(v)
+                .expect(concat!("rgsl::", stringify!($rust_name), "::from: allocation failed"))
+        }
+    }
+
+    impl From<Vec<$rust_ty>> for $rust_name {
+        /// Panics if the allocation fails.
+        fn from(v: Vec<$rust_ty>) -> $rust_name {
+            $rust_name::from(&v[..])
+        }
+    }
+
+    impl std::iter::FromIterator<$rust_ty> for $rust_name {
+        /// Panics if the allocation fails.
+        fn from_iter<I: IntoIterator<Item = $rust_ty>>(iter: I) -> $rust_name {
+            $rust_name::from(iter.into_iter().collect::<Vec<_>>())
+        }
+    }
+
+    /// Two vectors are equal when they have the same length and the same elements, whatever their
+    /// strides.
+    impl PartialEq for $rust_name {
+        fn eq(&self, other: &$rust_name) -> bool {
+            self.len() == other.len() && self.iter().eq(other.iter())
+        }
+    }
+
+    #[cfg(feature = "approx")]
+    impl approx::AbsDiffEq for $rust_name {
+        type Epsilon = <$rust_ty as approx::AbsDiffEq>::Epsilon;
+
+        fn default_epsilon() -> Self::Epsilon {
+            <$rust_ty as approx::AbsDiffEq>::default_epsilon()
+        }
+
+        fn abs_diff_eq(&self, other: &$rust_name, epsilon: Self::Epsilon) -> bool {
+            self.len() == other.len()
+                && self.iter().zip(other.iter()).all(|(a, b)| a.abs_diff_eq(b, epsilon))
+        }
+    }
+
+    /// The vector is serialized as the sequence of its elements.
+    #[cfg(feature = "serde")]
+    impl serde::Serialize for $rust_name {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(self.iter())
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<'de> serde::Deserialize<'de> for $rust_name {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<$rust_name, D::Error> {
+            let v = <Vec<$rust_ty> as serde::Deserialize>::deserialize(deserializer)?;
+            $rust_name::from_slice(&v)
+                .ok_or_else(|| serde::de::Error::custom("cannot allocate the vector"))
         }
     }
 
@@ -760,6 +1561,136 @@ gsl_vec!(VectorF32, gsl_vector_float, f32);
 gsl_vec!(VectorF64, gsl_vector, f64);
 gsl_vec!(VectorI32, gsl_vector_int, i32);
 gsl_vec!(VectorU32, gsl_vector_uint, u32);
+// The `long` and `char` C types have a platform dependent size (resp. signedness), hence the names
+// of these types: the elements of `VectorLong` and `VectorULong` are only 32 bits wide on Windows
+// and those of `VectorChar` are unsigned on some ARM platforms.
+gsl_vec!(VectorLong, gsl_vector_long, c_long);
+gsl_vec!(VectorULong, gsl_vector_ulong, c_ulong);
+gsl_vec!(VectorI16, gsl_vector_short, i16);
+gsl_vec!(VectorU16, gsl_vector_ushort, u16);
+gsl_vec!(VectorChar, gsl_vector_char, c_char);
+gsl_vec!(VectorU8, gsl_vector_uchar, u8);
+
+// Arithmetic operators: `*` and `/` act element-wise (see `dot` for the scalar product).
+macro_rules! vector_ops {
+    ($($rust_name:ident, $rust_ty:ty;)*) => {
+        $(
+            elementwise_ops!(
+                $rust_name, len,
+                Add add, AddAssign add_assign => add;
+                Sub sub, SubAssign sub_assign => sub;
+                Mul mul, MulAssign mul_assign => mul;
+                Div div, DivAssign div_assign => div;
+            );
+            scalar_ops!($rust_name, $rust_ty);
+        )*
+    };
+}
+
+vector_ops!(
+    VectorF32, f32; VectorF64, f64; VectorI32, i32; VectorU32, u32;
+    VectorLong, c_long; VectorULong, c_ulong; VectorI16, i16; VectorU16, u16;
+    VectorChar, c_char; VectorU8, u8;
+);
+scalar_ops!(@neg VectorF32, f32);
+scalar_ops!(@neg VectorF64, f64);
+scalar_ops!(@neg VectorI32, i32);
+scalar_ops!(@neg VectorLong, c_long);
+scalar_ops!(@neg VectorI16, i16);
+
+macro_rules! vector_dot {
+    ($rust_name:ident, $rust_ty:ty, $blas:ident) => {
+        paste! {
+            impl $rust_name {
+                /// Returns the scalar product of `self` and `other`. Note that the `*` operator
+                /// multiplies vectors element-wise.
+                #[doc(alias = gsl_blas_ $blas)]
+                pub fn dot(&self, other: &$rust_name) -> Result<$rust_ty, Value> {
+                    crate::blas::level1::$blas(self, other)
+                }
+            }
+        }
+    };
+}
+
+vector_dot!(VectorF32, f32, sdot);
+vector_dot!(VectorF64, f64, ddot);
+
+permute_ops!(VectorF32, vector_float);
+permute_ops!(VectorF64, vector);
+permute_ops!(VectorI32, vector_int);
+permute_ops!(VectorU32, vector_uint);
+permute_ops!(VectorLong, vector_long);
+permute_ops!(VectorULong, vector_ulong);
+permute_ops!(VectorI16, vector_short);
+permute_ops!(VectorU16, vector_ushort);
+permute_ops!(VectorChar, vector_char);
+permute_ops!(VectorU8, vector_uchar);
+
+macro_rules! vector_ranges {
+    ($rust_name:ident, $rust_ty:ident) => {
+        impl $rust_name {
+            /// Creates a vector of `n` evenly spaced values from `a` to `b`, both included.
+            ///
+            /// Returns `None` if `n` is zero.
+            pub fn linspace(a: $rust_ty, b: $rust_ty, n: usize) -> Option<$rust_name> {
+                match n {
+                    0 => return None,
+                    1 => return $rust_name::from_slice(&[a]),
+                    _ => {}
+                }
+                let h = (b - a) / (n - 1) as $rust_ty;
+                $rust_name::from_fn(n, |i| if i == n - 1 { b } else { a + i as $rust_ty * h })
+            }
+
+            /// Creates a vector of the values `start + i * step` lying in the half-open interval
+            /// `[start, stop)` (or `(stop, start]` if `step` is negative).
+            ///
+            /// Returns `None` if there are no such values or if `step` is zero.
+            pub fn arange(start: $rust_ty, stop: $rust_ty, step: $rust_ty) -> Option<$rust_name> {
+                let n = ((stop - start) / step).ceil();
+                if !n.is_finite() || n <= 0. {
+                    return None;
+                }
+                $rust_name::from_fn(n as usize, |i| start + i as $rust_ty * step)
+            }
+        }
+    };
+}
+
+vector_ranges!(VectorF32, f32);
+vector_ranges!(VectorF64, f64);
+
+// `RelativeEq` only makes sense for floating point elements.
+#[cfg(feature = "approx")]
+macro_rules! impl_relative_eq {
+    ($rust_name:ident, $rust_ty:ty) => {
+        #[cfg(feature = "approx")]
+        impl approx::RelativeEq for $rust_name {
+            fn default_max_relative() -> Self::Epsilon {
+                <$rust_ty as approx::RelativeEq>::default_max_relative()
+            }
+
+            fn relative_eq(
+                &self,
+                other: &$rust_name,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                self.len() == other.len()
+                    && self
+                        .iter()
+                        .zip(other.iter())
+                        .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "approx")]
+impl_relative_eq!(VectorF32, f32);
+#[cfg(feature = "approx")]
+impl_relative_eq!(VectorF64, f64);
 
 // Implement the `Vector` trait on standard vectors.
 
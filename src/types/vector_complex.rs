@@ -3,8 +3,14 @@
 //
 
 use crate::ffi::FFI;
+use crate::types::vector::{fmt_elements, read_value};
+#[cfg(feature = "complex")]
+use crate::vector::{Vector, VectorMut};
 use crate::Value;
+#[cfg(feature = "complex")]
+use num_complex::Complex;
 use paste::paste;
+use std::io;
 use std::{
     fmt::{self, Debug, Formatter},
     marker::PhantomData,
@@ -37,7 +43,23 @@ macro_rules! gsl_vec_complex {
                 if ptr.is_null() {
                     write!(f, "<null>")
                 } else {
-                    write!(f, "{:?}", self.as_slice().expect("conversion to slice failed"))
+                    write!(f, "{} {{ len: {}, data: ", stringify!($rust_name), self.len())?;
+                    fmt_elements(f, self.len(), true, |f, i| Debug::fmt(&self.get(i), f))?;
+                    write!(f, " }}")
+                }
+            }
+        }
+
+        /// The elements are displayed with the width and precision given to the formatter. Long
+        /// vectors are truncated, except with the alternate flag `{:#}`.
+        impl fmt::Display for $rust_name {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                if self.unwrap_shared().is_null() {
+                    write!(f, "<null>")
+                } else {
+                    fmt_elements(f, self.len(), !f.alternate(), |f, i| {
+                        fmt::Display::fmt(&self.get(i), f)
+                    })
                 }
             }
         }
@@ -257,6 +279,13 @@ macro_rules! gsl_vec_complex {
                 result_handler!(ret, ())
             }
 
+            /// This function replaces each element of the vector by its complex conjugate.
+            pub fn conjugate(&mut self) {
+                for z in self.iter_mut() {
+                    z.dat[1] = -z.dat[1];
+                }
+            }
+
             /// This function multiplies the elements of the self vector by the constant factor x. The
             /// result a_i <- a_i is stored in self.
             #[doc(alias = $name _scale)]
@@ -280,34 +309,250 @@ macro_rules! gsl_vec_complex {
                 result_handler!(ret, ())
             }
 
-            /// This function returns true if all the elements of the self vector are equal to 0.
+            /// Returns `true` if all the elements of the vector are zero.
             #[doc(alias = $name _isnull)]
-            pub fn is_null(&self) -> bool {
+            pub fn is_zero(&self) -> bool {
                 unsafe { sys::[<$name _isnull>](self.unwrap_shared()) == 1 }
             }
 
-            /// This function returns true if all the elements of the self vector are stricly positive.
+            /// Returns `true` if all the elements of the vector are strictly positive.
             #[doc(alias = $name _ispos)]
-            pub fn is_pos(&self) -> bool {
+            pub fn all_positive(&self) -> bool {
                 unsafe { sys::[<$name _ispos>](self.unwrap_shared()) == 1 }
             }
 
-            /// This function returns true if all the elements of the self vector are stricly negative.
+            /// Returns `true` if all the elements of the vector are strictly negative.
             #[doc(alias = $name _isneg)]
-            pub fn is_neg(&self) -> bool {
+            pub fn all_negative(&self) -> bool {
                 unsafe { sys::[<$name _isneg>](self.unwrap_shared()) == 1 }
             }
 
-            /// This function returns true if all the elements of the self vector are stricly non-negative.
+            /// Returns `true` if all the elements of the vector are non-negative.
             #[doc(alias = $name _isnonneg)]
-            pub fn is_non_neg(&self) -> bool {
+            pub fn all_non_negative(&self) -> bool {
                 unsafe { sys::[<$name _isnonneg>](self.unwrap_shared()) == 1 }
             }
 
+            #[deprecated(since = "8.0.0", note = "Please use `is_zero` instead")]
+            pub fn is_null(&self) -> bool {
+                self.is_zero()
+            }
+
+            #[deprecated(since = "8.0.0", note = "Please use `all_positive` instead")]
+            pub fn is_pos(&self) -> bool {
+                self.all_positive()
+            }
+
+            #[deprecated(since = "8.0.0", note = "Please use `all_negative` instead")]
+            pub fn is_neg(&self) -> bool {
+                self.all_negative()
+            }
+
+            #[deprecated(since = "8.0.0", note = "Please use `all_non_negative` instead")]
+            pub fn is_non_neg(&self) -> bool {
+                self.all_non_negative()
+            }
+
+            /// Returns `true` if the two vectors have the same dimensions and all their elements
+            /// are equal. This is the same as `==`.
             #[doc(alias = $name _equal)]
             pub fn equal(&self, other: &$rust_name) -> bool {
+                // GSL calls the error handler when the dimensions differ.
+                self.len() == other.len()
+                    && unsafe {
+                        sys::[<$name _equal>](self.unwrap_shared(), other.unwrap_shared()) == 1
+                    }
+            }
+
+            /// Copies the elements of the vector (taking the stride into account) into a `Vec`.
+            pub fn into_vec(self) -> Vec<$complex> {
+                self.iter().copied().collect()
+            }
+
+            /// Returns an iterator over the elements of the vector, taking the stride into account.
+            pub fn iter(&self) -> std::iter::StepBy<std::slice::Iter<'_, $complex>> {
+                self.strided_slice().iter().step_by(self.stride())
+            }
+
+            /// Returns an iterator allowing to modify the elements of the vector, taking the stride
+            /// into account.
+            pub fn iter_mut(&mut self) -> std::iter::StepBy<std::slice::IterMut<'_, $complex>> {
+                let stride = self.stride();
+                self.strided_slice_mut().iter_mut().step_by(stride)
+            }
+
+            /// Returns a new vector whose elements are the images of the elements of `self` by `f`.
+            ///
+            /// Returns `None` if the allocation of the new vector fails.
+            pub fn map<F: FnMut($complex) -> $complex>(&self, mut f: F) -> Option<$rust_name> {
+                let mut v = $rust_name::new(self.len())?;
+                for (y, &x) in v.iter_mut().zip(self.iter()) {
+                    *y = f(x);
+                }
+                Some(v)
+            }
+
+            /// Replaces each element `x` of the vector by `f(x)`.
+            pub fn map_inplace<F: FnMut($complex) -> $complex>(&mut self, mut f: F) {
+                for x in self.iter_mut() {
+                    *x = f(*x);
+                }
+            }
+
+            /// Returns a new vector whose elements are `f(x, y)` where `x` and `y` are the elements
+            /// of `self` and `other` at the same position.
+            ///
+            /// Returns [`Value::BadLength`] if the vectors do not have the same length.
+            pub fn zip_map<F: FnMut($complex, $complex) -> $complex>(
+                &self,
+                other: &$rust_name,
+                mut f: F,
+            ) -> Result<$rust_name, Value> {
+                if self.len() != other.len() {
+                    return Err(Value::BadLength);
+                }
+                let mut v = $rust_name::new(self.len()).ok_or(Value::NoMemory)?;
+                for ((z, &x), &y) in v.iter_mut().zip(self.iter()).zip(other.iter()) {
+                    *z = f(x, y);
+                }
+                Ok(v)
+            }
+
+            /// Same as
+            #[doc = concat!("[`", stringify!($rust_name), "::map`]")]
+            /// but the function is applied in parallel.
+            #[cfg(feature = "rayon")]
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "rayon")))]
+            pub fn par_map<F: Fn($complex) -> $complex + Sync + Send>(
+                &self,
+                f: F,
+            ) -> Option<$rust_name> {
+                use rayon::prelude::*;
+
+                let stride = self.stride();
+                let mut v = $rust_name::new(self.len())?;
+                v.strided_slice_mut()
+                    .par_iter_mut()
+                    .zip(self.strided_slice().par_chunks(stride))
+                    .for_each(|(y, x)| *y = f(x[0]));
+                Some(v)
+            }
+
+            /// Same as
+            #[doc = concat!("[`", stringify!($rust_name), "::map_inplace`]")]
+            /// but the function is applied in parallel.
+            #[cfg(feature = "rayon")]
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "rayon")))]
+            pub fn par_map_inplace<F: Fn($complex) -> $complex + Sync + Send>(&mut self, f: F) {
+                use rayon::prelude::*;
+
+                let stride = self.stride();
+                self.strided_slice_mut()
+                    .par_chunks_mut(stride)
+                    .for_each(|x| x[0] = f(x[0]));
+            }
+
+            /// Same as
+            #[doc = concat!("[`", stringify!($rust_name), "::zip_map`]")]
+            /// but the function is applied in parallel.
+            #[cfg(feature = "rayon")]
+            #[cfg_attr(feature = "dox", doc(cfg(feature = "rayon")))]
+            pub fn par_zip_map<F: Fn($complex, $complex) -> $complex + Sync + Send>(
+                &self,
+                other: &$rust_name,
+                f: F,
+            ) -> Result<$rust_name, Value> {
+                use rayon::prelude::*;
+
+                if self.len() != other.len() {
+                    return Err(Value::BadLength);
+                }
+                let (stride1, stride2) = (self.stride(), other.stride());
+                let mut v = $rust_name::new(self.len()).ok_or(Value::NoMemory)?;
+                v.strided_slice_mut()
+                    .par_iter_mut()
+                    .zip(self.strided_slice().par_chunks(stride1))
+                    .zip(other.strided_slice().par_chunks(stride2))
+                    .for_each(|((z, x), y)| *z = f(x[0], y[0]));
+                Ok(v)
+            }
+
+            fn stride(&self) -> usize {
+                unsafe { self.unwrap_shared().as_ref() }.map_or(1, |v| v.stride)
+            }
+
+            /// This function writes the elements of the vector to `w` in binary format, using the
+            /// native representation of the numbers (the format of `gsl_vector_fwrite`). Since the
+            /// data is written in the native binary format it may not be portable between different
+            /// architectures.
+            pub fn write_binary<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+                for x in self.iter() {
+                    x.write_ne(w)?;
+                }
+                Ok(())
+            }
+
+            /// This function reads into the vector the binary data written by
+            #[doc = concat!("[`", stringify!($rust_name), "::write_binary`].")]
+            /// The vector must be preallocated with the correct length since the format contains no
+            /// size information.
+            pub fn read_binary<R: io::Read>(&mut self, r: &mut R) -> io::Result<()> {
+                for x in self.iter_mut() {
+                    *x = $complex::read_ne(r)?;
+                }
+                Ok(())
+            }
+
+            /// This function writes the elements of the vector to `w`, one per line (the format of
+            /// `gsl_vector_fprintf`).
+            pub fn to_writer<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+                for x in self.iter() {
+                    writeln!(w, "{} {}", x.dat[0], x.dat[1])?;
+                }
+                Ok(())
+            }
+
+            /// This function reads whitespace separated values from `r` into the vector (the format
+            /// of `gsl_vector_fscanf`). The vector must be preallocated with the correct length
+            /// since the format contains no size information.
+            pub fn from_reader<R: io::BufRead>(&mut self, r: &mut R) -> io::Result<()> {
+                for x in self.iter_mut() {
+                    x.dat[0] = read_value(r)?;
+                    x.dat[1] = read_value(r)?;
+                }
+                Ok(())
+            }
+
+            // The memory spanned by the vector elements, `(len - 1) * stride + 1` values.
+            fn strided_slice(&self) -> &[$complex] {
+                let len = self.len();
+                if len == 0 {
+                    return &[];
+                }
                 unsafe {
-                    sys::[<$name _equal>](self.unwrap_shared(), other.unwrap_shared()) == 1
+                    let v = &*self.unwrap_shared();
+                    if v.data.is_null() {
+                        &[]
+                    } else {
+                        let n = (len - 1) * v.stride + 1;
+                        std::slice::from_raw_parts(v.data as *const $complex, n)
+                    }
+                }
+            }
+
+            fn strided_slice_mut(&mut self) -> &mut [$complex] {
+                let len = self.len();
+                if len == 0 {
+                    return &mut [];
+                }
+                unsafe {
+                    let v = &*self.unwrap_unique();
+                    if v.data.is_null() {
+                        &mut []
+                    } else {
+                        let n = (len - 1) * v.stride + 1;
+                        std::slice::from_raw_parts_mut(v.data as *mut $complex, n)
+                    }
                 }
             }
 
@@ -328,6 +573,139 @@ macro_rules! gsl_vec_complex {
             }
         }
 
+        // `$complex` has the same layout as `Complex<$rust_ty>`, so complex vectors can be fed
+        // to the functions taking `Vector`s.
+        #[cfg(feature = "complex")]
+        impl Vector<Complex<$rust_ty>> for $rust_name {
+            #[inline]
+            fn len(x: &Self) -> usize {
+                $rust_name::len(x)
+            }
+            #[inline]
+            fn stride(x: &Self) -> usize {
+                x.stride()
+            }
+            #[inline]
+            fn as_slice(x: &Self) -> &[Complex<$rust_ty>] {
+                let s = x.strided_slice();
+                unsafe { std::slice::from_raw_parts(s.as_ptr() as *const _, s.len()) }
+            }
+        }
+
+        #[cfg(feature = "complex")]
+        impl VectorMut<Complex<$rust_ty>> for $rust_name {
+            #[inline]
+            fn as_mut_slice(x: &mut Self) -> &mut [Complex<$rust_ty>] {
+                let s = x.strided_slice_mut();
+                unsafe { std::slice::from_raw_parts_mut(s.as_mut_ptr() as *mut _, s.len()) }
+            }
+        }
+
+        impl std::ops::Index<usize> for $rust_name {
+            type Output = $complex;
+
+            fn index(&self, i: usize) -> &$complex {
+                let len = self.len();
+                assert!(i < len, "index out of bounds: the len is {} but the index is {}", len, i);
+                unsafe {
+                    let v = &*self.unwrap_shared();
+                    &*(v.data.add(2 * i * v.stride) as *const $complex)
+                }
+            }
+        }
+
+        impl std::ops::IndexMut<usize> for $rust_name {
+            fn index_mut(&mut self, i: usize) -> &mut $complex {
+                let len = self.len();
+                assert!(i < len, "index out of bounds: the len is {} but the index is {}", len, i);
+                unsafe {
+                    let v = &*self.unwrap_unique();
+                    &mut *(v.data.add(2 * i * v.stride) as *mut $complex)
+                }
+            }
+        }
+
+        impl From<&[$complex]> for $rust_name {
+            /// Panics if the allocation fails.
+            fn from(v: &[$complex]) -> $rust_name {
+                $rust_name::from_slice(v)
+                    .expect(concat!("rgsl::", stringify!($rust_name), "::from: allocation failed"))
+            }
+        }
+
+        impl From<Vec<$complex>> for $rust_name {
+            /// Panics if the allocation fails.
+            fn from(v: Vec<$complex>) -> $rust_name {
+                $rust_name::from(&v[..])
+            }
+        }
+
+        impl std::iter::FromIterator<$complex> for $rust_name {
+            /// Panics if the allocation fails.
+            fn from_iter<I: IntoIterator<Item = $complex>>(iter: I) -> $rust_name {
+                $rust_name::from(iter.into_iter().collect::<Vec<_>>())
+            }
+        }
+
+        /// Two vectors are equal when they have the same length and the same elements, whatever
+        /// their strides.
+        impl PartialEq for $rust_name {
+            fn eq(&self, other: &$rust_name) -> bool {
+                self.len() == other.len() && self.iter().eq(other.iter())
+            }
+        }
+
+        #[cfg(feature = "approx")]
+        impl approx::AbsDiffEq for $rust_name {
+            type Epsilon = <$complex as approx::AbsDiffEq>::Epsilon;
+
+            fn default_epsilon() -> Self::Epsilon {
+                <$complex as approx::AbsDiffEq>::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &$rust_name, epsilon: Self::Epsilon) -> bool {
+                self.len() == other.len()
+                    && self.iter().zip(other.iter()).all(|(a, b)| a.abs_diff_eq(b, epsilon))
+            }
+        }
+
+        #[cfg(feature = "approx")]
+        impl approx::RelativeEq for $rust_name {
+            fn default_max_relative() -> Self::Epsilon {
+                <$complex as approx::RelativeEq>::default_max_relative()
+            }
+
+            fn relative_eq(
+                &self,
+                other: &$rust_name,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                self.len() == other.len()
+                    && self
+                        .iter()
+                        .zip(other.iter())
+                        .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+            }
+        }
+
+        /// The vector is serialized as the sequence of its elements.
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $rust_name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_seq(self.iter())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $rust_name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<$rust_name, D::Error> {
+                let v = <Vec<$complex> as serde::Deserialize>::deserialize(deserializer)?;
+                $rust_name::from_slice(&v)
+                    .ok_or_else(|| serde::de::Error::custom("cannot allocate the vector"))
+            }
+        }
+
         pub struct [<$rust_name View>]<'a> {
             v: sys::[<$name _view>],
             #[allow(dead_code)]
@@ -512,3 +890,23 @@ macro_rules! gsl_vec_complex {
 
 gsl_vec_complex!(VectorComplexF32, gsl_vector_complex_float, ComplexF32, f32);
 gsl_vec_complex!(VectorComplexF64, gsl_vector_complex, ComplexF64, f64);
+
+// Element-wise arithmetic operators.
+macro_rules! vector_complex_ops {
+    ($($rust_name:ident),*) => {
+        $(
+            elementwise_ops!(
+                $rust_name, len,
+                Add add, AddAssign add_assign => add;
+                Sub sub, SubAssign sub_assign => sub;
+                Mul mul, MulAssign mul_assign => mul;
+                Div div, DivAssign div_assign => div;
+            );
+        )*
+    };
+}
+
+vector_complex_ops!(VectorComplexF32, VectorComplexF64);
+
+permute_ops!(VectorComplexF32, vector_complex_float);
+permute_ops!(VectorComplexF64, vector_complex);
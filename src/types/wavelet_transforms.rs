@@ -53,6 +53,8 @@ Circulation 101(23):e215-e220 2000.
 !*/
 
 use crate::ffi::FFI;
+use crate::types::matrix::for_each_line;
+use crate::vector::VectorMut;
 
 ffi_wrapper!(
     Wavelet,
@@ -138,7 +140,8 @@ impl WaveletType {
     }
 }
 
-ffi_wrapper!(WaveletWorkspace, *mut sys::gsl_wavelet_workspace, gsl_wavelet_workspace_free,
+ffi_wrapper!(WaveletWorkspace, *mut sys::gsl_wavelet_workspace, gsl_wavelet_workspace_free;
+size: usize => 0;,
 "The WaveletWorkspace structure contains scratch space of the same size as the input data and is
 used to hold intermediate results during the transform.");
 
@@ -154,7 +157,234 @@ impl WaveletWorkspace {
         if tmp.is_null() {
             None
         } else {
-            Some(WaveletWorkspace::wrap(tmp))
+            let mut work = WaveletWorkspace::wrap(tmp);
+            work.size = n;
+            Some(work)
         }
     }
+
+    unsafe fn run(
+        &mut self,
+        f: WaveletFn,
+        w: &Wavelet,
+        data: *mut f64,
+        stride: usize,
+        n: usize,
+    ) -> Result<(), crate::Value> {
+        // GSL calls the error handler rather than returning an error for these.
+        if !n.is_power_of_two() || n > self.size {
+            return Err(crate::Value::Invalid);
+        }
+        let ret = f(w.unwrap_shared(), data, stride, n, self.unwrap_unique());
+        result_handler!(ret, ())
+    }
+
+    fn run_vector<V: VectorMut<f64> + ?Sized>(
+        &mut self,
+        f: WaveletFn,
+        w: &Wavelet,
+        data: &mut V,
+    ) -> Result<(), crate::Value> {
+        let (stride, n) = (V::stride(data), V::len(data));
+        let data = V::as_mut_slice(data).as_mut_ptr();
+        unsafe { self.run(f, w, data, stride, n) }
+    }
+
+    fn run_matrix(
+        &mut self,
+        f: WaveletFn,
+        w: &Wavelet,
+        m: &mut crate::MatrixF64,
+        rows: bool,
+    ) -> Result<(), crate::Value> {
+        let Some(m) = (unsafe { m.unwrap_unique().as_ref() }) else {
+            return Ok(());
+        };
+        unsafe {
+            for_each_line(
+                m.data,
+                (m.size1, m.size2, m.tda),
+                1,
+                rows,
+                |data, stride, n| self.run(f, w, data, stride, n),
+            )
+        }
+    }
+
+    /// Computes the forward discrete wavelet transform of `data` in place, using the wavelet
+    /// `w`. `data` can be any [`VectorMut`], e.g. a slice or a (strided) vector view, whose
+    /// length and stride are used for the transform.
+    ///
+    /// Returns [`Value::Invalid`](crate::Value::Invalid) if the length of `data` is not a power
+    /// of two or exceeds the size of the workspace.
+    #[doc(alias = "gsl_wavelet_transform_forward")]
+    pub fn transform_forward<V: VectorMut<f64> + ?Sized>(
+        &mut self,
+        w: &Wavelet,
+        data: &mut V,
+    ) -> Result<(), crate::Value> {
+        self.run_vector(sys::gsl_wavelet_transform_forward, w, data)
+    }
+
+    /// Inverts [`transform_forward`](Self::transform_forward), in place.
+    #[doc(alias = "gsl_wavelet_transform_inverse")]
+    pub fn transform_inverse<V: VectorMut<f64> + ?Sized>(
+        &mut self,
+        w: &Wavelet,
+        data: &mut V,
+    ) -> Result<(), crate::Value> {
+        self.run_vector(sys::gsl_wavelet_transform_inverse, w, data)
+    }
+
+    /// Computes the forward transform of each row of `m` in place, as with
+    /// [`transform_forward`](Self::transform_forward).
+    pub fn transform_rows_forward(
+        &mut self,
+        w: &Wavelet,
+        m: &mut crate::MatrixF64,
+    ) -> Result<(), crate::Value> {
+        self.run_matrix(sys::gsl_wavelet_transform_forward, w, m, true)
+    }
+
+    /// Inverts [`transform_rows_forward`](Self::transform_rows_forward), in place.
+    pub fn transform_rows_inverse(
+        &mut self,
+        w: &Wavelet,
+        m: &mut crate::MatrixF64,
+    ) -> Result<(), crate::Value> {
+        self.run_matrix(sys::gsl_wavelet_transform_inverse, w, m, true)
+    }
+
+    /// Computes the forward transform of each column of `m` in place, as with
+    /// [`transform_forward`](Self::transform_forward).
+    pub fn transform_columns_forward(
+        &mut self,
+        w: &Wavelet,
+        m: &mut crate::MatrixF64,
+    ) -> Result<(), crate::Value> {
+        self.run_matrix(sys::gsl_wavelet_transform_forward, w, m, false)
+    }
+
+    /// Inverts [`transform_columns_forward`](Self::transform_columns_forward), in place.
+    pub fn transform_columns_inverse(
+        &mut self,
+        w: &Wavelet,
+        m: &mut crate::MatrixF64,
+    ) -> Result<(), crate::Value> {
+        self.run_matrix(sys::gsl_wavelet_transform_inverse, w, m, false)
+    }
+}
+
+type WaveletFn = unsafe extern "C" fn(
+    *const sys::gsl_wavelet,
+    *mut f64,
+    usize,
+    usize,
+    *mut sys::gsl_wavelet_workspace,
+) -> std::os::raw::c_int;
+
+/// Two-dimensional discrete wavelet transforms of `n`-by-`n` matrices, bundling the wavelet with
+/// the workspace needed by the transforms.
+///
+/// See [`wavelet_transforms::two_dimension`](crate::wavelet_transforms::two_dimension) for a
+/// description of the standard and non-standard forms.
+///
+/// ```
+/// use rgsl::{MatrixF64, Wavelet2D, WaveletType};
+///
+/// let mut image = MatrixF64::new(4, 4).unwrap();
+/// image.set(1, 2, 1.);
+/// let mut w = Wavelet2D::new(WaveletType::haar(), 2, 4).unwrap();
+/// w.nstransform_forward(&mut image).unwrap();
+/// w.nstransform_inverse(&mut image).unwrap();
+/// assert!((image.get(1, 2) - 1.).abs() < 1e-12);
+/// ```
+pub struct Wavelet2D {
+    n: usize,
+    wavelet: Wavelet,
+    work: WaveletWorkspace,
+}
+
+impl Wavelet2D {
+    /// Creates the transforms of `n`-by-`n` matrices for the member `k` of the wavelet family
+    /// `t` (see [`Wavelet::new`]). Returns `None` if the wavelet member is not supported or if
+    /// the allocation fails.
+    pub fn new(t: WaveletType, k: usize, n: usize) -> Option<Wavelet2D> {
+        Some(Wavelet2D {
+            n,
+            wavelet: Wavelet::new(t, k)?,
+            work: WaveletWorkspace::new(n)?,
+        })
+    }
+
+    /// Returns the number of rows (and columns) of the matrices that can be transformed.
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the wavelet used by the transforms.
+    pub fn wavelet(&self) -> &Wavelet {
+        &self.wavelet
+    }
+
+    // GSL calls the error handler rather than returning an error for these.
+    fn check_size(&self, m: &crate::MatrixF64) -> Result<(), crate::Value> {
+        if m.size1() != self.n || m.size2() != self.n {
+            Err(crate::Value::BadLength)
+        } else if !self.n.is_power_of_two() {
+            Err(crate::Value::Invalid)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Computes the forward wavelet transform of `m` in standard form, in place.
+    ///
+    /// Returns [`Value::BadLength`](crate::Value::BadLength) if `m` is not `n`-by-`n` and
+    /// [`Value::Invalid`](crate::Value::Invalid) if `n` is not a power of two.
+    #[doc(alias = "gsl_wavelet2d_transform_matrix_forward")]
+    pub fn transform_forward(&mut self, m: &mut crate::MatrixF64) -> Result<(), crate::Value> {
+        self.check_size(m)?;
+        crate::wavelet_transforms::two_dimension::transform_matrix_forward(
+            &self.wavelet,
+            m,
+            &mut self.work,
+        )
+    }
+
+    /// Inverts [`transform_forward`](Self::transform_forward), in place.
+    #[doc(alias = "gsl_wavelet2d_transform_matrix_inverse")]
+    pub fn transform_inverse(&mut self, m: &mut crate::MatrixF64) -> Result<(), crate::Value> {
+        self.check_size(m)?;
+        crate::wavelet_transforms::two_dimension::transform_matrix_inverse(
+            &self.wavelet,
+            m,
+            &mut self.work,
+        )
+    }
+
+    /// Computes the forward wavelet transform of `m` in non-standard form, in place. This is the
+    /// form typically used in image analysis.
+    ///
+    /// Errors are reported as for [`transform_forward`](Self::transform_forward).
+    #[doc(alias = "gsl_wavelet2d_nstransform_matrix_forward")]
+    pub fn nstransform_forward(&mut self, m: &mut crate::MatrixF64) -> Result<(), crate::Value> {
+        self.check_size(m)?;
+        crate::wavelet_transforms::two_dimension::nstransform_matrix_forward(
+            &self.wavelet,
+            m,
+            &mut self.work,
+        )
+    }
+
+    /// Inverts [`nstransform_forward`](Self::nstransform_forward), in place.
+    #[doc(alias = "gsl_wavelet2d_nstransform_matrix_inverse")]
+    pub fn nstransform_inverse(&mut self, m: &mut crate::MatrixF64) -> Result<(), crate::Value> {
+        self.check_size(m)?;
+        crate::wavelet_transforms::two_dimension::nstransform_matrix_inverse(
+            &self.wavelet,
+            m,
+            &mut self.work,
+        )
+    }
 }
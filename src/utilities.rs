@@ -4,12 +4,10 @@
 
 use std::ffi::CString;
 use std::io;
-use std::os::raw::c_char;
 use std::path::Path;
 
 use sys::libc::{fclose, fopen, FILE};
 
-#[allow(dead_code)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum Mode {
     Write,
@@ -24,20 +22,27 @@ pub struct IOStream {
 }
 
 impl IOStream {
-    /// Open a file in write mode.
-    pub fn fwrite_handle<P: AsRef<Path>>(file: &P) -> io::Result<IOStream> {
+    fn open<P: AsRef<Path>>(file: &P, mode: Mode) -> io::Result<IOStream> {
         let path = CString::new(file.as_ref().to_str().unwrap()).unwrap();
-        let ptr = unsafe { fopen(path.as_ptr(), b"w\0".as_ptr() as *const c_char) };
+        let flags = match mode {
+            Mode::Write => c"w",
+            Mode::Read => c"r",
+        };
+        let ptr = unsafe { fopen(path.as_ptr(), flags.as_ptr()) };
         if ptr.is_null() {
-            return Err(io::Error::new(
-                io::ErrorKind::Other,
-                "Failed to open file...",
-            ));
+            return Err(io::Error::other("Failed to open file..."));
         }
-        Ok(IOStream {
-            inner: ptr,
-            mode: Mode::Write,
-        })
+        Ok(IOStream { inner: ptr, mode })
+    }
+
+    /// Open a file in write mode.
+    pub fn fwrite_handle<P: AsRef<Path>>(file: &P) -> io::Result<IOStream> {
+        IOStream::open(file, Mode::Write)
+    }
+
+    /// Open a file in read mode.
+    pub fn fread_handle<P: AsRef<Path>>(file: &P) -> io::Result<IOStream> {
+        IOStream::open(file, Mode::Read)
     }
 
     pub fn write_mode(&self) -> bool {
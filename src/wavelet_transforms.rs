@@ -377,3 +377,64 @@ pub mod two_dimension {
         result_handler!(ret, ())
     }
 }
+
+/// Removes noise from `signal` by thresholding its wavelet coefficients: the signal is
+/// transformed with the wavelet `w`, the detail coefficients are thresholded according to
+/// `rule` and the result is transformed back. The smoothing coefficient is left untouched.
+///
+/// Returns [`Value::Invalid`](crate::Value::Invalid) if the length of `signal` is not a power of
+/// two larger than one.
+///
+/// ```
+/// use rgsl::{wavelet_transforms, Wavelet, WaveletThreshold, WaveletType};
+///
+/// let signal: Vec<f64> = (0..256).map(|i| (i as f64 / 20.).sin()).collect();
+/// let w = Wavelet::new(WaveletType::daubechies(), 4).unwrap();
+/// let denoised =
+///     wavelet_transforms::denoise(&signal, &w, WaveletThreshold::UniversalSoft).unwrap();
+/// ```
+pub fn denoise(
+    signal: &[f64],
+    w: &crate::Wavelet,
+    rule: crate::WaveletThreshold,
+) -> Result<Vec<f64>, crate::Value> {
+    use crate::{Value, WaveletThreshold};
+
+    let n = signal.len();
+    if n < 2 || !n.is_power_of_two() {
+        return Err(Value::Invalid);
+    }
+    let mut data = signal.to_vec();
+    let mut work = crate::WaveletWorkspace::new(n).ok_or(Value::NoMemory)?;
+    work.transform_forward(w, &mut data)?;
+
+    let universal = || {
+        // The finest level holds the last n/2 coefficients.
+        let mut finest: Vec<f64> = data[n / 2..].iter().map(|d| d.abs()).collect();
+        finest.sort_by(|a, b| a.total_cmp(b));
+        // `m` is a power of two, hence even unless it is 1.
+        let m = finest.len();
+        let median = if m == 1 {
+            finest[0]
+        } else {
+            0.5 * (finest[m / 2 - 1] + finest[m / 2])
+        };
+        median / 0.6745 * (2. * (n as f64).ln()).sqrt()
+    };
+    let (threshold, soft) = match rule {
+        WaveletThreshold::Hard(t) => (t, false),
+        WaveletThreshold::Soft(t) => (t, true),
+        WaveletThreshold::UniversalHard => (universal(), false),
+        WaveletThreshold::UniversalSoft => (universal(), true),
+    };
+    for d in &mut data[1..] {
+        if d.abs() <= threshold {
+            *d = 0.;
+        } else if soft {
+            *d -= threshold.copysign(*d);
+        }
+    }
+
+    work.transform_inverse(w, &mut data)?;
+    Ok(data)
+}